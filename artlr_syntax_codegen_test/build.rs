@@ -0,0 +1,46 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::path::Path;
+
+use artlr_syntax::codegen::debug_rust_symbol_formatter::DebugRustSymbolFormatter;
+use artlr_syntax::codegen::rust_parser_generator::RustParserGenerator;
+use artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar;
+use artlr_syntax::grammar::first_follow_symbols::FirstFollowSymbols;
+use artlr_syntax::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+#[path = "src/grammars.rs"]
+#[allow(dead_code)]
+mod grammars;
+
+fn generate_parser<T: Clone + Debug + Eq + Hash>(
+    grammar: &ContextFreeGrammar<T>,
+    type_path: &str,
+    file_name: &str,
+) {
+    let first_follow_symbols = FirstFollowSymbols::from(grammar);
+    let transitions = RecursiveDescentParserTransitions::from(grammar, &first_follow_symbols);
+    let generator = RustParserGenerator::new(
+        grammar,
+        &transitions,
+        DebugRustSymbolFormatter::new(String::from(type_path)),
+    );
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    std::fs::write(Path::new(&out_dir).join(file_name), generator.generate()).unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/grammars.rs");
+
+    generate_parser(
+        &grammars::build_backtracking_grammar(),
+        "crate::grammars::BacktrackingSyntax",
+        "backtracking_parser.rs",
+    );
+    generate_parser(
+        &grammars::build_nested_grammar(),
+        "crate::grammars::NestedSyntax",
+        "nested_parser.rs",
+    );
+}
@@ -0,0 +1,82 @@
+use artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar;
+use artlr_syntax::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+/// Symbols of a grammar whose parse backtracks over sibling symbols
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BacktrackingSyntax {
+    A,
+    ATerminal,
+    BTerminal,
+    Epsilon,
+    S,
+}
+
+/// Symbols of a grammar with nested candidate productions and epsilon productions
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NestedSyntax {
+    A,
+    ATerminal,
+    B,
+    BTerminal,
+    C,
+    Eof,
+    Epsilon,
+    S,
+}
+
+/// S → A b, A → a b | a
+pub fn build_backtracking_grammar() -> ContextFreeGrammar<BacktrackingSyntax> {
+    ContextFreeGrammar::new(
+        BacktrackingSyntax::Epsilon,
+        BacktrackingSyntax::S,
+        vec![
+            ContextFreeGrammarProduction::new(
+                BacktrackingSyntax::S,
+                vec![BacktrackingSyntax::A, BacktrackingSyntax::BTerminal],
+            ),
+            ContextFreeGrammarProduction::new(
+                BacktrackingSyntax::A,
+                vec![BacktrackingSyntax::ATerminal, BacktrackingSyntax::BTerminal],
+            ),
+            ContextFreeGrammarProduction::new(
+                BacktrackingSyntax::A,
+                vec![BacktrackingSyntax::ATerminal],
+            ),
+        ],
+    )
+}
+
+/// S → A B C Eof, A → a | a a | ε, B → C b | a, C → b C | ε
+pub fn build_nested_grammar() -> ContextFreeGrammar<NestedSyntax> {
+    ContextFreeGrammar::new(
+        NestedSyntax::Epsilon,
+        NestedSyntax::S,
+        vec![
+            ContextFreeGrammarProduction::new(
+                NestedSyntax::S,
+                vec![
+                    NestedSyntax::A,
+                    NestedSyntax::B,
+                    NestedSyntax::C,
+                    NestedSyntax::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(NestedSyntax::A, vec![NestedSyntax::ATerminal]),
+            ContextFreeGrammarProduction::new(
+                NestedSyntax::A,
+                vec![NestedSyntax::ATerminal, NestedSyntax::ATerminal],
+            ),
+            ContextFreeGrammarProduction::new(NestedSyntax::A, vec![NestedSyntax::Epsilon]),
+            ContextFreeGrammarProduction::new(
+                NestedSyntax::B,
+                vec![NestedSyntax::C, NestedSyntax::BTerminal],
+            ),
+            ContextFreeGrammarProduction::new(NestedSyntax::B, vec![NestedSyntax::ATerminal]),
+            ContextFreeGrammarProduction::new(
+                NestedSyntax::C,
+                vec![NestedSyntax::BTerminal, NestedSyntax::C],
+            ),
+            ContextFreeGrammarProduction::new(NestedSyntax::C, vec![NestedSyntax::Epsilon]),
+        ],
+    )
+}
@@ -0,0 +1,9 @@
+pub mod grammars;
+
+pub mod backtracking_parser {
+    include!(concat!(env!("OUT_DIR"), "/backtracking_parser.rs"));
+}
+
+pub mod nested_parser {
+    include!(concat!(env!("OUT_DIR"), "/nested_parser.rs"));
+}
@@ -0,0 +1,96 @@
+#![allow(clippy::unused_unit)]
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use artlr_syntax::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar;
+use artlr_syntax::parser::panic_mode_solver::PanicModeSolver;
+use artlr_syntax::parser::parse_result::ParseResult;
+use artlr_syntax::parser::recursive_descent_parser::RecursiveDescentParser;
+use artlr_syntax::token::token::Token;
+use artlr_syntax_codegen_test::grammars::{
+    build_backtracking_grammar, build_nested_grammar, BacktrackingSyntax,
+};
+use artlr_syntax_codegen_test::{backtracking_parser, nested_parser};
+
+/// Every sequence of terminal symbols up to a length.
+fn build_inputs<T: Clone>(terminal_symbols: &[T], max_length: usize) -> Vec<Vec<Token<u64, T>>> {
+    let mut inputs: Vec<Vec<Token<u64, T>>> = vec![vec![]];
+    let mut previous_inputs: Vec<Vec<Token<u64, T>>> = vec![vec![]];
+
+    for _ in 0..max_length {
+        let next_inputs: Vec<Vec<Token<u64, T>>> = previous_inputs
+            .iter()
+            .flat_map(|input| {
+                terminal_symbols.iter().map(move |terminal_symbol| {
+                    let mut next_input = input.clone();
+                    next_input.push(Token::new(
+                        Some(input.len() as u64),
+                        terminal_symbol.clone(),
+                    ));
+
+                    next_input
+                })
+            })
+            .collect();
+
+        inputs.extend(next_inputs.iter().cloned());
+        previous_inputs = next_inputs;
+    }
+
+    inputs
+}
+
+/// Parse function of a generated parser.
+type GeneratedParse<T> = fn(&[Token<u64, T>]) -> Option<AbstractSyntaxTree<Token<u64, T>>>;
+
+/// Asserts the generated parser builds the tree `RecursiveDescentParser` builds for every input.
+fn assert_same_trees<T: Clone + Debug + Eq + Hash>(
+    grammar: &ContextFreeGrammar<T>,
+    parse: GeneratedParse<T>,
+    max_length: usize,
+) {
+    let recursive_descent_parser: RecursiveDescentParser<u64, T, PanicModeSolver> =
+        RecursiveDescentParser::from_grammar(grammar);
+    let terminal_symbols: Vec<T> = grammar
+        .get_terminal_symbols()
+        .into_iter()
+        .filter(|symbol| grammar.get_epsilon_symbol().ne(symbol))
+        .collect();
+
+    for input in build_inputs(&terminal_symbols, max_length) {
+        let expected_tree =
+            match recursive_descent_parser.parse_from_tokens(input.clone().into_iter()) {
+                ParseResult::Ok(tree) => Some(tree),
+                _ => None,
+            };
+
+        let tree = parse(&input);
+
+        assert!(
+            expected_tree.map(|tree| tree.root) == tree.map(|tree| tree.root),
+            "trees of {:?} differ",
+            input
+                .iter()
+                .map(|token| token.t_type.clone())
+                .collect::<Vec<T>>()
+        );
+    }
+}
+
+#[test]
+fn it_backtracks_over_sibling_symbols() -> () {
+    let tokens = vec![
+        Token::new(Some(0u64), BacktrackingSyntax::ATerminal),
+        Token::new(Some(1u64), BacktrackingSyntax::BTerminal),
+    ];
+
+    assert!(backtracking_parser::parse(&tokens).is_some());
+}
+
+#[test]
+fn it_builds_the_trees_of_the_recursive_descent_parser() -> () {
+    assert_same_trees(&build_backtracking_grammar(), backtracking_parser::parse, 5);
+    assert_same_trees(&build_nested_grammar(), nested_parser::parse, 6);
+}
@@ -0,0 +1,231 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parenthesized, Data, DeriveInput, Error, Expr, Fields, Ident, Path, Token, Variant};
+
+const ATTRIBUTE: &str = "grammar";
+const EPSILON_KEYWORD: &str = "epsilon";
+const PRODUCTION_KEYWORD: &str = "production";
+const START_KEYWORD: &str = "start";
+
+/// Grammar declared by the attributes of an enum
+struct GrammarDeclaration {
+    epsilon_variant: Ident,
+    productions: Vec<(Ident, Vec<TokenStream>)>,
+    start_variant: Ident,
+}
+
+/// Expands `#[derive(Grammar)]` into an inherent `grammar` function.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let variants = match &input.data {
+        Data::Enum(data) => data.variants.iter().collect::<Vec<&Variant>>(),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Grammar` can only be derived for enums",
+            ))
+        }
+    };
+
+    let declaration = parse_declaration(input, &variants)?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let epsilon_variant = &declaration.epsilon_variant;
+    let start_variant = &declaration.start_variant;
+    let productions = declaration.productions.iter().map(|(input, output)| {
+        quote! {
+            ::artlr_syntax::grammar::context_free_grammar_production::ContextFreeGrammarProduction::new(
+                Self::#input,
+                vec![#(#output),*],
+            )
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub fn grammar() -> ::artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar<Self> {
+                ::artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar::new(
+                    Self::#epsilon_variant,
+                    Self::#start_variant,
+                    vec![#(#productions),*],
+                )
+            }
+        }
+    })
+}
+
+fn parse_declaration(
+    input: &DeriveInput,
+    variants: &[&Variant],
+) -> Result<GrammarDeclaration, Error> {
+    let mut epsilon_variant: Option<Ident> = None;
+    let mut start_variant: Option<Ident> = None;
+    let mut production_outputs: Vec<(Ident, Vec<Expr>)> = vec![];
+
+    for variant in variants {
+        for attribute in variant
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident(ATTRIBUTE))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident(EPSILON_KEYWORD) {
+                    parse_declaration_set_variant(&mut epsilon_variant, variant, &meta.path)
+                } else if meta.path.is_ident(START_KEYWORD) {
+                    parse_declaration_set_variant(&mut start_variant, variant, &meta.path)
+                } else if meta.path.is_ident(PRODUCTION_KEYWORD) {
+                    parse_declaration_check_unit_variant(variant, &meta.path)?;
+
+                    let content;
+                    parenthesized!(content in meta.input);
+
+                    let output: Vec<Expr> =
+                        Punctuated::<Expr, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect();
+
+                    if output.is_empty() {
+                        return Err(meta.error("expected production to have at least one symbol"));
+                    }
+
+                    production_outputs.push((variant.ident.clone(), output));
+
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `epsilon`, `production` or `start`"))
+                }
+            })?;
+        }
+    }
+
+    let epsilon_variant = epsilon_variant.ok_or_else(|| {
+        Error::new_spanned(&input.ident, "expected a `#[grammar(epsilon)]` variant")
+    })?;
+    let start_variant = start_variant.ok_or_else(|| {
+        Error::new_spanned(&input.ident, "expected a `#[grammar(start)]` variant")
+    })?;
+
+    if production_outputs
+        .iter()
+        .any(|(production_input, _)| production_input.eq(&epsilon_variant))
+    {
+        return Err(Error::new_spanned(
+            &epsilon_variant,
+            "expected epsilon symbol not to generate any symbols",
+        ));
+    }
+
+    if !production_outputs
+        .iter()
+        .any(|(production_input, _)| production_input.eq(&start_variant))
+    {
+        return Err(Error::new_spanned(
+            &start_variant,
+            "expected start symbol to have at least one production",
+        ));
+    }
+
+    let mut productions: Vec<(Ident, Vec<TokenStream>)> = vec![];
+
+    for (production_input, output) in production_outputs {
+        if output.len() > 1 {
+            if let Some(symbol) = output.iter().find(
+                |symbol| matches!(symbol, Expr::Path(path) if path.path.is_ident(&epsilon_variant)),
+            ) {
+                return Err(Error::new_spanned(
+                    symbol,
+                    "expected epsilon production not to have additional symbols",
+                ));
+            }
+        }
+
+        let output = output
+            .iter()
+            .map(|symbol| parse_declaration_symbol(variants, symbol))
+            .collect::<Result<Vec<TokenStream>, Error>>()?;
+
+        productions.push((production_input, output));
+    }
+
+    Ok(GrammarDeclaration {
+        epsilon_variant,
+        productions,
+        start_variant,
+    })
+}
+
+fn parse_declaration_check_unit_variant(
+    variant: &Variant,
+    spanned: &impl Spanned,
+) -> Result<(), Error> {
+    match variant.fields {
+        Fields::Unit => Ok(()),
+        _ => Err(Error::new(
+            spanned.span(),
+            format!("expected `{}` to be a unit variant", variant.ident),
+        )),
+    }
+}
+
+fn parse_declaration_set_variant(
+    target: &mut Option<Ident>,
+    variant: &Variant,
+    keyword: &Path,
+) -> Result<(), Error> {
+    parse_declaration_check_unit_variant(variant, keyword)?;
+
+    match target {
+        Some(previous_variant) => Err(Error::new_spanned(
+            keyword,
+            format!(
+                "`{}` was already declared by `{}`",
+                quote!(#keyword),
+                previous_variant
+            ),
+        )),
+        None => {
+            *target = Some(variant.ident.clone());
+
+            Ok(())
+        }
+    }
+}
+
+/// Expression of a production symbol, checking it references a variant of the enum.
+fn parse_declaration_symbol(variants: &[&Variant], symbol: &Expr) -> Result<TokenStream, Error> {
+    let (variant_ident, args) = match symbol {
+        Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+            (path.path.get_ident().unwrap(), None)
+        }
+        Expr::Call(call) => match call.func.as_ref() {
+            Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+                (path.path.get_ident().unwrap(), Some(&call.args))
+            }
+            _ => return Err(Error::new_spanned(symbol, "expected a variant")),
+        },
+        _ => return Err(Error::new_spanned(symbol, "expected a variant")),
+    };
+
+    let variant = variants
+        .iter()
+        .find(|variant| variant.ident.eq(variant_ident))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                variant_ident,
+                format!("unknown variant `{}`", variant_ident),
+            )
+        })?;
+
+    match (&variant.fields, args) {
+        (Fields::Unit, None) => Ok(quote!(Self::#variant_ident)),
+        (Fields::Unnamed(fields), Some(args)) if fields.unnamed.len() == args.len() => {
+            Ok(quote!(Self::#variant_ident(#args)))
+        }
+        _ => Err(Error::new_spanned(
+            symbol,
+            format!("expected symbol to match the fields of `{}`", variant_ident),
+        )),
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    mod expand {
+        use syn::{parse_quote, DeriveInput};
+
+        use crate::grammar_derive::expand;
+
+        fn expand_error(input: DeriveInput) -> String {
+            expand(&input).err().unwrap().to_string()
+        }
+
+        #[test]
+        fn it_expands_a_grammar_function() -> () {
+            let input: DeriveInput = parse_quote! {
+                enum SyntaxTokenTest {
+                    #[grammar(epsilon)]
+                    Epsilon,
+                    #[grammar(start, production(Terminal(TerminalTokenTypeTest::Id), Eof))]
+                    Module,
+                    Eof,
+                    Terminal(TerminalTokenTypeTest),
+                }
+            };
+
+            let output = expand(&input).unwrap().to_string();
+
+            assert!(output.contains("pub fn grammar ()"));
+            assert!(output.contains("Self :: Terminal (TerminalTokenTypeTest :: Id)"));
+        }
+
+        #[test]
+        fn it_fails_on_unknown_variant() -> () {
+            let input: DeriveInput = parse_quote! {
+                enum SyntaxTokenTest {
+                    #[grammar(epsilon)]
+                    Epsilon,
+                    #[grammar(start, production(Id, Eof))]
+                    Module,
+                    Eof,
+                }
+            };
+
+            assert_eq!("unknown variant `Id`", expand_error(input));
+        }
+
+        #[test]
+        fn it_fails_on_missing_epsilon_variant() -> () {
+            let input: DeriveInput = parse_quote! {
+                enum SyntaxTokenTest {
+                    #[grammar(start, production(Eof))]
+                    Module,
+                    Eof,
+                }
+            };
+
+            assert_eq!(
+                "expected a `#[grammar(epsilon)]` variant",
+                expand_error(input)
+            );
+        }
+
+        #[test]
+        fn it_fails_on_symbol_not_matching_variant_fields() -> () {
+            let input: DeriveInput = parse_quote! {
+                enum SyntaxTokenTest {
+                    #[grammar(epsilon)]
+                    Epsilon,
+                    #[grammar(start, production(Terminal))]
+                    Module,
+                    Terminal(TerminalTokenTypeTest),
+                }
+            };
+
+            assert_eq!(
+                "expected symbol to match the fields of `Terminal`",
+                expand_error(input)
+            );
+        }
+    }
+}
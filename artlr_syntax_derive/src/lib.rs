@@ -0,0 +1,42 @@
+mod grammar_derive;
+mod grammar_derive_spec;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives a `grammar` function for an enum of grammar symbols
+///
+/// Variants are annotated with `#[grammar(...)]` attributes:
+///
+/// - `epsilon`: the variant is the epsilon symbol. Exactly one variant is expected.
+/// - `start`: the variant is the initial symbol. Exactly one variant is expected.
+/// - `production(A, B, ...)`: the variant derives the given symbols. A variant may declare many
+///   productions, in the order they are provided.
+///
+/// Symbols of a production are variants of the enum, either unit variants (`A`) or tuple
+/// variants with their arguments (`Terminal(TerminalSymbol::Id)`). Unknown variants are reported
+/// at compile time.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Debug, Eq, Grammar, Hash, PartialEq)]
+/// enum Symbol {
+///     #[grammar(epsilon)]
+///     Epsilon,
+///     #[grammar(start, production(A, Eof))]
+///     S,
+///     #[grammar(production(ATerminal), production(Epsilon))]
+///     A,
+///     ATerminal,
+///     Eof,
+/// }
+///
+/// let grammar = Symbol::grammar();
+/// ```
+#[proc_macro_derive(Grammar, attributes(grammar))]
+pub fn derive_grammar(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    grammar_derive::expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
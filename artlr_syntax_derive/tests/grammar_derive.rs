@@ -0,0 +1,38 @@
+#![allow(clippy::unused_unit)]
+
+use artlr_syntax::grammar::first_follow_symbols::FirstFollowSymbols;
+use artlr_syntax_derive::Grammar;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum TerminalTokenTypeTest {
+    Eof,
+    Id,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Grammar, Hash, PartialEq)]
+enum SyntaxTokenTest {
+    #[grammar(epsilon)]
+    Epsilon,
+    #[grammar(start, production(A, Terminal(TerminalTokenTypeTest::Eof)))]
+    Module,
+    #[grammar(production(Terminal(TerminalTokenTypeTest::Id)), production(Epsilon))]
+    A,
+    Terminal(TerminalTokenTypeTest),
+}
+
+#[test]
+fn it_derives_a_grammar() -> () {
+    let grammar = SyntaxTokenTest::grammar();
+
+    assert_eq!(&SyntaxTokenTest::Module, grammar.get_initial_symbol());
+    assert_eq!(&SyntaxTokenTest::Epsilon, grammar.get_epsilon_symbol());
+    assert_eq!(
+        vec![SyntaxTokenTest::Module, SyntaxTokenTest::A],
+        grammar.get_non_terminal_symbols()
+    );
+    assert_eq!(
+        2,
+        grammar.get_productions(&SyntaxTokenTest::A).unwrap().len()
+    );
+    assert!(FirstFollowSymbols::from(&grammar).is_nullable(&SyntaxTokenTest::A));
+}
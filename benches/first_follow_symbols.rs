@@ -0,0 +1,115 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use artlr_syntax::grammar::context_free_grammar::ContextFreeGrammar;
+use artlr_syntax::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+use artlr_syntax::grammar::first_follow_symbols::FirstFollowSymbols;
+
+const EPSILON: u32 = 0;
+
+/// Builds a pseudo random grammar with nullable, recursive and mutually recursive rules.
+///
+/// Symbol `0` is epsilon, symbols `1..=non_terminals_count` are non terminal symbols and the
+/// remaining ones are terminal symbols.
+fn build_grammar(non_terminals_count: u32) -> ContextFreeGrammar<u32> {
+    let terminals_count: u32 = non_terminals_count / 4 + 1;
+    let mut state: u64 = 0x5eed;
+    let mut next_random = |bound: u32| -> u32 {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+
+        ((state >> 33) % u64::from(bound)) as u32
+    };
+
+    let mut productions: Vec<ContextFreeGrammarProduction<u32>> = vec![];
+
+    for non_terminal in 1..=non_terminals_count {
+        for _ in 0..1 + next_random(3) {
+            let output: Vec<u32> = if next_random(6) == 0 {
+                vec![EPSILON]
+            } else {
+                (0..1 + next_random(4))
+                    .map(|_| {
+                        if next_random(2) == 0 {
+                            1 + next_random(non_terminals_count)
+                        } else {
+                            non_terminals_count + 1 + next_random(terminals_count)
+                        }
+                    })
+                    .collect()
+            };
+
+            productions.push(ContextFreeGrammarProduction::new(non_terminal, output));
+        }
+    }
+
+    ContextFreeGrammar::new(EPSILON, 1, productions)
+}
+
+fn bench_first_follow_symbols(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("first_follow_symbols");
+
+    for non_terminals_count in [100, 500, 1000] {
+        let grammar = build_grammar(non_terminals_count);
+
+        group.bench_with_input(
+            BenchmarkId::new("fixed_point", non_terminals_count),
+            &grammar,
+            |bencher, grammar| {
+                bencher.iter(|| FirstFollowSymbols::from_fixed_point(black_box(grammar)))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("worklist", non_terminals_count),
+            &grammar,
+            |bencher, grammar| bencher.iter(|| FirstFollowSymbols::from(black_box(grammar))),
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_first_follow_symbols_update(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("first_follow_symbols_update");
+
+    for non_terminals_count in [100, 500, 1000] {
+        let mut grammar = build_grammar(non_terminals_count);
+        let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+
+        // A nullable production of a symbol deep in the grammar
+        let production = ContextFreeGrammarProduction::new(non_terminals_count, vec![EPSILON]);
+
+        grammar.add_production(production.clone());
+
+        group.bench_with_input(
+            BenchmarkId::new("update_added_production", non_terminals_count),
+            &grammar,
+            |bencher, grammar| {
+                bencher.iter_batched(
+                    || first_follow_symbols.clone(),
+                    |mut first_follow_symbols| {
+                        first_follow_symbols
+                            .update_added_production(black_box(grammar), black_box(&production))
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("rebuild", non_terminals_count),
+            &grammar,
+            |bencher, grammar| bencher.iter(|| FirstFollowSymbols::from(black_box(grammar))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_first_follow_symbols,
+    bench_first_follow_symbols_update
+);
+criterion_main!(benches);
@@ -1,5 +1,8 @@
+use crate::ast::abstract_syntax_node_kind::AbstractSyntaxNodeKind;
+
 pub struct AbstractSyntaxNode<TToken> {
     pub child_nodes: Vec<AbstractSyntaxNode<TToken>>,
+    pub kind: AbstractSyntaxNodeKind,
     pub token: TToken,
 }
 
@@ -8,6 +11,47 @@ impl<TToken> AbstractSyntaxNode<TToken> {
         child_nodes: Vec<AbstractSyntaxNode<TToken>>,
         token: TToken,
     ) -> AbstractSyntaxNode<TToken> {
-        AbstractSyntaxNode { child_nodes, token }
+        Self::with_kind(child_nodes, AbstractSyntaxNodeKind::Ok, token)
+    }
+
+    pub fn with_kind(
+        child_nodes: Vec<AbstractSyntaxNode<TToken>>,
+        kind: AbstractSyntaxNodeKind,
+        token: TToken,
+    ) -> AbstractSyntaxNode<TToken> {
+        AbstractSyntaxNode {
+            child_nodes,
+            kind,
+            token,
+        }
+    }
+
+    /// Whether the node or any of its descendants is not an `Ok` node.
+    pub fn has_errors(&self) -> bool {
+        let mut pending_nodes = vec![self];
+
+        while let Some(node) = pending_nodes.pop() {
+            if node.kind != AbstractSyntaxNodeKind::Ok {
+                return true;
+            }
+
+            pending_nodes.extend(node.child_nodes.iter());
+        }
+
+        false
+    }
+}
+
+impl<TToken: Clone> Clone for AbstractSyntaxNode<TToken> {
+    fn clone(&self) -> Self {
+        Self::with_kind(self.child_nodes.clone(), self.kind, self.token.clone())
+    }
+}
+
+impl<TToken: PartialEq> PartialEq for AbstractSyntaxNode<TToken> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.token.eq(&other.token)
+            && self.child_nodes.eq(&other.child_nodes)
     }
 }
@@ -0,0 +1,13 @@
+/// Kind of a node of an abstract syntax tree
+///
+/// - `Ok`: node derived from the input.
+/// - `Error`: non terminal symbol whose derivation was fixed.
+/// - `Missing`: symbol of a production which could not be derived.
+/// - `Skipped`: input token discarded by a fix.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AbstractSyntaxNodeKind {
+    Ok,
+    Error,
+    Missing,
+    Skipped,
+}
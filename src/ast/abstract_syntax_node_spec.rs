@@ -0,0 +1,32 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    mod has_errors {
+        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+        use crate::ast::abstract_syntax_node_kind::AbstractSyntaxNodeKind;
+
+        #[test]
+        fn it_returns_false_if_every_node_is_ok() -> () {
+            let node = AbstractSyntaxNode::new(vec![AbstractSyntaxNode::new(vec![], 1u64)], 0u64);
+
+            assert!(!node.has_errors());
+        }
+
+        #[test]
+        fn it_returns_true_if_a_descendant_is_not_ok() -> () {
+            let node = AbstractSyntaxNode::new(
+                vec![AbstractSyntaxNode::new(
+                    vec![AbstractSyntaxNode::with_kind(
+                        vec![],
+                        AbstractSyntaxNodeKind::Missing,
+                        2u64,
+                    )],
+                    1u64,
+                )],
+                0u64,
+            );
+
+            assert!(node.has_errors());
+        }
+    }
+}
@@ -1,2 +1,5 @@
 pub mod abstract_syntax_node;
+pub mod abstract_syntax_node_kind;
 pub mod abstract_syntax_tree;
+
+mod abstract_syntax_node_spec;
@@ -0,0 +1,64 @@
+use std::fmt::Debug;
+
+use crate::codegen::rust_symbol_formatter::RustSymbolFormatter;
+
+/// Rust symbol formatter based on the `Debug` representation of the symbols
+///
+/// Intended for enums of unit variants: the symbol `Syntax::ATerminal` of an enum located at
+/// `crate::Syntax` is formatted as the `crate::Syntax::ATerminal` path and the `a_terminal`
+/// identifier.
+pub struct DebugRustSymbolFormatter {
+    type_path: String,
+}
+
+impl DebugRustSymbolFormatter {
+    pub fn new(type_path: String) -> Self {
+        Self { type_path }
+    }
+
+    fn to_snake_case(name: &str) -> String {
+        let characters: Vec<char> = name.chars().collect();
+        let mut snake_case_name = String::new();
+
+        for (index, character) in characters.iter().enumerate() {
+            if !character.is_alphanumeric() {
+                if !snake_case_name.is_empty() && !snake_case_name.ends_with('_') {
+                    snake_case_name.push('_');
+                }
+
+                continue;
+            }
+
+            if character.is_uppercase() && index > 0 {
+                let previous_character = characters[index - 1];
+                let is_next_lowercase = characters
+                    .get(index + 1)
+                    .is_some_and(|next_character| next_character.is_lowercase());
+
+                if (previous_character.is_lowercase() || previous_character.is_numeric())
+                    || (previous_character.is_uppercase() && is_next_lowercase)
+                {
+                    snake_case_name.push('_');
+                }
+            }
+
+            snake_case_name.extend(character.to_lowercase());
+        }
+
+        snake_case_name.trim_end_matches('_').to_string()
+    }
+}
+
+impl<T: Debug> RustSymbolFormatter<T> for DebugRustSymbolFormatter {
+    fn format_path(&self, symbol: &T) -> String {
+        format!("{}::{:?}", self.type_path, symbol)
+    }
+
+    fn format_identifier(&self, symbol: &T) -> String {
+        Self::to_snake_case(&format!("{:?}", symbol))
+    }
+
+    fn format_type(&self) -> String {
+        self.type_path.clone()
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    mod format {
+        use crate::codegen::debug_rust_symbol_formatter::DebugRustSymbolFormatter;
+        use crate::codegen::rust_symbol_formatter::RustSymbolFormatter;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            ATerminal,
+            Expression2,
+            HTTPRequest,
+            Id,
+        }
+
+        #[test]
+        fn it_formats_paths() -> () {
+            let formatter = DebugRustSymbolFormatter::new(String::from("crate::Syntax"));
+
+            assert_eq!(
+                formatter.format_path(&SyntaxTokenTest::ATerminal),
+                "crate::Syntax::ATerminal"
+            );
+            assert_eq!(
+                RustSymbolFormatter::<SyntaxTokenTest>::format_type(&formatter),
+                "crate::Syntax"
+            );
+        }
+
+        #[test]
+        fn it_formats_snake_case_identifiers() -> () {
+            let formatter = DebugRustSymbolFormatter::new(String::from("crate::Syntax"));
+
+            assert_eq!(
+                formatter.format_identifier(&SyntaxTokenTest::ATerminal),
+                "a_terminal"
+            );
+            assert_eq!(
+                formatter.format_identifier(&SyntaxTokenTest::Expression2),
+                "expression2"
+            );
+            assert_eq!(
+                formatter.format_identifier(&SyntaxTokenTest::HTTPRequest),
+                "http_request"
+            );
+            assert_eq!(formatter.format_identifier(&SyntaxTokenTest::Id), "id");
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod debug_rust_symbol_formatter;
+mod debug_rust_symbol_formatter_spec;
+pub mod rust_parser_generator;
+mod rust_parser_generator_spec;
+pub mod rust_symbol_formatter;
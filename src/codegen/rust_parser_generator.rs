@@ -0,0 +1,485 @@
+use std::fmt::Write;
+use std::hash::Hash;
+
+use crate::codegen::rust_symbol_formatter::RustSymbolFormatter;
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+const INDENTATION: &str = "    ";
+
+/// Rust parser generator
+///
+/// Generates the Rust source code of a parser specialized for a grammar. The generated parser
+/// has a function per non terminal symbol matching the type of the current token against the
+/// transitions table, so no table is built at runtime.
+///
+/// The generated source is meant to be written from a `build.rs` script and included in its own
+/// module:
+///
+/// ```ignore
+/// mod parser {
+///     include!(concat!(env!("OUT_DIR"), "/parser.rs"));
+/// }
+/// ```
+///
+/// The generated module exposes a `parse` function which receives a slice of tokens and returns
+/// the syntax tree of the input, if any. The tree is the one `RecursiveDescentParser` builds:
+/// when a transition has several candidate productions they are tried in order, and when a
+/// symbol of a production fails the last symbol with candidate productions left is derived
+/// again with its next candidate. Tokens following the derivation of the initial symbol are
+/// ignored. Left recursive grammars are not supported.
+pub struct RustParserGenerator<'a, T, TFormatter: RustSymbolFormatter<T>> {
+    formatter: TFormatter,
+    grammar: &'a ContextFreeGrammar<T>,
+    transitions: &'a RecursiveDescentParserTransitions<T>,
+}
+
+impl<'a, T: Clone + Eq + Hash, TFormatter: RustSymbolFormatter<T>>
+    RustParserGenerator<'a, T, TFormatter>
+{
+    pub fn new(
+        grammar: &'a ContextFreeGrammar<T>,
+        transitions: &'a RecursiveDescentParserTransitions<T>,
+        formatter: TFormatter,
+    ) -> Self {
+        Self {
+            formatter,
+            grammar,
+            transitions,
+        }
+    }
+
+    pub fn generate(&self) -> String {
+        let mut source = String::new();
+
+        self.generate_header(&mut source);
+        self.generate_parse_function(&mut source);
+        self.generate_helper_functions(&mut source);
+
+        for non_terminal_symbol in self.get_sorted_non_terminal_symbols() {
+            self.generate_non_terminal_function(&mut source, &non_terminal_symbol);
+
+            let productions = self.grammar.get_productions(&non_terminal_symbol).unwrap();
+
+            for (production_index, production) in productions.iter().enumerate() {
+                self.generate_production_function(&mut source, production, production_index);
+            }
+        }
+
+        source
+    }
+
+    fn generate_header(&self, source: &mut String) {
+        let symbol_type = self.formatter.format_type();
+
+        let _ = writeln!(
+            source,
+            "// Generated by artlr_syntax, do not edit this file manually.
+
+use ::artlr_syntax::ast::abstract_syntax_node::AbstractSyntaxNode;
+use ::artlr_syntax::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use ::artlr_syntax::token::token::Token;
+
+/// Node of a symbol, position of the next token and next candidate production of the symbol.
+type Derivation<TLex> = Option<(AbstractSyntaxNode<Token<TLex, {symbol_type}>>, usize, Option<usize>)>;
+
+/// Node of a production and position of the next token.
+type Production<TLex> = Option<(AbstractSyntaxNode<Token<TLex, {symbol_type}>>, usize)>;
+
+/// Nodes of the symbols of a production and position of the next token.
+type Sequence<TLex> = Option<(Vec<AbstractSyntaxNode<Token<TLex, {symbol_type}>>>, usize)>;",
+            symbol_type = symbol_type,
+        );
+    }
+
+    fn generate_parse_function(&self, source: &mut String) {
+        let symbol_type = self.formatter.format_type();
+        let initial_symbol_function =
+            self.get_non_terminal_function_name(self.grammar.get_initial_symbol());
+
+        let _ = writeln!(
+            source,
+            "
+pub fn parse<TLex: Clone>(
+    tokens: &[Token<TLex, {symbol_type}>],
+) -> Option<AbstractSyntaxTree<Token<TLex, {symbol_type}>>> {{
+    {initial_symbol_function}(tokens, 0, 0).map(|(root, _, _)| AbstractSyntaxTree::new(root))
+}}",
+            symbol_type = symbol_type,
+            initial_symbol_function = initial_symbol_function,
+        );
+    }
+
+    fn generate_helper_functions(&self, source: &mut String) {
+        let symbol_type = self.formatter.format_type();
+
+        let _ = writeln!(
+            source,
+            "
+/// Derives the symbols of a production, deriving again the last symbol with candidate
+/// productions left when a symbol fails.
+#[allow(dead_code)]
+fn derive_sequence<TLex: Clone>(
+    symbols_count: usize,
+    token_position: usize,
+    derive_symbol: impl Fn(usize, usize, usize) -> Derivation<TLex>,
+) -> Sequence<TLex> {{
+    let mut derivations = Vec::with_capacity(symbols_count);
+    let mut derivation = derive_symbol(0, token_position, 0);
+
+    loop {{
+        match derivation {{
+            Some(symbol_derivation) => derivations.push(symbol_derivation),
+            None => {{
+                let (_, _, next_candidate) = derivations.pop()?;
+                let current_position = derivations
+                    .last()
+                    .map_or(token_position, |(_, next_position, _)| *next_position);
+
+                derivation = next_candidate.and_then(|next_candidate| {{
+                    derive_symbol(derivations.len(), current_position, next_candidate)
+                }});
+
+                continue;
+            }}
+        }}
+
+        let current_position = derivations
+            .last()
+            .map_or(token_position, |(_, next_position, _)| *next_position);
+
+        if derivations.len() == symbols_count {{
+            let child_nodes = derivations.into_iter().map(|(node, _, _)| node).collect();
+
+            return Some((child_nodes, current_position));
+        }}
+
+        derivation = derive_symbol(derivations.len(), current_position, 0);
+    }}
+}}
+
+/// Derives a terminal symbol.
+#[allow(dead_code)]
+fn derive_terminal<TLex: Clone>(
+    tokens: &[Token<TLex, {symbol_type}>],
+    token_position: usize,
+    is_symbol: impl Fn(&{symbol_type}) -> bool,
+) -> Derivation<TLex> {{
+    tokens
+        .get(token_position)
+        .filter(|token| is_symbol(&token.t_type))
+        .map(|token| (AbstractSyntaxNode::new(vec![], token.clone()), token_position + 1, None))
+}}",
+            symbol_type = symbol_type,
+        );
+    }
+
+    fn generate_non_terminal_function(&self, source: &mut String, non_terminal_symbol: &T) {
+        self.generate_function_signature(
+            source,
+            &self.formatter.format_path(non_terminal_symbol),
+            &self.get_non_terminal_function_name(non_terminal_symbol),
+            "tokens",
+            "    first_candidate: usize,\n",
+            "Derivation",
+        );
+
+        let _ = writeln!(
+            source,
+            "{}let candidates: &[usize] = match tokens.get(token_position).map(|token| &token.t_type) {{",
+            INDENTATION,
+        );
+
+        for (terminal_symbols, production_indexes) in self.get_match_arms(non_terminal_symbol) {
+            let patterns: Vec<String> = terminal_symbols
+                .iter()
+                .map(|terminal_symbol| {
+                    format!("Some({})", self.formatter.format_path(terminal_symbol))
+                })
+                .collect();
+
+            let candidates: Vec<String> = production_indexes
+                .iter()
+                .map(|production_index| production_index.to_string())
+                .collect();
+
+            let _ = writeln!(
+                source,
+                "{indentation}{indentation}{} => &[{}],",
+                patterns.join(" | "),
+                candidates.join(", "),
+                indentation = INDENTATION,
+            );
+        }
+
+        let _ = writeln!(
+            source,
+            "{indentation}{indentation}_ => &[],
+{indentation}}};
+
+{indentation}for (candidate, production_index) in candidates.iter().enumerate().skip(first_candidate) {{
+{indentation}{indentation}let production = match production_index {{",
+            indentation = INDENTATION
+        );
+
+        let productions_count = self
+            .grammar
+            .get_productions(non_terminal_symbol)
+            .map_or(0, Vec::len);
+
+        for production_index in 0..productions_count {
+            let _ = writeln!(
+                source,
+                "{indentation}{indentation}{indentation}{} => {}(tokens, token_position),",
+                production_index,
+                self.get_production_function_name(non_terminal_symbol, production_index),
+                indentation = INDENTATION,
+            );
+        }
+
+        let _ = writeln!(
+            source,
+            "{indentation}{indentation}{indentation}_ => None,
+{indentation}{indentation}}};
+
+{indentation}{indentation}if let Some((node, next_position)) = production {{
+{indentation}{indentation}{indentation}return Some((node, next_position, Some(candidate + 1)));
+{indentation}{indentation}}}
+{indentation}}}
+
+{indentation}None
+}}",
+            indentation = INDENTATION
+        );
+    }
+
+    fn generate_production_function(
+        &self,
+        source: &mut String,
+        production: &ContextFreeGrammarProduction<T>,
+        production_index: usize,
+    ) {
+        let production_output: Vec<String> = production
+            .output
+            .iter()
+            .map(|symbol| self.formatter.format_path(symbol))
+            .collect();
+
+        let is_epsilon_production = production
+            .output
+            .first()
+            .is_some_and(|symbol| self.grammar.get_epsilon_symbol().eq(symbol));
+
+        // Epsilon productions do not consume tokens
+        let tokens_parameter = if is_epsilon_production {
+            "_tokens"
+        } else {
+            "tokens"
+        };
+
+        self.generate_function_signature(
+            source,
+            &format!(
+                "{} → {}",
+                self.formatter.format_path(&production.input),
+                production_output.join(" ")
+            ),
+            &self.get_production_function_name(&production.input, production_index),
+            tokens_parameter,
+            "",
+            "Production",
+        );
+
+        let input_node = format!(
+            "{}let node = AbstractSyntaxNode::new(child_nodes, Token::new(None, {}));",
+            INDENTATION,
+            self.formatter.format_path(&production.input)
+        );
+
+        if is_epsilon_production {
+            let _ = writeln!(
+                source,
+                "{indentation}let child_nodes = vec![AbstractSyntaxNode::new(vec![], Token::new(None, {}))];
+{}
+
+{indentation}Some((node, token_position))
+}}",
+                self.formatter.format_path(self.grammar.get_epsilon_symbol()),
+                input_node,
+                indentation = INDENTATION,
+            );
+
+            return;
+        }
+
+        // Terminal symbols have a single candidate
+        let first_candidate_parameter = if production
+            .output
+            .iter()
+            .any(|symbol| self.grammar.is_non_terminal(symbol))
+        {
+            "first_candidate"
+        } else {
+            "_first_candidate"
+        };
+
+        let _ = writeln!(
+            source,
+            "{indentation}let (child_nodes, next_position) = derive_sequence(
+{indentation}{indentation}{},
+{indentation}{indentation}token_position,
+{indentation}{indentation}|symbol_index, current_position, {}| match symbol_index {{",
+            production.output.len(),
+            first_candidate_parameter,
+            indentation = INDENTATION,
+        );
+
+        for (symbol_index, symbol) in production.output.iter().enumerate() {
+            let symbol_derivation = if self.grammar.is_non_terminal(symbol) {
+                format!(
+                    "{}(tokens, current_position, first_candidate)",
+                    self.get_non_terminal_function_name(symbol)
+                )
+            } else {
+                format!(
+                    "derive_terminal(tokens, current_position, |t_type| matches!(t_type, {}))",
+                    self.formatter.format_path(symbol)
+                )
+            };
+
+            let _ = writeln!(
+                source,
+                "{indentation}{indentation}{indentation}{} => {},",
+                symbol_index,
+                symbol_derivation,
+                indentation = INDENTATION,
+            );
+        }
+
+        let _ = writeln!(
+            source,
+            "{indentation}{indentation}{indentation}_ => None,
+{indentation}{indentation}}},
+{indentation})?;
+
+{}
+
+{indentation}Some((node, next_position))
+}}",
+            input_node,
+            indentation = INDENTATION,
+        );
+    }
+
+    fn generate_function_signature(
+        &self,
+        source: &mut String,
+        description: &str,
+        function_name: &str,
+        tokens_parameter: &str,
+        additional_parameters: &str,
+        return_type: &str,
+    ) {
+        let _ = writeln!(
+            source,
+            "
+/// Derives `{}`.
+#[allow(dead_code)]
+fn {}<TLex: Clone>(
+    {}: &[Token<TLex, {}>],
+    token_position: usize,
+{}) -> {}<TLex> {{",
+            description,
+            function_name,
+            tokens_parameter,
+            self.formatter.format_type(),
+            additional_parameters,
+            return_type,
+        );
+    }
+
+    /// Groups the terminal symbols of a non terminal symbol by their candidate productions.
+    ///
+    /// Arms are sorted by the formatted path of their first terminal symbol so the generated
+    /// source is deterministic.
+    fn get_match_arms(&self, non_terminal_symbol: &T) -> Vec<(Vec<T>, Vec<usize>)> {
+        let mut match_arms: Vec<(Vec<T>, Vec<usize>)> = vec![];
+
+        for terminal_symbol in self.get_sorted_terminal_symbols() {
+            let production_indexes: Vec<usize> = self
+                .grammar
+                .get_symbol_id(non_terminal_symbol)
+                .zip(self.grammar.get_symbol_id(&terminal_symbol))
+                .and_then(|(non_terminal_symbol_id, terminal_symbol_id)| {
+                    self.transitions
+                        .get_production_indexes_by_id(non_terminal_symbol_id, terminal_symbol_id)
+                })
+                .map_or(vec![], |production_indexes| {
+                    production_indexes
+                        .iter()
+                        .map(|production_index| *production_index as usize)
+                        .collect()
+                });
+
+            if production_indexes.is_empty() {
+                continue;
+            }
+
+            match match_arms
+                .iter_mut()
+                .find(|(_, arm_production_indexes)| arm_production_indexes == &production_indexes)
+            {
+                Some((arm_terminal_symbols, _)) => arm_terminal_symbols.push(terminal_symbol),
+                None => match_arms.push((vec![terminal_symbol], production_indexes)),
+            }
+        }
+
+        match_arms
+    }
+
+    fn get_non_terminal_function_name(&self, symbol: &T) -> String {
+        format!("parse_{}", self.formatter.format_identifier(symbol))
+    }
+
+    fn get_production_function_name(&self, symbol: &T, production_index: usize) -> String {
+        format!(
+            "derive_{}_{}",
+            self.formatter.format_identifier(symbol),
+            production_index
+        )
+    }
+
+    /// Non terminal symbols, the initial one first and the others sorted by identifier.
+    fn get_sorted_non_terminal_symbols(&self) -> Vec<T> {
+        let initial_symbol = self.grammar.get_initial_symbol();
+
+        let mut non_terminal_symbols: Vec<T> = self
+            .grammar
+            .get_non_terminal_symbols()
+            .into_iter()
+            .filter(|symbol| initial_symbol.ne(symbol))
+            .collect();
+
+        non_terminal_symbols.sort_by_key(|symbol| self.formatter.format_identifier(symbol));
+
+        if self.grammar.is_non_terminal(initial_symbol) {
+            non_terminal_symbols.insert(0, initial_symbol.clone());
+        }
+
+        non_terminal_symbols
+    }
+
+    fn get_sorted_terminal_symbols(&self) -> Vec<T> {
+        let mut terminal_symbols: Vec<T> = self
+            .grammar
+            .get_terminal_symbols()
+            .into_iter()
+            .filter(|symbol| self.grammar.get_epsilon_symbol().ne(symbol))
+            .collect();
+
+        terminal_symbols.sort_by_key(|symbol| self.formatter.format_path(symbol));
+
+        terminal_symbols
+    }
+}
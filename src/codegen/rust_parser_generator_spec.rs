@@ -0,0 +1,279 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    mod generate {
+        use crate::codegen::debug_rust_symbol_formatter::DebugRustSymbolFormatter;
+        use crate::codegen::rust_parser_generator::RustParserGenerator;
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+        use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            A,
+            ATerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
+
+        #[test]
+        fn it_generates_a_parser() -> () {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<SyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::S,
+                    vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::A,
+                    vec![SyntaxTokenTest::ATerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::A,
+                    vec![SyntaxTokenTest::Epsilon],
+                ),
+            ];
+
+            let grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+
+            let generator = RustParserGenerator::new(
+                &grammar,
+                &transitions,
+                DebugRustSymbolFormatter::new(String::from("super::Syntax")),
+            );
+
+            let expected_source = r#"// Generated by artlr_syntax, do not edit this file manually.
+
+use ::artlr_syntax::ast::abstract_syntax_node::AbstractSyntaxNode;
+use ::artlr_syntax::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use ::artlr_syntax::token::token::Token;
+
+/// Node of a symbol, position of the next token and next candidate production of the symbol.
+type Derivation<TLex> = Option<(AbstractSyntaxNode<Token<TLex, super::Syntax>>, usize, Option<usize>)>;
+
+/// Node of a production and position of the next token.
+type Production<TLex> = Option<(AbstractSyntaxNode<Token<TLex, super::Syntax>>, usize)>;
+
+/// Nodes of the symbols of a production and position of the next token.
+type Sequence<TLex> = Option<(Vec<AbstractSyntaxNode<Token<TLex, super::Syntax>>>, usize)>;
+
+pub fn parse<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+) -> Option<AbstractSyntaxTree<Token<TLex, super::Syntax>>> {
+    parse_s(tokens, 0, 0).map(|(root, _, _)| AbstractSyntaxTree::new(root))
+}
+
+/// Derives the symbols of a production, deriving again the last symbol with candidate
+/// productions left when a symbol fails.
+#[allow(dead_code)]
+fn derive_sequence<TLex: Clone>(
+    symbols_count: usize,
+    token_position: usize,
+    derive_symbol: impl Fn(usize, usize, usize) -> Derivation<TLex>,
+) -> Sequence<TLex> {
+    let mut derivations = Vec::with_capacity(symbols_count);
+    let mut derivation = derive_symbol(0, token_position, 0);
+
+    loop {
+        match derivation {
+            Some(symbol_derivation) => derivations.push(symbol_derivation),
+            None => {
+                let (_, _, next_candidate) = derivations.pop()?;
+                let current_position = derivations
+                    .last()
+                    .map_or(token_position, |(_, next_position, _)| *next_position);
+
+                derivation = next_candidate.and_then(|next_candidate| {
+                    derive_symbol(derivations.len(), current_position, next_candidate)
+                });
+
+                continue;
+            }
+        }
+
+        let current_position = derivations
+            .last()
+            .map_or(token_position, |(_, next_position, _)| *next_position);
+
+        if derivations.len() == symbols_count {
+            let child_nodes = derivations.into_iter().map(|(node, _, _)| node).collect();
+
+            return Some((child_nodes, current_position));
+        }
+
+        derivation = derive_symbol(derivations.len(), current_position, 0);
+    }
+}
+
+/// Derives a terminal symbol.
+#[allow(dead_code)]
+fn derive_terminal<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+    is_symbol: impl Fn(&super::Syntax) -> bool,
+) -> Derivation<TLex> {
+    tokens
+        .get(token_position)
+        .filter(|token| is_symbol(&token.t_type))
+        .map(|token| (AbstractSyntaxNode::new(vec![], token.clone()), token_position + 1, None))
+}
+
+/// Derives `super::Syntax::S`.
+#[allow(dead_code)]
+fn parse_s<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+    first_candidate: usize,
+) -> Derivation<TLex> {
+    let candidates: &[usize] = match tokens.get(token_position).map(|token| &token.t_type) {
+        Some(super::Syntax::ATerminal) | Some(super::Syntax::Eof) => &[0],
+        _ => &[],
+    };
+
+    for (candidate, production_index) in candidates.iter().enumerate().skip(first_candidate) {
+        let production = match production_index {
+            0 => derive_s_0(tokens, token_position),
+            _ => None,
+        };
+
+        if let Some((node, next_position)) = production {
+            return Some((node, next_position, Some(candidate + 1)));
+        }
+    }
+
+    None
+}
+
+/// Derives `super::Syntax::S → super::Syntax::A super::Syntax::Eof`.
+#[allow(dead_code)]
+fn derive_s_0<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+) -> Production<TLex> {
+    let (child_nodes, next_position) = derive_sequence(
+        2,
+        token_position,
+        |symbol_index, current_position, first_candidate| match symbol_index {
+            0 => parse_a(tokens, current_position, first_candidate),
+            1 => derive_terminal(tokens, current_position, |t_type| matches!(t_type, super::Syntax::Eof)),
+            _ => None,
+        },
+    )?;
+
+    let node = AbstractSyntaxNode::new(child_nodes, Token::new(None, super::Syntax::S));
+
+    Some((node, next_position))
+}
+
+/// Derives `super::Syntax::A`.
+#[allow(dead_code)]
+fn parse_a<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+    first_candidate: usize,
+) -> Derivation<TLex> {
+    let candidates: &[usize] = match tokens.get(token_position).map(|token| &token.t_type) {
+        Some(super::Syntax::ATerminal) => &[0],
+        Some(super::Syntax::Eof) => &[1],
+        _ => &[],
+    };
+
+    for (candidate, production_index) in candidates.iter().enumerate().skip(first_candidate) {
+        let production = match production_index {
+            0 => derive_a_0(tokens, token_position),
+            1 => derive_a_1(tokens, token_position),
+            _ => None,
+        };
+
+        if let Some((node, next_position)) = production {
+            return Some((node, next_position, Some(candidate + 1)));
+        }
+    }
+
+    None
+}
+
+/// Derives `super::Syntax::A → super::Syntax::ATerminal`.
+#[allow(dead_code)]
+fn derive_a_0<TLex: Clone>(
+    tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+) -> Production<TLex> {
+    let (child_nodes, next_position) = derive_sequence(
+        1,
+        token_position,
+        |symbol_index, current_position, _first_candidate| match symbol_index {
+            0 => derive_terminal(tokens, current_position, |t_type| matches!(t_type, super::Syntax::ATerminal)),
+            _ => None,
+        },
+    )?;
+
+    let node = AbstractSyntaxNode::new(child_nodes, Token::new(None, super::Syntax::A));
+
+    Some((node, next_position))
+}
+
+/// Derives `super::Syntax::A → super::Syntax::Epsilon`.
+#[allow(dead_code)]
+fn derive_a_1<TLex: Clone>(
+    _tokens: &[Token<TLex, super::Syntax>],
+    token_position: usize,
+) -> Production<TLex> {
+    let child_nodes = vec![AbstractSyntaxNode::new(vec![], Token::new(None, super::Syntax::Epsilon))];
+    let node = AbstractSyntaxNode::new(child_nodes, Token::new(None, super::Syntax::A));
+
+    Some((node, token_position))
+}
+"#;
+
+            assert_eq!(generator.generate(), expected_source);
+        }
+
+        #[test]
+        fn it_generates_ordered_candidate_productions() -> () {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<SyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::S,
+                    vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::A,
+                    vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A],
+                ),
+                ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::A,
+                    vec![SyntaxTokenTest::ATerminal],
+                ),
+            ];
+
+            let grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+
+            let generator = RustParserGenerator::new(
+                &grammar,
+                &transitions,
+                DebugRustSymbolFormatter::new(String::from("super::Syntax")),
+            );
+
+            let expected_arm = "        Some(super::Syntax::ATerminal) => &[0, 1],\n";
+
+            assert!(generator.generate().contains(expected_arm));
+        }
+    }
+}
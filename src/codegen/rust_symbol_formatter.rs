@@ -0,0 +1,15 @@
+/// Rust symbol formatter
+///
+/// Formats the symbols of a grammar as Rust source code when generating a parser.
+pub trait RustSymbolFormatter<T> {
+    /// Formats a symbol as a Rust path usable both as an expression and as a pattern, for example
+    /// `crate::Syntax::Id`.
+    fn format_path(&self, symbol: &T) -> String;
+
+    /// Formats a symbol as a snake case identifier used to name the generated functions, for
+    /// example `id`. Every symbol is expected to be formatted as a different identifier.
+    fn format_identifier(&self, symbol: &T) -> String;
+
+    /// Formats the symbols type as a Rust path, for example `crate::Syntax`.
+    fn format_type(&self) -> String;
+}
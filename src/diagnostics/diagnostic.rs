@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+
+use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::parser::failed_symbol::FailedSymbol;
+use crate::parser::fixed_production::FixedProduction;
+use crate::parser::fixed_production_part::FixedProductionPart;
+use crate::parser::fixed_symbol::FixedSymbol;
+use crate::token::token::Token;
+use crate::token::token_span::TokenSpan;
+
+/// Syntax error found at a token
+///
+/// - `expected_symbols`: terminal symbols which could be found instead, in order of appearance
+///   in the grammar.
+/// - `expects_end_of_input`: whether the end of the input could be found instead.
+/// - `found_token`: token found, `None` at the end of the input.
+/// - `token_position`: position of the token.
+pub struct Diagnostic<TLex, TSyntax> {
+    pub expected_symbols: Vec<TSyntax>,
+    pub expects_end_of_input: bool,
+    pub found_token: Option<Token<TLex, TSyntax>>,
+    pub token_position: usize,
+}
+
+impl<TLex, TSyntax> Diagnostic<TLex, TSyntax> {
+    pub fn new(
+        expected_symbols: Vec<TSyntax>,
+        found_token: Option<Token<TLex, TSyntax>>,
+        token_position: usize,
+    ) -> Self {
+        Self {
+            expected_symbols,
+            expects_end_of_input: false,
+            found_token,
+            token_position,
+        }
+    }
+
+    /// Builds the diagnostic of a token found after the derivation of the initial symbol.
+    pub fn expecting_end_of_input(
+        found_token: Token<TLex, TSyntax>,
+        token_position: usize,
+    ) -> Self {
+        Self {
+            expected_symbols: vec![],
+            expects_end_of_input: true,
+            found_token: Some(found_token),
+            token_position,
+        }
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone + Eq + Hash> Diagnostic<TLex, TSyntax> {
+    /// Builds the diagnostic of the farthest failure of a failed symbol.
+    ///
+    /// Every symbol failing at the farthest token contributes to the expected symbols: given a
+    /// failed production A → αXβ, these are FIRST(Xβ), FOLLOW(A) replacing epsilon if Xβ is
+    /// nullable.
+    #[allow(clippy::type_complexity)]
+    pub fn from_failed_symbol(
+        first_follow_symbols: &FirstFollowSymbols<TSyntax>,
+        failed_symbol: &FailedSymbol<TLex, TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+    ) -> Self {
+        let symbol_table = first_follow_symbols.get_symbol_table();
+
+        let mut token_position = failed_symbol.token_position;
+        let mut expected_symbol_set = SymbolSet::new();
+        let mut pending_failed_symbols: Vec<(
+            &FailedSymbol<TLex, TSyntax>,
+            Option<u32>,
+            &[TSyntax],
+        )> = vec![(failed_symbol, None, &[])];
+
+        while let Some((failed_symbol, production_input_id, pending_symbols)) =
+            pending_failed_symbols.pop()
+        {
+            if !failed_symbol.failed_productions.is_empty() {
+                let symbol_id = symbol_table.get_id(&failed_symbol.symbol_to_derive);
+
+                for failed_production in failed_symbol.failed_productions.iter().rev() {
+                    pending_failed_symbols.push((
+                        &failed_production.failed_symbol,
+                        symbol_id,
+                        &failed_production.pending_symbols,
+                    ));
+                }
+
+                continue;
+            }
+
+            if failed_symbol.token_position < token_position {
+                continue;
+            }
+
+            if failed_symbol.token_position > token_position {
+                token_position = failed_symbol.token_position;
+                expected_symbol_set = SymbolSet::new();
+            }
+
+            let symbol_ids_option: Option<Vec<u32>> =
+                std::iter::once(&failed_symbol.symbol_to_derive)
+                    .chain(pending_symbols.iter())
+                    .map(|symbol| symbol_table.get_id(symbol))
+                    .collect();
+
+            let failed_symbol_expected_symbol_set =
+                symbol_ids_option.and_then(|symbol_ids| match production_input_id {
+                    Some(production_input_id) => first_follow_symbols
+                        .get_expected_symbol_set(&symbol_ids, production_input_id),
+                    None => first_follow_symbols
+                        .get_first_symbol_set_of_sequence(&symbol_ids)
+                        .map(|mut first_symbol_set| {
+                            first_symbol_set.remove(first_follow_symbols.get_epsilon_symbol_id());
+
+                            first_symbol_set
+                        }),
+                });
+
+            if let Some(failed_symbol_expected_symbol_set) = failed_symbol_expected_symbol_set {
+                expected_symbol_set.union_with(&failed_symbol_expected_symbol_set);
+            }
+        }
+
+        Self::new(
+            expected_symbol_set
+                .iter()
+                .map(|symbol_id| symbol_table.get_symbol(symbol_id).clone())
+                .collect(),
+            tokens.get(token_position).cloned(),
+            token_position,
+        )
+    }
+
+    /// Builds the diagnostics of the gaps of a fixed symbol derived from the first token, in
+    /// order of appearance.
+    ///
+    /// The expected symbols of a gap are the FIRST symbols of its skipped symbols, the found
+    /// token being the token at the position of the gap.
+    pub fn from_fixed_symbol(
+        first_follow_symbols: &FirstFollowSymbols<TSyntax>,
+        fixed_symbol: &FixedSymbol<TLex, TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+    ) -> Vec<Self> {
+        Self::from_fixed_symbol_with_gap_diagnostics(
+            first_follow_symbols,
+            fixed_symbol,
+            tokens,
+            HashMap::new(),
+        )
+    }
+
+    /// Builds the diagnostics of the gaps of a fixed symbol, gaps bounded by a key of
+    /// `gap_diagnostics` being described by its diagnostic instead.
+    ///
+    /// Gaps sharing the bounds of an already described gap are cascades of the same error, so
+    /// they are not described again.
+    pub(crate) fn from_fixed_symbol_with_gap_diagnostics(
+        first_follow_symbols: &FirstFollowSymbols<TSyntax>,
+        fixed_symbol: &FixedSymbol<TLex, TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        gap_diagnostics: HashMap<(usize, usize), Self>,
+    ) -> Vec<Self> {
+        let mut diagnostics = vec![];
+        let mut token_position = 0;
+        let mut gap_diagnostics: HashMap<(usize, usize), Option<Self>> = gap_diagnostics
+            .into_iter()
+            .map(|(gap_bounds, diagnostic)| (gap_bounds, Some(diagnostic)))
+            .collect();
+
+        Self::from_fixed_symbol_push_diagnostics(
+            first_follow_symbols,
+            &fixed_symbol.fixed_production,
+            tokens,
+            &mut gap_diagnostics,
+            &mut token_position,
+            &mut diagnostics,
+        );
+
+        diagnostics
+    }
+
+    fn from_fixed_symbol_push_diagnostics(
+        first_follow_symbols: &FirstFollowSymbols<TSyntax>,
+        fixed_production: &FixedProduction<TLex, TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        gap_diagnostics: &mut HashMap<(usize, usize), Option<Self>>,
+        token_position: &mut usize,
+        diagnostics: &mut Vec<Self>,
+    ) {
+        for fixed_part in fixed_production.fixed_parts.iter() {
+            match fixed_part {
+                FixedProductionPart::Ok(node) => {
+                    *token_position += Self::count_node_tokens(first_follow_symbols, node);
+                }
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    Self::from_fixed_symbol_push_diagnostics(
+                        first_follow_symbols,
+                        &fixed_symbol.fixed_production,
+                        tokens,
+                        gap_diagnostics,
+                        token_position,
+                        diagnostics,
+                    );
+                }
+                FixedProductionPart::Gap(fix_gap) => {
+                    let gap_bounds = (
+                        *token_position,
+                        *token_position + fix_gap.skipped_tokens.len(),
+                    );
+                    *token_position = gap_bounds.1;
+
+                    if let Some(gap_diagnostic) = gap_diagnostics.get_mut(&gap_bounds) {
+                        diagnostics.extend(gap_diagnostic.take());
+
+                        continue;
+                    }
+
+                    let expected_symbols = first_follow_symbols
+                        .first_of_sequence(&fix_gap.skipped_symbols)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|symbol| {
+                            first_follow_symbols.get_symbol_table().get_id(symbol)
+                                != Some(first_follow_symbols.get_epsilon_symbol_id())
+                        })
+                        .collect();
+
+                    diagnostics.push(Self::new(
+                        expected_symbols,
+                        tokens.get(gap_bounds.0).cloned(),
+                        gap_bounds.0,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Number of input tokens of a node: its leaves, excluding epsilon ones.
+    fn count_node_tokens(
+        first_follow_symbols: &FirstFollowSymbols<TSyntax>,
+        node: &AbstractSyntaxNode<Token<TLex, TSyntax>>,
+    ) -> usize {
+        let symbol_table = first_follow_symbols.get_symbol_table();
+        let mut pending_nodes = vec![node];
+        let mut tokens_count = 0;
+
+        while let Some(node) = pending_nodes.pop() {
+            if node.child_nodes.is_empty() {
+                if symbol_table.get_id(&node.token.t_type)
+                    != Some(first_follow_symbols.get_epsilon_symbol_id())
+                {
+                    tokens_count += 1;
+                }
+            } else {
+                pending_nodes.extend(node.child_nodes.iter());
+            }
+        }
+
+        tokens_count
+    }
+}
+
+impl<TLex: TokenSpan, TSyntax: Debug> Diagnostic<TLex, TSyntax> {
+    /// Renders the diagnostic followed by the line of the source the token was found at, the
+    /// token being pointed by carets.
+    ///
+    /// At the end of the input, the caret points to the end of the source. Tokens without a
+    /// lexeme are rendered without source line.
+    pub fn render(&self, source: &str) -> String {
+        let span = match &self.found_token {
+            Some(token) => match &token.lex {
+                Some(lex) => lex.get_span(),
+                None => return self.to_string(),
+            },
+            None => source.len()..source.len(),
+        };
+
+        let span_start = span.start.min(source.len());
+        let line_start = source[..span_start]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = source[span_start..]
+            .find('\n')
+            .map_or(source.len(), |index| span_start + index);
+        let line = source[line_start..line_end].trim_end_matches('\r');
+        let line_number = source[..line_start].matches('\n').count() + 1;
+        let column = source[line_start..span_start].chars().count() + 1;
+
+        let caret_offset: String = source[line_start..span_start]
+            .chars()
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect();
+        let caret_count = source[span_start..span.end.clamp(span_start, line_end)]
+            .chars()
+            .count()
+            .max(1);
+
+        let gutter = " ".repeat(line_number.to_string().len());
+
+        format!(
+            "{}\n{}--> {}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            line_number,
+            column,
+            gutter,
+            line_number,
+            line,
+            gutter,
+            caret_offset,
+            "^".repeat(caret_count),
+        )
+    }
+}
+
+impl<TLex, TSyntax: Debug> Display for Diagnostic<TLex, TSyntax> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let found = match &self.found_token {
+            Some(token) => format!("`{:?}`", token.t_type),
+            None => String::from("end of input"),
+        };
+
+        let mut expected: Vec<String> = self
+            .expected_symbols
+            .iter()
+            .map(|symbol| format!("`{:?}`", symbol))
+            .collect();
+
+        if self.expects_end_of_input {
+            expected.push(String::from("end of input"));
+        }
+
+        match expected.as_slice() {
+            [] => write!(f, "unexpected {}", found)?,
+            [symbol] => write!(f, "expected {} but found {}", symbol, found)?,
+            [symbols @ .., last_symbol] => write!(
+                f,
+                "expected one of {} or {} but found {}",
+                symbols.join(", "),
+                last_symbol,
+                found
+            )?,
+        }
+
+        write!(f, " at token {}", self.token_position)
+    }
+}
@@ -0,0 +1,286 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use std::ops::Range;
+
+    use crate::diagnostics::diagnostic::Diagnostic;
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+    use crate::parser::minimum_cost_solver::MinimumCostSolver;
+    use crate::parser::panic_mode_solver::PanicModeSolver;
+    use crate::parser::parse_result::ParseResult;
+    use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+    use crate::parser::repair_costs::RepairCosts;
+    use crate::token::token::Token;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum StatementSyntaxTokenTest {
+        Assign,
+        Eof,
+        Epsilon,
+        Id,
+        Number,
+        S,
+        Semicolon,
+        Stmt,
+        Value,
+    }
+
+    fn build_grammar() -> ContextFreeGrammar<StatementSyntaxTokenTest> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::S,
+                vec![
+                    StatementSyntaxTokenTest::Stmt,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Value,
+                    StatementSyntaxTokenTest::Semicolon,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Number],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Id],
+            ),
+        ];
+
+        ContextFreeGrammar::new(
+            StatementSyntaxTokenTest::Epsilon,
+            StatementSyntaxTokenTest::S,
+            grammar_productions,
+        )
+    }
+
+    fn build_tokens(
+        tokens: Vec<(StatementSyntaxTokenTest, Range<usize>)>,
+    ) -> Vec<Token<Range<usize>, StatementSyntaxTokenTest>> {
+        tokens
+            .into_iter()
+            .map(|(t_type, span)| Token::new(Some(span), t_type))
+            .collect()
+    }
+
+    /// Parses tokens located in a source, building the diagnostic of the syntax error.
+    fn build_diagnostic(
+        tokens: Vec<(StatementSyntaxTokenTest, Range<usize>)>,
+    ) -> Diagnostic<Range<usize>, StatementSyntaxTokenTest> {
+        let grammar = build_grammar();
+        let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+
+        let recursive_descent_parser: RecursiveDescentParser<
+            Range<usize>,
+            StatementSyntaxTokenTest,
+            PanicModeSolver,
+        > = RecursiveDescentParser::from_grammar_and_first_follow_symbols(
+            &grammar,
+            &first_follow_symbols,
+        );
+
+        let tokens = build_tokens(tokens);
+
+        match recursive_descent_parser.parse_from_tokens(tokens.clone().into_iter()) {
+            ParseResult::Err(failed_symbol) => {
+                Diagnostic::from_failed_symbol(&first_follow_symbols, &failed_symbol, &tokens)
+            }
+            _ => panic!("expected a syntax error"),
+        }
+    }
+
+    /// Parses tokens located in a source with a `MinimumCostSolver`, building the diagnostics of
+    /// the fix.
+    fn build_fix_diagnostics(
+        tokens: Vec<(StatementSyntaxTokenTest, Range<usize>)>,
+    ) -> Vec<Diagnostic<Range<usize>, StatementSyntaxTokenTest>> {
+        let grammar = build_grammar();
+        let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+
+        let recursive_descent_parser = RecursiveDescentParser::from_grammar_and_solver(
+            &grammar,
+            MinimumCostSolver::new(RepairCosts::default(), 1000),
+        );
+
+        let tokens = build_tokens(tokens);
+
+        match recursive_descent_parser.parse_from_tokens(tokens.clone().into_iter()) {
+            ParseResult::Fix(fixed_symbol) => {
+                Diagnostic::from_fixed_symbol(&first_follow_symbols, &fixed_symbol, &tokens)
+            }
+            _ => panic!("expected a fix"),
+        }
+    }
+
+    mod from_failed_symbol {
+        use crate::diagnostics::diagnostic_spec::test::{
+            build_diagnostic, StatementSyntaxTokenTest,
+        };
+
+        #[test]
+        fn it_returns_the_farthest_failure() -> () {
+            let diagnostic = build_diagnostic(vec![
+                (StatementSyntaxTokenTest::Id, 0..1),
+                (StatementSyntaxTokenTest::Assign, 2..3),
+                (StatementSyntaxTokenTest::Number, 4..5),
+                (StatementSyntaxTokenTest::Number, 6..7),
+                (StatementSyntaxTokenTest::Eof, 7..7),
+            ]);
+
+            assert_eq!(3, diagnostic.token_position);
+            assert_eq!(
+                vec![StatementSyntaxTokenTest::Semicolon],
+                diagnostic.expected_symbols
+            );
+            assert_eq!(
+                Some(6..7),
+                diagnostic.found_token.and_then(|token| token.lex)
+            );
+        }
+
+        #[test]
+        fn it_returns_the_expected_symbols_of_every_failed_production() -> () {
+            let diagnostic = build_diagnostic(vec![
+                (StatementSyntaxTokenTest::Id, 0..1),
+                (StatementSyntaxTokenTest::Assign, 2..3),
+                (StatementSyntaxTokenTest::Assign, 4..5),
+                (StatementSyntaxTokenTest::Eof, 5..5),
+            ]);
+
+            assert_eq!(2, diagnostic.token_position);
+            assert_eq!(
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Number
+                ],
+                diagnostic.expected_symbols
+            );
+        }
+
+        #[test]
+        fn it_returns_a_failure_at_the_end_of_input() -> () {
+            let diagnostic = build_diagnostic(vec![
+                (StatementSyntaxTokenTest::Id, 0..1),
+                (StatementSyntaxTokenTest::Assign, 2..3),
+                (StatementSyntaxTokenTest::Number, 4..5),
+            ]);
+
+            assert_eq!(3, diagnostic.token_position);
+            assert_eq!(
+                vec![StatementSyntaxTokenTest::Semicolon],
+                diagnostic.expected_symbols
+            );
+            assert!(diagnostic.found_token.is_none());
+        }
+    }
+
+    mod from_fixed_symbol {
+        use crate::diagnostics::diagnostic_spec::test::{
+            build_fix_diagnostics, StatementSyntaxTokenTest,
+        };
+
+        #[test]
+        fn it_returns_a_diagnostic_for_each_gap() -> () {
+            let diagnostics = build_fix_diagnostics(vec![
+                (StatementSyntaxTokenTest::Id, 0..1),
+                (StatementSyntaxTokenTest::Assign, 2..3),
+                (StatementSyntaxTokenTest::Assign, 4..5),
+                (StatementSyntaxTokenTest::Number, 6..7),
+                (StatementSyntaxTokenTest::Eof, 7..7),
+            ]);
+
+            assert_eq!(
+                vec![
+                    "unexpected `Assign` at token 2",
+                    "expected `Semicolon` but found `Eof` at token 4",
+                ],
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.to_string())
+                    .collect::<Vec<String>>()
+            );
+        }
+    }
+
+    mod render {
+        use crate::diagnostics::diagnostic_spec::test::{
+            build_diagnostic, StatementSyntaxTokenTest,
+        };
+
+        #[test]
+        fn it_renders_the_source_line_of_the_token() -> () {
+            let diagnostic = build_diagnostic(vec![
+                (StatementSyntaxTokenTest::Id, 9..10),
+                (StatementSyntaxTokenTest::Assign, 11..12),
+                (StatementSyntaxTokenTest::Number, 13..15),
+                (StatementSyntaxTokenTest::Number, 16..19),
+                (StatementSyntaxTokenTest::Eof, 20..20),
+            ]);
+
+            assert_eq!(
+                "expected `Semicolon` but found `Number` at token 3
+ --> 2:8
+  |
+2 | x = 12 345;
+  |        ^^^",
+                diagnostic.render("// value\nx = 12 345;\n")
+            );
+        }
+
+        #[test]
+        fn it_renders_the_end_of_input() -> () {
+            let diagnostic = build_diagnostic(vec![
+                (StatementSyntaxTokenTest::Id, 0..1),
+                (StatementSyntaxTokenTest::Assign, 2..3),
+            ]);
+
+            assert_eq!(
+                "expected one of `Id` or `Number` but found end of input at token 2
+ --> 1:4
+  |
+1 | x =
+  |    ^",
+                diagnostic.render("x =")
+            );
+        }
+    }
+
+    mod to_string {
+        use crate::diagnostics::diagnostic::Diagnostic;
+        use crate::diagnostics::diagnostic_spec::test::StatementSyntaxTokenTest;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_describes_an_unexpected_token() -> () {
+            let diagnostic: Diagnostic<u64, StatementSyntaxTokenTest> = Diagnostic::new(
+                vec![],
+                Some(Token::new(None, StatementSyntaxTokenTest::Semicolon)),
+                0,
+            );
+
+            assert_eq!("unexpected `Semicolon` at token 0", diagnostic.to_string());
+        }
+
+        #[test]
+        fn it_describes_a_token_found_instead_of_the_end_of_input() -> () {
+            let diagnostic: Diagnostic<u64, StatementSyntaxTokenTest> =
+                Diagnostic::expecting_end_of_input(
+                    Token::new(None, StatementSyntaxTokenTest::Semicolon),
+                    3,
+                );
+
+            assert_eq!(
+                "expected end of input but found `Semicolon` at token 3",
+                diagnostic.to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod diagnostic;
+mod diagnostic_spec;
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use crate::grammar::symbol_map::SymbolMap;
+
+/// `BTreeMap` backed symbol map
+///
+/// Useful for symbols which are `Ord` but not `Hash`.
+pub struct BTreeSymbolMap<T> {
+    symbol_ids: BTreeMap<T, u32>,
+}
+
+impl<T: Clone + Ord> SymbolMap<T> for BTreeSymbolMap<T> {
+    fn get(&self, symbol: &T) -> Option<u32> {
+        self.symbol_ids.get(symbol).copied()
+    }
+
+    fn insert(&mut self, symbol: T, symbol_id: u32) {
+        self.symbol_ids.insert(symbol, symbol_id);
+    }
+}
+
+impl<T: Clone> Clone for BTreeSymbolMap<T> {
+    fn clone(&self) -> Self {
+        Self {
+            symbol_ids: self.symbol_ids.clone(),
+        }
+    }
+}
+
+impl<T> Default for BTreeSymbolMap<T> {
+    fn default() -> Self {
+        Self {
+            symbol_ids: BTreeMap::new(),
+        }
+    }
+}
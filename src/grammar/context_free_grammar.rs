@@ -1,54 +1,194 @@
-use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::iter::FromIterator;
+use std::sync::Arc;
 
 use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+use crate::grammar::hash_symbol_map::HashSymbolMap;
+use crate::grammar::symbol_map::SymbolMap;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::grammar::symbol_table::SymbolTable;
 
-pub struct ContextFreeGrammar<T> {
+/// Context free grammar
+///
+/// Symbols are interned in order of appearance, so every symbol has a dense `u32` ID. The ID
+/// based API is intended for algorithms which would otherwise hash symbols repeatedly.
+///
+/// Productions can be added and removed after creating the grammar. Symbols are never
+/// forgotten, so IDs are stable across edits.
+///
+/// Symbols are looked up through a `SymbolMap`. `ContextFreeGrammar::new` uses a `HashSymbolMap`,
+/// `ContextFreeGrammar::with_symbol_map` accepts any other map, for example a `BTreeSymbolMap`
+/// for symbols which are not `Hash`.
+pub struct ContextFreeGrammar<T, TMap = HashSymbolMap<T>> {
     epsilon_symbol: T,
+    epsilon_symbol_id: u32,
     initial_symbol: T,
-    non_terminal_symbols_set: HashSet<T>,
-    productions: HashMap<T, Vec<ContextFreeGrammarProduction<T>>>,
-    terminal_symbols_set: HashSet<T>,
+    non_terminal_symbols_set: SymbolSet,
+    production_output_ids: Vec<Vec<Vec<u32>>>,
+    productions: Vec<Vec<ContextFreeGrammarProduction<T>>>,
+    symbol_table: Arc<SymbolTable<T, TMap>>,
+    terminal_symbols_set: SymbolSet,
 }
 
-impl<T> ContextFreeGrammar<T> {
+impl<T, TMap> ContextFreeGrammar<T, TMap> {
     pub fn get_epsilon_symbol(&self) -> &T {
         &self.epsilon_symbol
     }
 
+    pub fn get_epsilon_symbol_id(&self) -> u32 {
+        self.epsilon_symbol_id
+    }
+
     pub fn get_initial_symbol(&self) -> &T {
         &self.initial_symbol
     }
+
+    /// IDs of the non terminal symbols, in ascending order.
+    pub fn get_non_terminal_symbol_ids(&self) -> Vec<u32> {
+        self.non_terminal_symbols_set.iter().collect()
+    }
+
+    /// Output symbol IDs of the productions of a symbol, in the same order as
+    /// `ContextFreeGrammar.get_productions`. Terminal symbols have no productions.
+    pub fn get_production_output_ids(&self, symbol_id: u32) -> &[Vec<u32>] {
+        self.production_output_ids
+            .get(symbol_id as usize)
+            .map_or(&[], |production_output_ids| {
+                production_output_ids.as_slice()
+            })
+    }
+
+    pub fn get_productions_by_id(
+        &self,
+        symbol_id: u32,
+    ) -> Option<&Vec<ContextFreeGrammarProduction<T>>> {
+        if self.is_non_terminal_id(symbol_id) {
+            self.productions.get(symbol_id as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Shortest sequence of terminal symbol IDs derived from each symbol, indexed by symbol ID.
+    ///
+    /// Terminal symbols derive themselves and epsilon derives the empty sequence. Symbols
+    /// deriving no terminal sequence have no derivation.
+    pub fn get_shortest_derivation_ids(&self) -> Vec<Option<Vec<u32>>> {
+        let mut shortest_derivation_ids: Vec<Option<Vec<u32>>> = (0..self.symbol_table.len()
+            as u32)
+            .map(|symbol_id| {
+                if symbol_id == self.epsilon_symbol_id {
+                    Some(vec![])
+                } else if self.is_terminal_id(symbol_id) {
+                    Some(vec![symbol_id])
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Derivations are settled from the shortest one, each using settled derivations only.
+        loop {
+            let mut shortest_option: Option<(u32, usize, &[u32])> = None;
+
+            for symbol_id in self.non_terminal_symbols_set.iter() {
+                if shortest_derivation_ids[symbol_id as usize].is_some() {
+                    continue;
+                }
+
+                for output_ids in self.get_production_output_ids(symbol_id) {
+                    let length_option: Option<usize> = output_ids
+                        .iter()
+                        .map(|output_id| {
+                            shortest_derivation_ids[*output_id as usize]
+                                .as_ref()
+                                .map(Vec::len)
+                        })
+                        .sum();
+
+                    if let Some(length) = length_option {
+                        if shortest_option
+                            .is_none_or(|(_, shortest_length, _)| length < shortest_length)
+                        {
+                            shortest_option = Some((symbol_id, length, output_ids));
+                        }
+                    }
+                }
+            }
+
+            let (symbol_id, _, output_ids) = match shortest_option {
+                Some(shortest) => shortest,
+                None => break,
+            };
+
+            let derivation_ids: Vec<u32> = output_ids
+                .iter()
+                .flat_map(|output_id| {
+                    shortest_derivation_ids[*output_id as usize]
+                        .clone()
+                        .unwrap_or_default()
+                })
+                .collect();
+
+            shortest_derivation_ids[symbol_id as usize] = Some(derivation_ids);
+        }
+
+        shortest_derivation_ids
+    }
+
+    pub fn get_symbol_table(&self) -> &Arc<SymbolTable<T, TMap>> {
+        &self.symbol_table
+    }
+
+    /// IDs of the terminal symbols, in ascending order.
+    pub fn get_terminal_symbol_ids(&self) -> Vec<u32> {
+        self.terminal_symbols_set.iter().collect()
+    }
+
+    pub fn is_non_terminal_id(&self, symbol_id: u32) -> bool {
+        self.non_terminal_symbols_set.contains(symbol_id)
+    }
+
+    pub fn is_terminal_id(&self, symbol_id: u32) -> bool {
+        self.terminal_symbols_set.contains(symbol_id)
+    }
 }
 
-impl<T: Clone> ContextFreeGrammar<T> {
+impl<T: Clone, TMap> ContextFreeGrammar<T, TMap> {
+    /// Non terminal symbols, in order of appearance.
     pub fn get_non_terminal_symbols(&self) -> Vec<T> {
         self.non_terminal_symbols_set
             .iter()
-            .map(|symbol| symbol.clone())
+            .map(|symbol_id| self.symbol_table.get_symbol(symbol_id).clone())
             .collect()
     }
 
+    /// Terminal symbols, in order of appearance.
     pub fn get_terminal_symbols(&self) -> Vec<T> {
         self.terminal_symbols_set
             .iter()
-            .map(|symbol| symbol.clone())
+            .map(|symbol_id| self.symbol_table.get_symbol(symbol_id).clone())
             .collect()
     }
 }
 
-impl<T: Eq + Hash> ContextFreeGrammar<T> {
+impl<T, TMap: SymbolMap<T>> ContextFreeGrammar<T, TMap> {
     pub fn get_productions(&self, symbol: &T) -> Option<&Vec<ContextFreeGrammarProduction<T>>> {
-        self.productions.get(symbol)
+        self.get_symbol_id(symbol)
+            .and_then(|symbol_id| self.get_productions_by_id(symbol_id))
+    }
+
+    pub fn get_symbol_id(&self, symbol: &T) -> Option<u32> {
+        self.symbol_table.get_id(symbol)
     }
 
     pub fn is_non_terminal(&self, symbol: &T) -> bool {
-        self.non_terminal_symbols_set.contains(symbol)
+        self.get_symbol_id(symbol)
+            .is_some_and(|symbol_id| self.is_non_terminal_id(symbol_id))
     }
 
     pub fn is_terminal(&self, symbol: &T) -> bool {
-        self.terminal_symbols_set.contains(symbol)
+        self.get_symbol_id(symbol)
+            .is_some_and(|symbol_id| self.is_terminal_id(symbol_id))
     }
 }
 
@@ -58,87 +198,163 @@ impl<T: Clone + Eq + Hash> ContextFreeGrammar<T> {
         initial_symbol: T,
         productions: Vec<ContextFreeGrammarProduction<T>>,
     ) -> Self {
-        let productions_hash_map: HashMap<T, Vec<ContextFreeGrammarProduction<T>>> =
-            Self::new_process_productions_map(productions);
-
-        Self::new_check_productions_map(&epsilon_symbol, &productions_hash_map);
-
-        let non_terminal_symbols: Vec<T> =
-            productions_hash_map.keys().map(|key| key.clone()).collect();
-        let non_terminal_symbols_set: HashSet<T> =
-            HashSet::from_iter(non_terminal_symbols.into_iter());
-        let terminal_symbols_set: HashSet<T> = ContextFreeGrammar::build_terminal_symbols_set(
-            &non_terminal_symbols_set,
-            &productions_hash_map,
+        Self::with_symbol_map(epsilon_symbol, initial_symbol, productions)
+    }
+}
+
+impl<T: Clone + PartialEq, TMap: SymbolMap<T>> ContextFreeGrammar<T, TMap> {
+    /// Adds a production at the end of the productions of its input symbol.
+    ///
+    /// Panics under the same conditions `ContextFreeGrammar::new` does.
+    pub fn add_production(&mut self, production: ContextFreeGrammarProduction<T>) {
+        Self::new_check_productions(&self.epsilon_symbol, std::slice::from_ref(&production));
+
+        let symbol_table = Arc::make_mut(&mut self.symbol_table);
+
+        let input_id = symbol_table.intern(&production.input);
+        let output_ids: Vec<u32> = production
+            .output
+            .iter()
+            .map(|symbol| symbol_table.intern(symbol))
+            .collect();
+
+        let symbols_count = symbol_table.len();
+
+        self.production_output_ids.resize(symbols_count, vec![]);
+        self.productions.resize_with(symbols_count, Vec::new);
+
+        self.non_terminal_symbols_set.insert(input_id);
+        self.production_output_ids[input_id as usize].push(output_ids);
+        self.productions[input_id as usize].push(production);
+
+        self.terminal_symbols_set = Self::build_terminal_symbols_set(
+            &self.non_terminal_symbols_set,
+            &self.production_output_ids,
         );
+    }
+
+    /// Removes the first production equal to the one provided, returning whether a production
+    /// was removed.
+    ///
+    /// A symbol with no productions left is no longer a non terminal symbol.
+    pub fn remove_production(&mut self, production: &ContextFreeGrammarProduction<T>) -> bool {
+        let input_id = match self.get_symbol_id(&production.input) {
+            Some(input_id) if self.is_non_terminal_id(input_id) => input_id,
+            _ => return false,
+        };
+
+        let productions = &mut self.productions[input_id as usize];
+
+        let production_index = match productions
+            .iter()
+            .position(|symbol_production| symbol_production.output == production.output)
+        {
+            Some(production_index) => production_index,
+            None => return false,
+        };
+
+        productions.remove(production_index);
+        self.production_output_ids[input_id as usize].remove(production_index);
+
+        if productions.is_empty() {
+            self.non_terminal_symbols_set.remove(input_id);
+        }
+
+        self.terminal_symbols_set = Self::build_terminal_symbols_set(
+            &self.non_terminal_symbols_set,
+            &self.production_output_ids,
+        );
+
+        true
+    }
+
+    /// Builds a grammar whose symbols are looked up with `TMap`.
+    ///
+    /// Panics under the same conditions `ContextFreeGrammar::new` does.
+    pub fn with_symbol_map(
+        epsilon_symbol: T,
+        initial_symbol: T,
+        productions: Vec<ContextFreeGrammarProduction<T>>,
+    ) -> Self {
+        Self::new_check_productions(&epsilon_symbol, &productions);
+
+        let mut symbol_table: SymbolTable<T, TMap> = SymbolTable::with_symbol_map();
+
+        for production in &productions {
+            symbol_table.intern(&production.input);
+
+            for symbol in &production.output {
+                symbol_table.intern(symbol);
+            }
+        }
+
+        let epsilon_symbol_id = symbol_table.intern(&epsilon_symbol);
+        symbol_table.intern(&initial_symbol);
+
+        let mut non_terminal_symbols_set = SymbolSet::with_capacity(symbol_table.len());
+        let mut production_output_ids: Vec<Vec<Vec<u32>>> = vec![vec![]; symbol_table.len()];
+        let mut productions_by_id: Vec<Vec<ContextFreeGrammarProduction<T>>> =
+            (0..symbol_table.len()).map(|_| vec![]).collect();
+
+        for production in productions {
+            let input_id = symbol_table.get_id(&production.input).unwrap();
+            let output_ids: Vec<u32> = production
+                .output
+                .iter()
+                .map(|symbol| symbol_table.get_id(symbol).unwrap())
+                .collect();
+
+            non_terminal_symbols_set.insert(input_id);
+            production_output_ids[input_id as usize].push(output_ids);
+            productions_by_id[input_id as usize].push(production);
+        }
+
+        let terminal_symbols_set =
+            Self::build_terminal_symbols_set(&non_terminal_symbols_set, &production_output_ids);
 
         ContextFreeGrammar {
             epsilon_symbol,
+            epsilon_symbol_id,
             initial_symbol,
             non_terminal_symbols_set,
-            productions: productions_hash_map,
+            production_output_ids,
+            productions: productions_by_id,
+            symbol_table: Arc::new(symbol_table),
             terminal_symbols_set,
         }
     }
 
     fn build_terminal_symbols_set(
-        non_terminal_symbols_set: &HashSet<T>,
-        productions_hash_map: &HashMap<T, Vec<ContextFreeGrammarProduction<T>>>,
-    ) -> HashSet<T> {
-        let mut terminal_symbols_set: HashSet<T> = HashSet::new();
-
-        productions_hash_map.values().for_each(|productions| {
-            productions.iter().for_each(|production| {
-                production.output.iter().for_each(|symbol| {
-                    if !non_terminal_symbols_set.contains(symbol) {
-                        terminal_symbols_set.insert(symbol.clone());
-                    }
-                });
+        non_terminal_symbols_set: &SymbolSet,
+        production_output_ids: &[Vec<Vec<u32>>],
+    ) -> SymbolSet {
+        let mut terminal_symbols_set = SymbolSet::with_capacity(production_output_ids.len());
+
+        production_output_ids
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|symbol_id| !non_terminal_symbols_set.contains(**symbol_id))
+            .for_each(|symbol_id| {
+                terminal_symbols_set.insert(*symbol_id);
             });
-        });
 
         terminal_symbols_set
     }
 
-    fn new_check_productions_map(
-        epsilon_symbol: &T,
-        productions_map: &HashMap<T, Vec<ContextFreeGrammarProduction<T>>>,
-    ) -> () {
-        for (symbol, productions) in productions_map {
-            if symbol.eq(epsilon_symbol) {
+    fn new_check_productions(epsilon_symbol: &T, productions: &[ContextFreeGrammarProduction<T>]) {
+        for production in productions {
+            if production.input.eq(epsilon_symbol) {
                 panic!("Expected epsilon symbol not to generate any symbols!");
             }
 
-            for production in productions {
-                if production.output.len() == 0 {
-                    panic!("Expected production to have at least one symbol");
-                } else {
-                    if production.output.len() > 1 && production.output.contains(epsilon_symbol) {
-                        panic!("Expected epsilon production not to have additional symbol");
-                    }
-                }
-            }
-        }
-    }
-
-    fn new_process_productions_map(
-        productions: Vec<ContextFreeGrammarProduction<T>>,
-    ) -> HashMap<T, Vec<ContextFreeGrammarProduction<T>>> {
-        let mut productions_hash_map: HashMap<T, Vec<ContextFreeGrammarProduction<T>>> =
-            HashMap::new();
-
-        for production in productions {
-            let current_productions: Option<&mut Vec<ContextFreeGrammarProduction<T>>> =
-                productions_hash_map.get_mut(&production.input);
-
-            match current_productions {
-                None => {
-                    productions_hash_map.insert(production.input.clone(), vec![production]);
+            if production.output.is_empty() {
+                panic!("Expected production to have at least one symbol");
+            } else {
+                if production.output.len() > 1 && production.output.contains(epsilon_symbol) {
+                    panic!("Expected epsilon production not to have additional symbol");
                 }
-                Some(productions) => productions.push(production),
             }
         }
-
-        productions_hash_map
     }
 }
@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::unused_unit)]
 mod test {
 
     mod new {
@@ -36,6 +37,34 @@ mod test {
             );
         }
 
+        #[test]
+        fn it_interns_symbols_in_order_of_appearance() -> () {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<SyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::Module,
+                    vec![
+                        SyntaxTokenTest::Terminal(TerminalTokenTypeTest::Id),
+                        SyntaxTokenTest::Terminal(TerminalTokenTypeTest::Eof),
+                    ],
+                )];
+
+            let grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::Module,
+                grammar_productions,
+            );
+
+            assert_eq!(Some(0), grammar.get_symbol_id(&SyntaxTokenTest::Module));
+            assert_eq!(
+                Some(2),
+                grammar.get_symbol_id(&SyntaxTokenTest::Terminal(TerminalTokenTypeTest::Eof))
+            );
+            assert_eq!(3, grammar.get_epsilon_symbol_id());
+            assert_eq!(vec![0], grammar.get_non_terminal_symbol_ids());
+            assert_eq!(vec![1, 2], grammar.get_terminal_symbol_ids());
+            assert_eq!(vec![vec![1, 2]], grammar.get_production_output_ids(0));
+        }
+
         #[test]
         #[should_panic]
         fn it_panics_if_production_input_is_epsilon() {
@@ -87,4 +116,217 @@ mod test {
             );
         }
     }
+
+    mod add_production {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            S,
+            ATerminal,
+            Eof,
+        }
+
+        #[test]
+        fn it_adds_a_production() -> () {
+            let mut grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::S,
+                    vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                )],
+            );
+
+            assert!(grammar.is_terminal(&SyntaxTokenTest::A));
+
+            grammar.add_production(ContextFreeGrammarProduction::new(
+                SyntaxTokenTest::A,
+                vec![SyntaxTokenTest::ATerminal],
+            ));
+
+            assert!(grammar.is_non_terminal(&SyntaxTokenTest::A));
+            assert!(grammar.is_terminal(&SyntaxTokenTest::ATerminal));
+            assert_eq!(
+                vec![SyntaxTokenTest::ATerminal],
+                grammar.get_productions(&SyntaxTokenTest::A).unwrap()[0].output
+            );
+            assert_eq!(
+                vec![SyntaxTokenTest::Eof, SyntaxTokenTest::ATerminal],
+                grammar.get_terminal_symbols()
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn it_panics_if_production_input_is_epsilon() -> () {
+            let mut grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![ContextFreeGrammarProduction::new(
+                    SyntaxTokenTest::S,
+                    vec![SyntaxTokenTest::Eof],
+                )],
+            );
+
+            grammar.add_production(ContextFreeGrammarProduction::new(
+                SyntaxTokenTest::Epsilon,
+                vec![SyntaxTokenTest::ATerminal],
+            ));
+        }
+    }
+
+    mod get_shortest_derivation_ids {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            C,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        #[test]
+        fn it_returns_the_shortest_terminal_derivation_of_each_symbol() -> () {
+            let grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::B, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(SyntaxTokenTest::C, vec![SyntaxTokenTest::C]),
+                ],
+            );
+
+            let shortest_derivation_ids = grammar.get_shortest_derivation_ids();
+            let shortest_derivation = |symbol: SyntaxTokenTest| {
+                shortest_derivation_ids[grammar.get_symbol_id(&symbol).unwrap() as usize]
+                    .as_ref()
+                    .map(|derivation_ids| {
+                        derivation_ids
+                            .iter()
+                            .map(|symbol_id| *grammar.get_symbol_table().get_symbol(*symbol_id))
+                            .collect::<Vec<SyntaxTokenTest>>()
+                    })
+            };
+
+            assert_eq!(
+                Some(vec![SyntaxTokenTest::BTerminal, SyntaxTokenTest::Eof]),
+                shortest_derivation(SyntaxTokenTest::S)
+            );
+            assert_eq!(
+                Some(vec![SyntaxTokenTest::BTerminal]),
+                shortest_derivation(SyntaxTokenTest::A)
+            );
+            assert_eq!(Some(vec![]), shortest_derivation(SyntaxTokenTest::B));
+            assert_eq!(None, shortest_derivation(SyntaxTokenTest::C));
+            assert_eq!(
+                Some(vec![SyntaxTokenTest::Eof]),
+                shortest_derivation(SyntaxTokenTest::Eof)
+            );
+        }
+    }
+
+    mod remove_production {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            S,
+            ATerminal,
+            Eof,
+        }
+
+        #[test]
+        fn it_removes_a_production() -> () {
+            let a_production = ContextFreeGrammarProduction::new(
+                SyntaxTokenTest::A,
+                vec![SyntaxTokenTest::ATerminal],
+            );
+
+            let mut grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                    ),
+                    a_production.clone(),
+                ],
+            );
+
+            let a_symbol_id = grammar.get_symbol_id(&SyntaxTokenTest::A);
+
+            assert!(grammar.remove_production(&a_production));
+            assert!(!grammar.remove_production(&a_production));
+
+            assert!(grammar.get_productions(&SyntaxTokenTest::A).is_none());
+            assert!(grammar.is_terminal(&SyntaxTokenTest::A));
+            assert!(!grammar.is_terminal(&SyntaxTokenTest::ATerminal));
+            assert_eq!(a_symbol_id, grammar.get_symbol_id(&SyntaxTokenTest::A));
+        }
+    }
+
+    mod with_symbol_map {
+        use crate::grammar::btree_symbol_map::BTreeSymbolMap;
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+        #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            Eof,
+            Id,
+            Module,
+        }
+
+        #[test]
+        fn it_creates_a_grammar_of_symbols_which_are_not_hash() -> () {
+            let grammar: ContextFreeGrammar<SyntaxTokenTest, BTreeSymbolMap<SyntaxTokenTest>> =
+                ContextFreeGrammar::with_symbol_map(
+                    SyntaxTokenTest::Epsilon,
+                    SyntaxTokenTest::Module,
+                    vec![ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::Module,
+                        vec![SyntaxTokenTest::Id, SyntaxTokenTest::Eof],
+                    )],
+                );
+
+            assert_eq!(Some(0), grammar.get_symbol_id(&SyntaxTokenTest::Module));
+            assert_eq!(Some(3), grammar.get_symbol_id(&SyntaxTokenTest::Epsilon));
+            assert!(grammar.is_non_terminal(&SyntaxTokenTest::Module));
+            assert!(grammar.is_terminal(&SyntaxTokenTest::Id));
+            assert_eq!(
+                vec![SyntaxTokenTest::Id, SyntaxTokenTest::Eof],
+                grammar.get_terminal_symbols()
+            );
+        }
+    }
 }
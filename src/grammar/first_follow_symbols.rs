@@ -1,96 +1,445 @@
-use std::borrow::Borrow;
-use std::cell::{Ref, RefCell, RefMut};
-use std::collections::hash_map::RandomState;
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::hash::Hash;
-use std::iter::{FromIterator, Map};
-use std::slice::Iter;
+use std::sync::Arc;
 
 use crate::grammar::context_free_grammar::ContextFreeGrammar;
 use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+use crate::grammar::hash_symbol_map::HashSymbolMap;
+use crate::grammar::symbol_map::SymbolMap;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::grammar::symbol_set_view::SymbolSetView;
+use crate::grammar::symbol_table::SymbolTable;
+
+/// Symbols of each symbol, in insertion order.
+///
+/// Symbols are interned in the order they are found, so the same map always leads to the same
+/// symbol IDs.
+pub type SymbolsMap<T> = Vec<(T, Vec<T>)>;
+
+/// FIRST and FOLLOW symbols of a grammar
+///
+/// Sets are stored as bitsets indexed by symbol ID. When computed from a grammar, IDs are the
+/// ones of the grammar.
+///
+/// `FirstFollowSymbols.from` computes the sets with a worklist, so only the productions affected
+/// by a change are processed again. `FirstFollowSymbols.from_fixed_point` re-processes every
+/// production until no set changes; it gives the same sets and is kept as a reference.
+///
+/// After adding or removing a production of a grammar, the sets can be updated instead of
+/// computed again. The dependencies between the sets are kept, so only the sets depending on the
+/// edited production are computed again.
+///
+/// Symbols are looked up through the `SymbolMap` of the symbol table, the one of the grammar
+/// when computed from a grammar.
+pub struct FirstFollowSymbols<T, TMap = HashSymbolMap<T>> {
+    /// Symbols of each symbol whose productions include it, empty if the sets are not computed
+    /// from a grammar.
+    dependent_symbols: Vec<SymbolSet>,
+    epsilon_symbol_id: u32,
+    first_symbols: Vec<Option<SymbolSet>>,
+    /// Symbols of each symbol whose FOLLOW symbols include the ones of the symbol, empty if the
+    /// sets are not computed from a grammar.
+    follow_edges: Vec<SymbolSet>,
+    follow_symbols: Vec<Option<SymbolSet>>,
+    symbol_table: Arc<SymbolTable<T, TMap>>,
+}
 
-pub type SymbolsMap<T> = HashMap<T, HashSet<T>>;
-
-pub struct FirstFollowSymbols<T> {
-    first_symbols: SymbolsMap<T>,
-    follow_symbols: SymbolsMap<T>,
+impl<T, TMap> Clone for FirstFollowSymbols<T, TMap> {
+    fn clone(&self) -> Self {
+        Self {
+            dependent_symbols: self.dependent_symbols.clone(),
+            epsilon_symbol_id: self.epsilon_symbol_id,
+            first_symbols: self.first_symbols.clone(),
+            follow_edges: self.follow_edges.clone(),
+            follow_symbols: self.follow_symbols.clone(),
+            symbol_table: self.symbol_table.clone(),
+        }
+    }
 }
 
-impl<T> FirstFollowSymbols<T> {
-    fn converge<M, F>(model: &M, callback: F) -> ()
+impl<T, TMap> FirstFollowSymbols<T, TMap> {
+    pub fn get_epsilon_symbol_id(&self) -> u32 {
+        self.epsilon_symbol_id
+    }
+
+    pub fn get_first_symbol_set(&self, symbol_id: u32) -> Option<&SymbolSet> {
+        Self::get_symbol_set(&self.first_symbols, symbol_id)
+    }
+
+    /// FIRST symbols of a sequence of symbols, given their IDs.
+    ///
+    /// The sequence is nullable, including epsilon in the result, if every symbol is nullable.
+    /// Returns `None` if the FIRST symbols of any symbol of the sequence are unknown.
+    pub fn get_first_symbol_set_of_sequence(&self, symbol_ids: &[u32]) -> Option<SymbolSet> {
+        let mut first_symbol_set = SymbolSet::new();
+
+        for symbol_id in symbol_ids {
+            if *symbol_id == self.epsilon_symbol_id {
+                continue;
+            }
+
+            first_symbol_set.union_with(self.get_first_symbol_set(*symbol_id)?);
+
+            if !first_symbol_set.remove(self.epsilon_symbol_id) {
+                return Some(first_symbol_set);
+            }
+        }
+
+        first_symbol_set.insert(self.epsilon_symbol_id);
+
+        Some(first_symbol_set)
+    }
+
+    pub fn get_follow_symbol_set(&self, symbol_id: u32) -> Option<&SymbolSet> {
+        Self::get_symbol_set(&self.follow_symbols, symbol_id)
+    }
+
+    /// Terminal symbols which could start a sequence of symbols of a production, given their IDs
+    /// and the ID of the input of the production.
+    ///
+    /// These are the FIRST symbols of the sequence, FOLLOW symbols of the input replacing epsilon
+    /// if the sequence is nullable. Returns `None` if the FIRST symbols of any symbol of the
+    /// sequence are unknown.
+    pub fn get_expected_symbol_set(
+        &self,
+        symbol_ids: &[u32],
+        production_input_id: u32,
+    ) -> Option<SymbolSet> {
+        let mut expected_symbol_set = self.get_first_symbol_set_of_sequence(symbol_ids)?;
+
+        if expected_symbol_set.remove(self.epsilon_symbol_id) {
+            if let Some(follow_symbol_set) = self.get_follow_symbol_set(production_input_id) {
+                expected_symbol_set.union_with(follow_symbol_set);
+            }
+        }
+
+        Some(expected_symbol_set)
+    }
+
+    pub fn get_symbol_table(&self) -> &Arc<SymbolTable<T, TMap>> {
+        &self.symbol_table
+    }
+
+    /// Whether a symbol derives epsilon, given its ID.
+    pub fn is_nullable_id(&self, symbol_id: u32) -> bool {
+        symbol_id == self.epsilon_symbol_id
+            || self
+                .get_first_symbol_set(symbol_id)
+                .is_some_and(|first_symbol_set| first_symbol_set.contains(self.epsilon_symbol_id))
+    }
+
+    fn converge<M, F>(model: &M, callback: F)
     where
         F: Fn(&M) -> bool,
     {
         while callback(model) {}
     }
-}
 
-impl<T: Eq + Hash> FirstFollowSymbols<T> {
-    pub fn new(first_symbols: SymbolsMap<T>, follow_symbols: SymbolsMap<T>) -> Self {
-        FirstFollowSymbols {
-            first_symbols,
-            follow_symbols,
+    fn get_symbol_set(symbol_sets: &[Option<SymbolSet>], symbol_id: u32) -> Option<&SymbolSet> {
+        symbol_sets
+            .get(symbol_id as usize)
+            .and_then(|symbol_set| symbol_set.as_ref())
+    }
+
+    /// Inserts the symbols of a set into another set, returning whether any symbol was inserted.
+    fn union_symbol_sets(symbol_sets: &mut [SymbolSet], target_id: u32, source_id: u32) -> bool {
+        if target_id == source_id {
+            return false;
         }
+
+        let source_symbol_set = symbol_sets[source_id as usize].clone();
+
+        symbol_sets[target_id as usize].union_with(&source_symbol_set)
+    }
+}
+
+impl<T: Clone, TMap: SymbolMap<T>> FirstFollowSymbols<T, TMap> {
+    /// FIRST symbols of a sequence of symbols, in ID order.
+    ///
+    /// The sequence is nullable, including epsilon in the result, if every symbol is nullable.
+    /// Returns `None` if the FIRST symbols of any symbol of the sequence are unknown.
+    pub fn first_of_sequence(&self, symbols: &[T]) -> Option<Vec<T>> {
+        let symbol_ids = symbols
+            .iter()
+            .map(|symbol| self.symbol_table.get_id(symbol))
+            .collect::<Option<Vec<u32>>>()?;
+
+        self.get_first_symbol_set_of_sequence(&symbol_ids)
+            .map(|first_symbol_set| {
+                first_symbol_set
+                    .iter()
+                    .map(|symbol_id| self.symbol_table.get_symbol(symbol_id).clone())
+                    .collect()
+            })
     }
 
-    pub fn get_first_symbols<'a>(&'a self, symbol: &'a T) -> Option<&'a HashSet<T>> {
-        FirstFollowSymbols::get_symbols_from_hash_map(&self.first_symbols, symbol)
+    /// Symbols deriving epsilon, in ID order. Epsilon itself is not included.
+    pub fn nullable_symbols(&self) -> Vec<T> {
+        (0..self.symbol_table.len() as u32)
+            .filter(|symbol_id| {
+                *symbol_id != self.epsilon_symbol_id && self.is_nullable_id(*symbol_id)
+            })
+            .map(|symbol_id| self.symbol_table.get_symbol(symbol_id).clone())
+            .collect()
     }
+}
 
-    pub fn get_follow_symbols<'a>(&'a self, symbol: &'a T) -> Option<&'a HashSet<T>> {
-        FirstFollowSymbols::get_symbols_from_hash_map(&self.follow_symbols, symbol)
+impl<T, TMap: SymbolMap<T>> FirstFollowSymbols<T, TMap> {
+    pub fn get_first_symbols(&self, symbol: &T) -> Option<SymbolSetView<'_, T, TMap>> {
+        self.get_symbol_set_view(&self.first_symbols, symbol)
     }
 
-    fn get_symbols_from_hash_map<'a>(
-        hash_map: &'a SymbolsMap<T>,
-        key: &T,
-    ) -> Option<&'a HashSet<T>> {
-        hash_map.get(key)
+    pub fn get_follow_symbols(&self, symbol: &T) -> Option<SymbolSetView<'_, T, TMap>> {
+        self.get_symbol_set_view(&self.follow_symbols, symbol)
     }
 
-    fn ref_map_to_symbols_map(symbols_ref_map: HashMap<T, RefCell<HashSet<T>>>) -> SymbolsMap<T> {
-        HashMap::from_iter(
-            symbols_ref_map
-                .into_iter()
-                .map(|(symbol, symbols_ref)| (symbol, symbols_ref.take())),
-        )
+    pub fn is_nullable(&self, symbol: &T) -> bool {
+        self.symbol_table
+            .get_id(symbol)
+            .is_some_and(|symbol_id| self.is_nullable_id(symbol_id))
+    }
+
+    fn get_symbol_set_view<'a>(
+        &'a self,
+        symbol_sets: &'a [Option<SymbolSet>],
+        symbol: &T,
+    ) -> Option<SymbolSetView<'a, T, TMap>> {
+        self.symbol_table
+            .get_id(symbol)
+            .and_then(|symbol_id| Self::get_symbol_set(symbol_sets, symbol_id))
+            .map(|symbol_set| SymbolSetView::new(symbol_set, &self.symbol_table))
     }
 }
 
 impl<T: Clone + Eq + Hash> FirstFollowSymbols<T> {
-    pub fn from(grammar: &ContextFreeGrammar<T>) -> Self {
-        let first_symbols = Self::inner_get_first_symbols(grammar);
-        let follow_symbols = Self::inner_get_follow_symbols(grammar, &first_symbols);
+    pub fn new(
+        epsilon_symbol: T,
+        first_symbols: SymbolsMap<T>,
+        follow_symbols: SymbolsMap<T>,
+    ) -> Self {
+        Self::with_symbol_map(epsilon_symbol, first_symbols, follow_symbols)
+    }
+}
 
-        Self::new(first_symbols, follow_symbols)
+impl<T: Clone + PartialEq, TMap: SymbolMap<T>> FirstFollowSymbols<T, TMap> {
+    /// Builds the sets from symbols maps, looking symbols up with `TMap`.
+    pub fn with_symbol_map(
+        epsilon_symbol: T,
+        first_symbols: SymbolsMap<T>,
+        follow_symbols: SymbolsMap<T>,
+    ) -> Self {
+        let mut symbol_table: SymbolTable<T, TMap> = SymbolTable::with_symbol_map();
+        let epsilon_symbol_id = symbol_table.intern(&epsilon_symbol);
+
+        let first_symbol_sets = Self::symbols_map_to_symbol_sets(&mut symbol_table, first_symbols);
+        let follow_symbol_sets =
+            Self::symbols_map_to_symbol_sets(&mut symbol_table, follow_symbols);
+
+        Self::from_symbol_sets(
+            Arc::new(symbol_table),
+            epsilon_symbol_id,
+            first_symbol_sets,
+            follow_symbol_sets,
+        )
     }
 
-    fn inner_get_first_symbols(grammar: &ContextFreeGrammar<T>) -> SymbolsMap<T> {
-        let non_terminal_symbols = grammar.get_non_terminal_symbols();
-        let terminal_symbols = grammar.get_terminal_symbols();
+    pub fn from(grammar: &ContextFreeGrammar<T, TMap>) -> Self {
+        let symbols_count = grammar.get_symbol_table().len();
+        let dependent_symbols = Self::inner_get_dependent_symbols(grammar);
 
-        let first_symbols_map: HashMap<T, RefCell<HashSet<T>>> =
-            Self::inner_get_first_symbols_initial_map(&non_terminal_symbols, &terminal_symbols);
+        let mut first_symbols = Self::inner_get_first_symbols_initial_sets(grammar);
 
-        Self::converge(
-            &first_symbols_map,
-            |model: &HashMap<T, RefCell<HashSet<T>>>| -> bool {
-                let mut updated_at_iter: bool = false;
+        Self::inner_get_first_symbols(
+            grammar,
+            &mut first_symbols,
+            &dependent_symbols,
+            Self::inner_get_non_terminal_symbol_set(grammar),
+        );
 
-                let productions = non_terminal_symbols
-                    .iter()
-                    .map(|symbol| (symbol, grammar.get_productions(symbol).unwrap()));
+        let follow_edges = Self::inner_get_follow_edges(grammar, &first_symbols);
+
+        let mut follow_symbols = vec![SymbolSet::with_capacity(symbols_count); symbols_count];
+
+        for (_, output_ids) in Self::inner_get_production_ids(grammar) {
+            Self::get_follow_symbols_process_production_first_symbols(
+                grammar,
+                &first_symbols,
+                &mut follow_symbols,
+                output_ids,
+            );
+        }
+
+        Self::inner_get_follow_symbols(
+            &mut follow_symbols,
+            &follow_edges,
+            Self::inner_get_non_terminal_symbol_set(grammar),
+        );
+
+        Self::from_grammar_symbol_sets(
+            grammar,
+            first_symbols,
+            follow_symbols,
+            dependent_symbols,
+            follow_edges,
+        )
+    }
+
+    pub fn from_fixed_point(grammar: &ContextFreeGrammar<T, TMap>) -> Self {
+        let first_symbols = Self::inner_get_first_symbols_fixed_point(grammar);
+        let follow_symbols = Self::inner_get_follow_symbols_fixed_point(grammar, &first_symbols);
+        let follow_edges = Self::inner_get_follow_edges(grammar, &first_symbols);
+
+        Self::from_grammar_symbol_sets(
+            grammar,
+            first_symbols,
+            follow_symbols,
+            Self::inner_get_dependent_symbols(grammar),
+            follow_edges,
+        )
+    }
+
+    /// Updates the sets after a production is added to the grammar.
+    ///
+    /// Returns the IDs of the symbols whose FIRST or FOLLOW symbols changed.
+    pub fn update_added_production(
+        &mut self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        production: &ContextFreeGrammarProduction<T>,
+    ) -> SymbolSet {
+        // Adding a production to a non terminal symbol only adds symbols to the sets
+        let is_input_non_terminal = grammar
+            .get_productions(&production.input)
+            .is_some_and(|productions| productions.len() > 1);
+
+        self.inner_update(grammar, production, !is_input_non_terminal)
+    }
+
+    /// Updates the sets after a production is removed from the grammar.
+    ///
+    /// Returns the IDs of the symbols whose FIRST or FOLLOW symbols changed.
+    pub fn update_removed_production(
+        &mut self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        production: &ContextFreeGrammarProduction<T>,
+    ) -> SymbolSet {
+        self.inner_update(grammar, production, true)
+    }
+
+    fn from_grammar_symbol_sets(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: Vec<SymbolSet>,
+        follow_symbols: Vec<SymbolSet>,
+        dependent_symbols: Vec<SymbolSet>,
+        follow_edges: Vec<SymbolSet>,
+    ) -> Self {
+        let mut first_follow_symbols = Self::from_symbol_sets(
+            grammar.get_symbol_table().clone(),
+            grammar.get_epsilon_symbol_id(),
+            vec![],
+            vec![],
+        );
 
-                for (symbol, tuple_productions) in productions {
-                    let mut tuple_symbol_first_symbols: RefMut<HashSet<T>> =
-                        first_symbols_map.get(symbol).unwrap().borrow_mut();
+        first_follow_symbols.inner_set_grammar_symbol_sets(grammar, first_symbols, follow_symbols);
+        first_follow_symbols.dependent_symbols = dependent_symbols;
+        first_follow_symbols.follow_edges = follow_edges;
 
-                    for production in tuple_productions {
+        first_follow_symbols
+    }
+
+    fn from_symbol_sets(
+        symbol_table: Arc<SymbolTable<T, TMap>>,
+        epsilon_symbol_id: u32,
+        mut first_symbols: Vec<Option<SymbolSet>>,
+        mut follow_symbols: Vec<Option<SymbolSet>>,
+    ) -> Self {
+        first_symbols.resize(symbol_table.len(), None);
+        follow_symbols.resize(symbol_table.len(), None);
+
+        Self {
+            dependent_symbols: vec![],
+            epsilon_symbol_id,
+            first_symbols,
+            follow_edges: vec![],
+            follow_symbols,
+            symbol_table,
+        }
+    }
+
+    /// Symbols of each symbol whose productions include it.
+    fn inner_get_dependent_symbols(grammar: &ContextFreeGrammar<T, TMap>) -> Vec<SymbolSet> {
+        let mut dependent_symbols: Vec<SymbolSet> =
+            vec![SymbolSet::new(); grammar.get_symbol_table().len()];
+
+        for (input_id, output_ids) in Self::inner_get_production_ids(grammar) {
+            for output_id in output_ids {
+                dependent_symbols[*output_id as usize].insert(input_id);
+            }
+        }
+
+        dependent_symbols
+    }
+
+    /// Computes FIRST symbols from a subset of them, processing first the productions of the
+    /// pending symbols.
+    ///
+    /// Returns the IDs of the symbols whose FIRST symbols were updated.
+    fn inner_get_first_symbols(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &mut [SymbolSet],
+        dependent_symbols: &[SymbolSet],
+        mut pending_symbol_ids: SymbolSet,
+    ) -> SymbolSet {
+        let mut pending_symbols: VecDeque<u32> = pending_symbol_ids.iter().collect();
+        let mut updated_symbol_ids = SymbolSet::new();
+
+        while let Some(symbol_id) = pending_symbols.pop_front() {
+            pending_symbol_ids.remove(symbol_id);
+
+            let mut is_updated = false;
+
+            for output_ids in grammar.get_production_output_ids(symbol_id) {
+                is_updated |= Self::inner_get_first_symbols_process_production(
+                    grammar,
+                    first_symbols,
+                    symbol_id,
+                    output_ids,
+                );
+            }
+
+            if is_updated {
+                updated_symbol_ids.insert(symbol_id);
+
+                for dependent_symbol_id in dependent_symbols[symbol_id as usize].iter() {
+                    if pending_symbol_ids.insert(dependent_symbol_id) {
+                        pending_symbols.push_back(dependent_symbol_id);
+                    }
+                }
+            }
+        }
+
+        updated_symbol_ids
+    }
+
+    fn inner_get_first_symbols_fixed_point(
+        grammar: &ContextFreeGrammar<T, TMap>,
+    ) -> Vec<SymbolSet> {
+        let non_terminal_symbol_ids = grammar.get_non_terminal_symbol_ids();
+        let first_symbols_model = RefCell::new(Self::inner_get_first_symbols_initial_sets(grammar));
+
+        Self::converge(
+            &first_symbols_model,
+            |model: &RefCell<Vec<SymbolSet>>| -> bool {
+                let mut first_symbols = model.borrow_mut();
+                let mut updated_at_iter: bool = false;
+
+                for symbol_id in &non_terminal_symbol_ids {
+                    for output_ids in grammar.get_production_output_ids(*symbol_id) {
                         updated_at_iter |= Self::inner_get_first_symbols_process_production(
                             grammar,
-                            model,
-                            &mut tuple_symbol_first_symbols,
-                            &production,
+                            &mut first_symbols,
+                            *symbol_id,
+                            output_ids,
                         );
                     }
                 }
@@ -99,165 +448,164 @@ impl<T: Clone + Eq + Hash> FirstFollowSymbols<T> {
             },
         );
 
-        Self::ref_map_to_symbols_map(first_symbols_map)
+        first_symbols_model.into_inner()
     }
 
-    fn inner_get_first_symbols_initial_map(
-        non_terminal_symbols: &Vec<T>,
-        terminal_symbols: &Vec<T>,
-    ) -> HashMap<T, RefCell<HashSet<T>>> {
-        let non_terminal_symbols_iterator =
-            Self::inner_get_first_symbols_non_terminal_map(&non_terminal_symbols);
-        let terminal_symbols_iterator =
-            Self::inner_get_first_symbols_terminal_map(&terminal_symbols);
+    fn inner_get_first_symbols_initial_set(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        symbol_id: u32,
+    ) -> SymbolSet {
+        let mut first_symbol_set = SymbolSet::with_capacity(grammar.get_symbol_table().len());
+
+        if grammar.is_terminal_id(symbol_id) {
+            first_symbol_set.insert(symbol_id);
+        }
 
-        HashMap::from_iter(non_terminal_symbols_iterator.chain(terminal_symbols_iterator))
+        first_symbol_set
     }
 
-    fn inner_get_first_symbols_non_terminal_map(
-        symbols: &Vec<T>,
-    ) -> Map<Iter<'_, T>, fn(&T) -> (T, RefCell<HashSet<T, RandomState>>)> {
-        symbols
-            .iter()
-            .map(|symbol| (symbol.clone(), RefCell::new(HashSet::new())))
+    fn inner_get_first_symbols_initial_sets(
+        grammar: &ContextFreeGrammar<T, TMap>,
+    ) -> Vec<SymbolSet> {
+        (0..grammar.get_symbol_table().len() as u32)
+            .map(|symbol_id| Self::inner_get_first_symbols_initial_set(grammar, symbol_id))
+            .collect()
     }
 
     fn inner_get_first_symbols_process_production(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        tuple_symbol_first_symbols: &mut RefMut<HashSet<T>>,
-        production: &ContextFreeGrammarProduction<T>,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &mut [SymbolSet],
+        input_id: u32,
+        output_ids: &[u32],
     ) -> bool {
-        let mut first_symbols_updated: bool = false;
-        let mut output_iterator_option: Option<Iter<T>> = Some(production.output.iter());
+        let epsilon_symbol_id = grammar.get_epsilon_symbol_id();
 
-        while output_iterator_option.is_some() {
-            let production_symbol_option = output_iterator_option.as_mut().unwrap().next();
+        let mut production_first_symbols = SymbolSet::new();
+        let mut is_nullable_production = true;
 
-            match production_symbol_option {
-                Some(production_symbol) => {
-                    first_symbols_updated |=
-                        Self::inner_get_first_symbols_process_production_symbol(
-                            grammar,
-                            first_symbols_map,
-                            tuple_symbol_first_symbols,
-                            &mut output_iterator_option,
-                            &production.input,
-                            production_symbol,
-                        );
-                }
-                None => {
-                    first_symbols_updated |=
-                        tuple_symbol_first_symbols.insert(grammar.get_epsilon_symbol().clone());
+        for output_id in output_ids {
+            let output_id = *output_id;
+
+            if output_id == input_id {
+                if !first_symbols[input_id as usize].contains(epsilon_symbol_id) {
+                    is_nullable_production = false;
                     break;
                 }
-            }
-        }
+            } else if grammar.is_non_terminal_id(output_id) {
+                let symbol_first_symbols = &first_symbols[output_id as usize];
 
-        first_symbols_updated
-    }
+                production_first_symbols.union_with(symbol_first_symbols);
 
-    fn inner_get_first_symbols_process_production_symbol(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        tuple_symbol_first_symbols: &mut RefMut<HashSet<T>>,
-        output_iterator_option: &mut Option<Iter<T>>,
-        production_input: &T,
-        production_symbol: &T,
-    ) -> bool {
-        let mut first_symbols_updated = false;
+                if !symbol_first_symbols.contains(epsilon_symbol_id) {
+                    is_nullable_production = false;
+                    break;
+                }
 
-        if production_symbol.eq(production_input) {
-            if !tuple_symbol_first_symbols.contains(grammar.get_epsilon_symbol()) {
-                *output_iterator_option = None;
-            }
-        } else {
-            if grammar.is_non_terminal(production_symbol) {
-                first_symbols_updated |=
-                    Self::inner_get_first_symbols_process_production_non_terminal_symbol(
-                        grammar,
-                        first_symbols_map,
-                        tuple_symbol_first_symbols,
-                        output_iterator_option,
-                        production_symbol,
-                    );
+                production_first_symbols.remove(epsilon_symbol_id);
             } else {
-                first_symbols_updated |=
-                    tuple_symbol_first_symbols.insert(production_symbol.clone());
+                production_first_symbols.insert(output_id);
 
-                *output_iterator_option = None
+                is_nullable_production = false;
+                break;
             }
         }
 
-        first_symbols_updated
-    }
+        if is_nullable_production {
+            production_first_symbols.insert(epsilon_symbol_id);
+        }
 
-    fn inner_get_first_symbols_process_production_non_terminal_symbol(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        tuple_symbol_first_symbols: &mut RefMut<HashSet<T>>,
-        output_iterator_option: &mut Option<Iter<T>>,
-        production_symbol: &T,
-    ) -> bool {
-        let mut first_symbols_updated = false;
+        first_symbols[input_id as usize].union_with(&production_first_symbols)
+    }
 
-        let symbol_first_symbols: Ref<HashSet<T>> =
-            first_symbols_map.get(production_symbol).unwrap().borrow();
+    /// Symbols of each symbol whose FOLLOW symbols include the ones of the symbol.
+    fn inner_get_follow_edges(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+    ) -> Vec<SymbolSet> {
+        (0..grammar.get_symbol_table().len() as u32)
+            .map(|symbol_id| {
+                Self::inner_get_follow_edges_of_symbol(grammar, first_symbols, symbol_id)
+            })
+            .collect()
+    }
 
-        if symbol_first_symbols.contains(grammar.get_epsilon_symbol()) {
-            first_symbols_updated |= Self::insert_many(
-                tuple_symbol_first_symbols,
-                &mut symbol_first_symbols
-                    .iter()
-                    .filter(|symbol| grammar.get_epsilon_symbol().ne(*symbol)),
-            );
-        } else {
-            first_symbols_updated |=
-                Self::insert_many(tuple_symbol_first_symbols, &mut symbol_first_symbols.iter());
+    fn inner_get_follow_edges_of_symbol(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+        symbol_id: u32,
+    ) -> SymbolSet {
+        let mut follow_edges = SymbolSet::new();
 
-            *output_iterator_option = None
+        for output_ids in grammar.get_production_output_ids(symbol_id) {
+            for output_id in Self::get_follow_symbols_last_epsilon_chain(
+                grammar,
+                first_symbols,
+                symbol_id,
+                output_ids,
+            ) {
+                follow_edges.insert(output_id);
+            }
         }
 
-        first_symbols_updated
+        follow_edges
     }
 
-    fn inner_get_first_symbols_terminal_map(
-        symbols: &Vec<T>,
-    ) -> Map<Iter<'_, T>, fn(&T) -> (T, RefCell<HashSet<T, RandomState>>)> {
-        symbols.iter().map(|symbol| {
-            let mut hash_set = HashSet::new();
-            hash_set.insert(symbol.clone());
+    /*
+     * Implementation notes:
+     *
+     * FOLLOW symbols taken from FIRST symbols do not change once FIRST symbols are computed, so
+     * they are added before calling this function. The remaining rule adds FOLLOW(A) to
+     * FOLLOW(X) for every production A → αXβ in which β is nullable, so FOLLOW(A) is propagated
+     * through those edges, starting from the pending symbols, until no set changes. Returns the
+     * IDs of the symbols whose FOLLOW symbols were updated.
+     */
+    fn inner_get_follow_symbols(
+        follow_symbols: &mut [SymbolSet],
+        follow_edges: &[SymbolSet],
+        mut pending_symbol_ids: SymbolSet,
+    ) -> SymbolSet {
+        let mut pending_symbols: VecDeque<u32> = pending_symbol_ids.iter().collect();
+        let mut updated_symbol_ids = SymbolSet::new();
+
+        while let Some(symbol_id) = pending_symbols.pop_front() {
+            pending_symbol_ids.remove(symbol_id);
+
+            for target_id in follow_edges[symbol_id as usize].iter() {
+                if Self::union_symbol_sets(follow_symbols, target_id, symbol_id) {
+                    updated_symbol_ids.insert(target_id);
+
+                    if pending_symbol_ids.insert(target_id) {
+                        pending_symbols.push_back(target_id);
+                    }
+                }
+            }
+        }
 
-            (symbol.clone(), RefCell::new(hash_set))
-        })
+        updated_symbol_ids
     }
 
-    fn inner_get_follow_symbols(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-    ) -> SymbolsMap<T> {
-        let non_terminal_symbols = grammar.get_non_terminal_symbols();
+    fn inner_get_follow_symbols_fixed_point(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+    ) -> Vec<SymbolSet> {
+        let symbols_count = grammar.get_symbol_table().len();
+        let non_terminal_symbol_ids = grammar.get_non_terminal_symbol_ids();
 
-        let follow_symbols_map: HashMap<T, RefCell<HashSet<T>>> = HashMap::from_iter(
-            non_terminal_symbols
-                .iter()
-                .map(|symbol| (symbol.clone(), RefCell::new(HashSet::new()))),
-        );
+        let follow_symbols_model =
+            RefCell::new(vec![SymbolSet::with_capacity(symbols_count); symbols_count]);
 
-        Self::converge(&follow_symbols_map, |model| {
+        Self::converge(&follow_symbols_model, |model| {
+            let mut follow_symbols = model.borrow_mut();
             let mut updated_at_iter: bool = false;
 
-            let productions = non_terminal_symbols
-                .iter()
-                .map(|symbol| (symbol, grammar.get_productions(symbol).unwrap()));
-
-            for (_, tuple_productions) in productions {
-                for production in tuple_productions {
+            for symbol_id in &non_terminal_symbol_ids {
+                for output_ids in grammar.get_production_output_ids(*symbol_id) {
                     updated_at_iter |= Self::get_follow_symbols_process_production(
                         grammar,
-                        first_symbols_map,
-                        model,
-                        production,
+                        first_symbols,
+                        &mut follow_symbols,
+                        *symbol_id,
+                        output_ids,
                     );
                 }
             }
@@ -265,195 +613,517 @@ impl<T: Clone + Eq + Hash> FirstFollowSymbols<T> {
             updated_at_iter
         });
 
-        Self::ref_map_to_symbols_map(follow_symbols_map)
+        follow_symbols_model.into_inner()
     }
 
-    fn get_follow_symbols_process_production_last_epsilon_chain(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-        follow_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        production: &ContextFreeGrammarProduction<T>,
-    ) -> bool {
-        let mut follow_symbols_updated: bool = false;
-
-        let mut production_output_index: usize = production.output.len() - 1;
+    /// Non terminal symbols of a production whose FOLLOW symbols include the ones of the input.
+    fn get_follow_symbols_last_epsilon_chain(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+        input_id: u32,
+        output_ids: &[u32],
+    ) -> Vec<u32> {
+        let mut chain_symbol_ids: Vec<u32> = vec![];
 
-        loop {
-            let output_symbol: &T = production.output.get(production_output_index).unwrap();
+        for output_id in output_ids.iter().rev() {
+            let output_id = *output_id;
 
-            if output_symbol.ne(&production.input) && grammar.is_non_terminal(output_symbol) {
-                let input_follow_symbols =
-                    follow_symbols_map.get(&production.input).unwrap().borrow();
-                let mut symbol_follow_symbols_ref =
-                    follow_symbols_map.get(output_symbol).unwrap().borrow_mut();
-
-                input_follow_symbols.iter().for_each(|symbol| {
-                    follow_symbols_updated |= symbol_follow_symbols_ref.insert(symbol.clone());
-                });
+            if output_id != input_id && grammar.is_non_terminal_id(output_id) {
+                chain_symbol_ids.push(output_id);
             }
 
-            let output_symbol_first_symbols: &HashSet<T> =
-                first_symbols_map.get(output_symbol).unwrap().borrow();
-
-            let output_symbol_first_symbols_contains_epsilon: bool =
-                output_symbol_first_symbols.contains(grammar.get_epsilon_symbol());
-
-            if production_output_index == 0 || !output_symbol_first_symbols_contains_epsilon {
+            if !first_symbols[output_id as usize].contains(grammar.get_epsilon_symbol_id()) {
                 break;
-            } else {
-                production_output_index -= 1;
             }
         }
 
-        follow_symbols_updated
+        chain_symbol_ids
     }
 
-    fn get_follow_symbols_process_production(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-        follow_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        production: &ContextFreeGrammarProduction<T>,
-    ) -> bool {
-        let mut follow_symbols_updated: bool =
-            Self::get_follow_symbols_process_production_last_epsilon_chain(
+    fn get_follow_symbols_process_production_first_symbols(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+        follow_symbols: &mut [SymbolSet],
+        output_ids: &[u32],
+    ) -> SymbolSet {
+        let mut updated_symbol_ids = SymbolSet::new();
+        let mut first_indexes: Vec<usize> = vec![];
+
+        for production_output_index in (0..output_ids.len().saturating_sub(1)).rev() {
+            if Self::get_follow_symbols_process_production_symbol(
                 grammar,
-                first_symbols_map,
-                follow_symbols_map,
-                &production,
-            );
-
-        let last_production_output_index: usize = production.output.len() - 1;
+                first_symbols,
+                follow_symbols,
+                output_ids,
+                production_output_index,
+                &mut first_indexes,
+            ) {
+                updated_symbol_ids.insert(output_ids[production_output_index]);
+            }
+        }
 
-        if last_production_output_index > 0 {
-            let mut production_output_index: usize = last_production_output_index - 1;
-            let mut first_indexes: Vec<usize> = vec![];
+        updated_symbol_ids
+    }
 
-            loop {
-                follow_symbols_updated |= Self::get_follow_symbols_process_production_symbol(
-                    grammar,
-                    first_symbols_map,
-                    follow_symbols_map,
-                    production,
-                    production_output_index,
-                    &mut first_indexes,
-                );
+    fn get_follow_symbols_process_production(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+        follow_symbols: &mut [SymbolSet],
+        input_id: u32,
+        output_ids: &[u32],
+    ) -> bool {
+        let mut follow_symbols_updated: bool = false;
 
-                if production_output_index == 0 {
-                    break;
-                } else {
-                    production_output_index -= 1;
-                }
-            }
+        for output_id in Self::get_follow_symbols_last_epsilon_chain(
+            grammar,
+            first_symbols,
+            input_id,
+            output_ids,
+        ) {
+            follow_symbols_updated |= Self::union_symbol_sets(follow_symbols, output_id, input_id);
         }
 
         follow_symbols_updated
+            | !Self::get_follow_symbols_process_production_first_symbols(
+                grammar,
+                first_symbols,
+                follow_symbols,
+                output_ids,
+            )
+            .is_empty()
     }
 
     fn get_follow_symbols_process_production_symbol(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-        follow_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
-        production: &ContextFreeGrammarProduction<T>,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: &[SymbolSet],
+        follow_symbols: &mut [SymbolSet],
+        output_ids: &[u32],
         production_output_index: usize,
         first_indexes: &mut Vec<usize>,
     ) -> bool {
-        let follow_symbols_updated: bool;
+        let current_symbol_id = output_ids[production_output_index];
 
-        let current_symbol: &T = production.output.get(production_output_index).unwrap();
+        if !grammar.is_non_terminal_id(current_symbol_id) {
+            first_indexes.clear();
 
-        if grammar.is_non_terminal(current_symbol) {
-            follow_symbols_updated =
-                Self::get_follow_symbols_process_production_non_terminal_symbol(
-                    grammar,
-                    first_symbols_map,
-                    follow_symbols_map,
-                    production,
-                    production_output_index,
-                    first_indexes,
-                    current_symbol,
-                );
-        } else {
-            follow_symbols_updated = false;
+            return false;
+        }
+
+        let epsilon_symbol_id = grammar.get_epsilon_symbol_id();
+        let next_symbol_index: usize = production_output_index + 1;
+        let next_symbol_id = output_ids[next_symbol_index];
+
+        if current_symbol_id == next_symbol_id {
+            return false;
+        }
 
+        if !first_symbols[next_symbol_id as usize].contains(epsilon_symbol_id) {
             first_indexes.clear();
         }
 
-        follow_symbols_updated
+        first_indexes.push(next_symbol_index);
+
+        let mut lambda_chain_first_symbols = SymbolSet::new();
+
+        for first_index in first_indexes.iter() {
+            lambda_chain_first_symbols
+                .union_with(&first_symbols[output_ids[*first_index] as usize]);
+        }
+
+        lambda_chain_first_symbols.remove(epsilon_symbol_id);
+
+        follow_symbols[current_symbol_id as usize].union_with(&lambda_chain_first_symbols)
+    }
+
+    fn inner_get_non_terminal_symbol_set(grammar: &ContextFreeGrammar<T, TMap>) -> SymbolSet {
+        let mut non_terminal_symbol_set = SymbolSet::new();
+
+        for symbol_id in grammar.get_non_terminal_symbol_ids() {
+            non_terminal_symbol_set.insert(symbol_id);
+        }
+
+        non_terminal_symbol_set
     }
 
-    fn get_follow_symbols_process_production_non_terminal_symbol(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-        follow_symbols_map: &HashMap<T, RefCell<HashSet<T>>>,
+    /// Symbols reachable from the initial ones through the edges provided.
+    fn inner_get_reachable_symbols(mut symbol_ids: SymbolSet, edges: &[SymbolSet]) -> SymbolSet {
+        let mut pending_symbol_ids: Vec<u32> = symbol_ids.iter().collect();
+
+        while let Some(symbol_id) = pending_symbol_ids.pop() {
+            for target_id in edges[symbol_id as usize].iter() {
+                if symbol_ids.insert(target_id) {
+                    pending_symbol_ids.push(target_id);
+                }
+            }
+        }
+
+        symbol_ids
+    }
+
+    /*
+     * Implementation notes:
+     *
+     * The symbols depending on the FIRST symbols of each symbol and the FOLLOW edges are kept
+     * between updates, so the worklists only start from the symbols the edited production
+     * affects:
+     *
+     *     1. FIRST(A) of the input A. If symbols may be removed from the sets, FIRST(B) of every
+     *        symbol B depending on A is reset as well.
+     *     2. FOLLOW(X) of every symbol X of a production of a symbol whose FIRST symbols changed
+     *        or of the edited production. If symbols may be removed from the sets, those sets and
+     *        the ones reachable through FOLLOW edges are reset.
+     *
+     * Adding a production to a non terminal symbol only adds symbols to the sets, so the current
+     * sets are a valid starting point of the worklists and nothing is reset. Any other set does
+     * not depend on the edited production, so it is already computed.
+     */
+    fn inner_update(
+        &mut self,
+        grammar: &ContextFreeGrammar<T, TMap>,
         production: &ContextFreeGrammarProduction<T>,
-        production_output_index: usize,
-        first_indexes: &mut Vec<usize>,
-        current_symbol: &T,
-    ) -> bool {
-        let mut follow_symbols_updated: bool = false;
+        is_reset_required: bool,
+    ) -> SymbolSet {
+        let symbols_count = grammar.get_symbol_table().len();
 
-        let next_symbol_index: usize = production_output_index + 1;
-        let next_symbol = production.output.get(next_symbol_index).unwrap();
+        if !self.inner_update_is_symbol_table_compatible(grammar)
+            || self.dependent_symbols.len() != self.symbol_table.len()
+        {
+            *self = Self::from(grammar);
 
-        if current_symbol.ne(next_symbol) {
-            let next_symbol_first_symbols = first_symbols_map.get(next_symbol).unwrap();
+            let mut changed_symbol_ids = SymbolSet::with_capacity(symbols_count);
 
-            if !next_symbol_first_symbols.contains(grammar.get_epsilon_symbol()) {
-                first_indexes.clear();
+            for symbol_id in 0..symbols_count as u32 {
+                changed_symbol_ids.insert(symbol_id);
             }
 
-            first_indexes.push(next_symbol_index);
+            return changed_symbol_ids;
+        }
 
-            let mut current_symbol_follow_symbols =
-                follow_symbols_map.get(current_symbol).unwrap().borrow_mut();
+        let input_id = grammar.get_symbol_id(&production.input).unwrap();
+        let output_ids: Vec<u32> = production
+            .output
+            .iter()
+            .map(|symbol| grammar.get_symbol_id(symbol).unwrap())
+            .collect();
 
-            follow_symbols_updated |= Self::update_follow_symbols_lambda_chain(
-                grammar,
-                first_symbols_map,
-                production,
-                first_indexes,
-                &mut current_symbol_follow_symbols,
-            );
+        // Symbols which may have become terminal or non terminal symbols
+        let mut edited_symbol_ids = SymbolSet::new();
+
+        edited_symbol_ids.insert(input_id);
+
+        for symbol_id in output_ids
+            .iter()
+            .copied()
+            .chain(self.symbol_table.len() as u32..symbols_count as u32)
+        {
+            edited_symbol_ids.insert(symbol_id);
         }
 
-        follow_symbols_updated
+        self.dependent_symbols
+            .resize(symbols_count, SymbolSet::new());
+        self.follow_edges.resize(symbols_count, SymbolSet::new());
+
+        for output_id in &output_ids {
+            if grammar
+                .get_production_output_ids(input_id)
+                .iter()
+                .any(|production_output_ids| production_output_ids.contains(output_id))
+            {
+                self.dependent_symbols[*output_id as usize].insert(input_id);
+            } else {
+                self.dependent_symbols[*output_id as usize].remove(input_id);
+            }
+        }
+
+        let mut reset_first_symbol_ids = SymbolSet::new();
+
+        reset_first_symbol_ids.insert(input_id);
+
+        if is_reset_required {
+            reset_first_symbol_ids =
+                Self::inner_get_reachable_symbols(reset_first_symbol_ids, &self.dependent_symbols);
+        }
+
+        let mut previous_first_symbol_ids = reset_first_symbol_ids.clone();
+        previous_first_symbol_ids.union_with(&edited_symbol_ids);
+
+        let previous_first_symbols = self.inner_get_first_symbol_sets(&previous_first_symbol_ids);
+
+        let mut first_symbols = Self::take_symbol_sets(&mut self.first_symbols, symbols_count);
+
+        for symbol_id in edited_symbol_ids.iter() {
+            if !grammar.is_non_terminal_id(symbol_id) {
+                first_symbols[symbol_id as usize] =
+                    Self::inner_get_first_symbols_initial_set(grammar, symbol_id);
+            }
+        }
+
+        if is_reset_required {
+            for symbol_id in reset_first_symbol_ids.iter() {
+                first_symbols[symbol_id as usize] =
+                    Self::inner_get_first_symbols_initial_set(grammar, symbol_id);
+            }
+        }
+
+        let updated_first_symbol_ids = Self::inner_get_first_symbols(
+            grammar,
+            &mut first_symbols,
+            &self.dependent_symbols,
+            reset_first_symbol_ids,
+        );
+
+        let mut changed_first_symbol_ids = updated_first_symbol_ids.clone();
+
+        for (symbol_id, previous_first_symbol_set) in &previous_first_symbols {
+            if previous_first_symbol_set.as_ref() != Some(&first_symbols[*symbol_id as usize]) {
+                changed_first_symbol_ids.insert(*symbol_id);
+            }
+        }
+
+        // Symbols whose productions include the edited production or a symbol whose FIRST
+        // symbols changed
+        let mut affected_symbol_ids = SymbolSet::new();
+
+        affected_symbol_ids.insert(input_id);
+
+        for symbol_id in changed_first_symbol_ids.iter() {
+            affected_symbol_ids.union_with(&self.dependent_symbols[symbol_id as usize]);
+        }
+
+        let affected_follow_edges: Vec<(u32, SymbolSet)> = affected_symbol_ids
+            .iter()
+            .map(|symbol_id| {
+                (
+                    symbol_id,
+                    Self::inner_get_follow_edges_of_symbol(grammar, &first_symbols, symbol_id),
+                )
+            })
+            .collect();
+
+        let mut reset_follow_symbol_ids = SymbolSet::new();
+
+        if is_reset_required {
+            for output_id in &output_ids {
+                reset_follow_symbol_ids.insert(*output_id);
+            }
+
+            for symbol_id in affected_symbol_ids.iter() {
+                for production_output_ids in grammar.get_production_output_ids(symbol_id) {
+                    if production_output_ids
+                        .iter()
+                        .any(|output_id| changed_first_symbol_ids.contains(*output_id))
+                    {
+                        for output_id in production_output_ids {
+                            reset_follow_symbol_ids.insert(*output_id);
+                        }
+                    }
+                }
+            }
+
+            // Both the previous and the updated edges reach the sets to reset
+            for (symbol_id, follow_edges) in &affected_follow_edges {
+                self.follow_edges[*symbol_id as usize].union_with(follow_edges);
+            }
+
+            reset_follow_symbol_ids =
+                Self::inner_get_reachable_symbols(reset_follow_symbol_ids, &self.follow_edges);
+        }
+
+        for (symbol_id, follow_edges) in affected_follow_edges {
+            self.follow_edges[symbol_id as usize] = follow_edges;
+        }
+
+        let mut previous_follow_symbol_ids = reset_follow_symbol_ids.clone();
+        previous_follow_symbol_ids.union_with(&edited_symbol_ids);
+
+        let previous_follow_symbols =
+            self.inner_get_follow_symbol_sets(&previous_follow_symbol_ids);
+
+        let mut follow_symbols = Self::take_symbol_sets(&mut self.follow_symbols, symbols_count);
+
+        for symbol_id in reset_follow_symbol_ids.iter() {
+            follow_symbols[symbol_id as usize] = SymbolSet::with_capacity(symbols_count);
+        }
+
+        // Productions adding FIRST symbols to the FOLLOW symbols of an affected or reset symbol
+        let mut pending_symbol_ids = affected_symbol_ids;
+
+        for symbol_id in reset_follow_symbol_ids.iter() {
+            pending_symbol_ids.union_with(&self.dependent_symbols[symbol_id as usize]);
+        }
+
+        let mut updated_follow_symbol_ids = SymbolSet::new();
+
+        for symbol_id in pending_symbol_ids.iter() {
+            for production_output_ids in grammar.get_production_output_ids(symbol_id) {
+                updated_follow_symbol_ids.union_with(
+                    &Self::get_follow_symbols_process_production_first_symbols(
+                        grammar,
+                        &first_symbols,
+                        &mut follow_symbols,
+                        production_output_ids,
+                    ),
+                );
+            }
+        }
+
+        pending_symbol_ids.union_with(&updated_follow_symbol_ids);
+
+        updated_follow_symbol_ids.union_with(&Self::inner_get_follow_symbols(
+            &mut follow_symbols,
+            &self.follow_edges,
+            pending_symbol_ids,
+        ));
+
+        self.inner_set_grammar_symbol_sets(grammar, first_symbols, follow_symbols);
+        self.symbol_table = grammar.get_symbol_table().clone();
+
+        let mut changed_symbol_ids = SymbolSet::with_capacity(symbols_count);
+
+        for symbol_id in updated_first_symbol_ids.iter() {
+            if !previous_first_symbol_ids.contains(symbol_id) {
+                changed_symbol_ids.insert(symbol_id);
+            }
+        }
+
+        for symbol_id in updated_follow_symbol_ids.iter() {
+            if !previous_follow_symbol_ids.contains(symbol_id) {
+                changed_symbol_ids.insert(symbol_id);
+            }
+        }
+
+        for (symbol_id, previous_first_symbol_set) in previous_first_symbols {
+            if previous_first_symbol_set.as_ref() != self.get_first_symbol_set(symbol_id) {
+                changed_symbol_ids.insert(symbol_id);
+            }
+        }
+
+        for (symbol_id, previous_follow_symbol_set) in previous_follow_symbols {
+            if previous_follow_symbol_set.as_ref() != self.get_follow_symbol_set(symbol_id) {
+                changed_symbol_ids.insert(symbol_id);
+            }
+        }
+
+        changed_symbol_ids
     }
 
-    fn insert_many(
-        hash_set_ref: &mut RefMut<HashSet<T>>,
-        iterator: &mut dyn Iterator<Item = &T>,
-    ) -> bool {
-        let mut item_inserted: bool = false;
+    fn inner_get_first_symbol_sets(&self, symbol_ids: &SymbolSet) -> Vec<(u32, Option<SymbolSet>)> {
+        symbol_ids
+            .iter()
+            .map(|symbol_id| (symbol_id, self.get_first_symbol_set(symbol_id).cloned()))
+            .collect()
+    }
 
-        iterator.for_each(|symbol_first_symbol| {
-            item_inserted |= hash_set_ref.insert(symbol_first_symbol.clone());
-        });
+    fn inner_get_follow_symbol_sets(
+        &self,
+        symbol_ids: &SymbolSet,
+    ) -> Vec<(u32, Option<SymbolSet>)> {
+        symbol_ids
+            .iter()
+            .map(|symbol_id| (symbol_id, self.get_follow_symbol_set(symbol_id).cloned()))
+            .collect()
+    }
 
-        item_inserted
+    /// Stores the sets computed from a grammar, keeping the FIRST symbols of its symbols and the
+    /// FOLLOW symbols of its non terminal symbols.
+    fn inner_set_grammar_symbol_sets(
+        &mut self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_symbols: Vec<SymbolSet>,
+        follow_symbols: Vec<SymbolSet>,
+    ) {
+        let first_symbol_ids = grammar
+            .get_non_terminal_symbol_ids()
+            .into_iter()
+            .chain(grammar.get_terminal_symbol_ids());
+
+        self.first_symbols =
+            Self::symbol_sets_to_option_symbol_sets(first_symbols, first_symbol_ids);
+        self.follow_symbols = Self::symbol_sets_to_option_symbol_sets(
+            follow_symbols,
+            grammar.get_non_terminal_symbol_ids().into_iter(),
+        );
     }
 
-    fn update_follow_symbols_lambda_chain(
-        grammar: &ContextFreeGrammar<T>,
-        first_symbols_map: &SymbolsMap<T>,
-        production: &ContextFreeGrammarProduction<T>,
-        first_indexes: &Vec<usize>,
-        current_symbol_follow_symbols: &mut HashSet<T>,
+    /// Whether the IDs of the sets are the ones of the grammar.
+    ///
+    /// Grammars only intern new symbols when edited, so the IDs are kept as long as the symbols
+    /// of the sets are the first symbols of the grammar.
+    fn inner_update_is_symbol_table_compatible(
+        &self,
+        grammar: &ContextFreeGrammar<T, TMap>,
     ) -> bool {
-        let mut follow_symbols_updated: bool = false;
+        Arc::ptr_eq(&self.symbol_table, grammar.get_symbol_table())
+            || grammar
+                .get_symbol_table()
+                .get_symbols()
+                .starts_with(self.symbol_table.get_symbols())
+    }
 
-        first_indexes.iter().for_each(|index| {
-            let symbol: &T = production.output.get(*index).unwrap();
+    /// Productions of the grammar as pairs of input ID and output IDs.
+    fn inner_get_production_ids(grammar: &ContextFreeGrammar<T, TMap>) -> Vec<(u32, &[u32])> {
+        grammar
+            .get_non_terminal_symbol_ids()
+            .into_iter()
+            .flat_map(|symbol_id| {
+                grammar
+                    .get_production_output_ids(symbol_id)
+                    .iter()
+                    .map(move |output_ids| (symbol_id, output_ids.as_slice()))
+            })
+            .collect()
+    }
 
-            first_symbols_map
-                .get(symbol)
-                .unwrap()
-                .iter()
-                .filter(|symbol| (grammar.get_epsilon_symbol()).ne(*symbol))
-                .for_each(|symbol| {
-                    follow_symbols_updated |= current_symbol_follow_symbols.insert(symbol.clone());
-                });
-        });
+    fn symbol_sets_to_option_symbol_sets<TIter: Iterator<Item = u32>>(
+        mut symbol_sets: Vec<SymbolSet>,
+        symbol_ids: TIter,
+    ) -> Vec<Option<SymbolSet>> {
+        let mut option_symbol_sets: Vec<Option<SymbolSet>> = vec![None; symbol_sets.len()];
 
-        follow_symbols_updated
+        for symbol_id in symbol_ids {
+            option_symbol_sets[symbol_id as usize] =
+                Some(std::mem::take(&mut symbol_sets[symbol_id as usize]));
+        }
+
+        option_symbol_sets
+    }
+
+    /// Moves the sets out of their options, unknown sets being empty.
+    fn take_symbol_sets(
+        option_symbol_sets: &mut Vec<Option<SymbolSet>>,
+        symbols_count: usize,
+    ) -> Vec<SymbolSet> {
+        let mut symbol_sets: Vec<SymbolSet> = std::mem::take(option_symbol_sets)
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect();
+
+        symbol_sets.resize(symbols_count, SymbolSet::new());
+
+        symbol_sets
+    }
+
+    fn symbols_map_to_symbol_sets(
+        symbol_table: &mut SymbolTable<T, TMap>,
+        symbols_map: SymbolsMap<T>,
+    ) -> Vec<Option<SymbolSet>> {
+        let mut symbol_sets: Vec<Option<SymbolSet>> = vec![];
+
+        for (symbol, symbols) in symbols_map {
+            let symbol_id = symbol_table.intern(&symbol) as usize;
+            let mut symbol_set = SymbolSet::new();
+
+            for symbol in &symbols {
+                symbol_set.insert(symbol_table.intern(symbol));
+            }
+
+            if symbol_id >= symbol_sets.len() {
+                symbol_sets.resize(symbol_id + 1, None);
+            }
+
+            symbol_sets[symbol_id] = Some(symbol_set);
+        }
+
+        symbol_sets
     }
 }
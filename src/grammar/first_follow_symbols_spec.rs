@@ -1,5 +1,80 @@
 #[cfg(test)]
+#[allow(clippy::unused_unit)]
 mod test {
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+    use crate::grammar::symbol_set::SymbolSet;
+
+    const EPSILON: u32 = 0;
+
+    /// Builds a pseudo random grammar with nullable, recursive and mutually recursive rules.
+    fn build_grammar(seed: u64, non_terminals_count: u32) -> ContextFreeGrammar<u32> {
+        let terminals_count: u32 = non_terminals_count / 2 + 1;
+        let mut state = seed;
+        let mut next_random = |bound: u32| -> u32 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            ((state >> 33) % u64::from(bound)) as u32
+        };
+
+        let mut productions: Vec<ContextFreeGrammarProduction<u32>> = vec![];
+
+        for non_terminal in 1..=non_terminals_count {
+            for _ in 0..1 + next_random(3) {
+                let output: Vec<u32> = if next_random(5) == 0 {
+                    vec![EPSILON]
+                } else {
+                    (0..1 + next_random(4))
+                        .map(|_| {
+                            if next_random(2) == 0 {
+                                1 + next_random(non_terminals_count)
+                            } else {
+                                non_terminals_count + 1 + next_random(terminals_count)
+                            }
+                        })
+                        .collect()
+                };
+
+                productions.push(ContextFreeGrammarProduction::new(non_terminal, output));
+            }
+        }
+
+        ContextFreeGrammar::new(EPSILON, 1, productions)
+    }
+
+    fn assert_changed_symbols(
+        previous: &FirstFollowSymbols<u32>,
+        actual: &FirstFollowSymbols<u32>,
+        changed_symbol_ids: &SymbolSet,
+    ) -> () {
+        for symbol_id in 0..actual.get_symbol_table().len() as u32 {
+            let is_changed = previous.get_first_symbol_set(symbol_id)
+                != actual.get_first_symbol_set(symbol_id)
+                || previous.get_follow_symbol_set(symbol_id)
+                    != actual.get_follow_symbol_set(symbol_id);
+
+            assert_eq!(is_changed, changed_symbol_ids.contains(symbol_id));
+        }
+    }
+
+    fn assert_same_symbols(
+        expected: &FirstFollowSymbols<u32>,
+        actual: &FirstFollowSymbols<u32>,
+    ) -> () {
+        for symbol_id in 0..expected.get_symbol_table().len() as u32 {
+            assert_eq!(
+                expected.get_first_symbol_set(symbol_id),
+                actual.get_first_symbol_set(symbol_id),
+            );
+            assert_eq!(
+                expected.get_follow_symbol_set(symbol_id),
+                actual.get_follow_symbol_set(symbol_id),
+            );
+        }
+    }
 
     mod get_first_follow_symbols {
         use crate::grammar::context_free_grammar::ContextFreeGrammar;
@@ -544,4 +619,435 @@ mod test {
             )));
         }
     }
+
+    mod from_fixed_point {
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        use super::build_grammar;
+
+        #[test]
+        fn it_returns_the_same_symbols_as_from() -> () {
+            for seed in 0..50 {
+                let grammar = build_grammar(seed, 40);
+                let symbols_count = grammar.get_symbol_table().len() as u32;
+
+                let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+                let fixed_point_first_follow_symbols =
+                    FirstFollowSymbols::from_fixed_point(&grammar);
+
+                for symbol_id in 0..symbols_count {
+                    assert_eq!(
+                        fixed_point_first_follow_symbols.get_first_symbol_set(symbol_id),
+                        first_follow_symbols.get_first_symbol_set(symbol_id),
+                    );
+                    assert_eq!(
+                        fixed_point_first_follow_symbols.get_follow_symbol_set(symbol_id),
+                        first_follow_symbols.get_follow_symbol_set(symbol_id),
+                    );
+                }
+            }
+        }
+    }
+
+    mod update_added_production {
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        use super::{assert_changed_symbols, assert_same_symbols, build_grammar};
+
+        #[test]
+        fn it_returns_the_same_symbols_as_from() -> () {
+            for seed in 0..20 {
+                let mut grammar = build_grammar(seed, 20);
+                let mut first_follow_symbols = FirstFollowSymbols::from(&grammar);
+
+                let productions = vec![
+                    ContextFreeGrammarProduction::new(1, vec![100]),
+                    ContextFreeGrammarProduction::new(100, vec![0]),
+                    ContextFreeGrammarProduction::new(100, vec![2, 101]),
+                    ContextFreeGrammarProduction::new(3, vec![100, 1]),
+                ];
+
+                for production in productions {
+                    grammar.add_production(production.clone());
+                    first_follow_symbols.update_added_production(&grammar, &production);
+
+                    assert_same_symbols(&FirstFollowSymbols::from(&grammar), &first_follow_symbols);
+                }
+            }
+        }
+
+        #[test]
+        fn it_returns_the_changed_symbols() -> () {
+            for seed in 0..20 {
+                let mut grammar = build_grammar(seed, 20);
+                let mut first_follow_symbols = FirstFollowSymbols::from(&grammar);
+                let other_grammar = build_grammar(seed + 100, 24);
+
+                let productions: Vec<ContextFreeGrammarProduction<u32>> = other_grammar
+                    .get_non_terminal_symbols()
+                    .iter()
+                    .flat_map(|symbol| other_grammar.get_productions(symbol).unwrap().clone())
+                    .take(30)
+                    .collect();
+
+                for production in productions {
+                    if grammar
+                        .get_productions(&production.input)
+                        .is_some_and(|productions| {
+                            productions.iter().any(|grammar_production| {
+                                grammar_production.output == production.output
+                            })
+                        })
+                    {
+                        continue;
+                    }
+
+                    let previous_first_follow_symbols = first_follow_symbols.clone();
+
+                    grammar.add_production(production.clone());
+
+                    let changed_symbol_ids =
+                        first_follow_symbols.update_added_production(&grammar, &production);
+
+                    assert_same_symbols(&FirstFollowSymbols::from(&grammar), &first_follow_symbols);
+                    assert_changed_symbols(
+                        &previous_first_follow_symbols,
+                        &first_follow_symbols,
+                        &changed_symbol_ids,
+                    );
+                }
+            }
+        }
+    }
+
+    mod update_removed_production {
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        use super::{assert_changed_symbols, assert_same_symbols, build_grammar};
+
+        #[test]
+        fn it_returns_the_same_symbols_as_from() -> () {
+            for seed in 0..20 {
+                let mut grammar = build_grammar(seed, 20);
+                let mut first_follow_symbols = FirstFollowSymbols::from(&grammar);
+
+                let productions: Vec<ContextFreeGrammarProduction<u32>> = grammar
+                    .get_non_terminal_symbols()
+                    .iter()
+                    .flat_map(|symbol| grammar.get_productions(symbol).unwrap().clone())
+                    .collect();
+
+                for production in productions {
+                    let previous_first_follow_symbols = first_follow_symbols.clone();
+
+                    assert!(grammar.remove_production(&production));
+
+                    let changed_symbol_ids =
+                        first_follow_symbols.update_removed_production(&grammar, &production);
+
+                    assert_same_symbols(&FirstFollowSymbols::from(&grammar), &first_follow_symbols);
+
+                    assert_changed_symbols(
+                        &previous_first_follow_symbols,
+                        &first_follow_symbols,
+                        &changed_symbol_ids,
+                    );
+
+                    grammar.add_production(production.clone());
+                    first_follow_symbols.update_added_production(&grammar, &production);
+
+                    assert_same_symbols(&FirstFollowSymbols::from(&grammar), &first_follow_symbols);
+                }
+            }
+        }
+    }
+
+    mod first_of_sequence {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<SyntaxTokenTest> {
+            ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::B, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_returns_first_symbols_of_a_nullable_prefix() -> () {
+            let first_follow_symbols = FirstFollowSymbols::from(&build_grammar());
+
+            assert_eq!(
+                Some(vec![
+                    SyntaxTokenTest::Eof,
+                    SyntaxTokenTest::ATerminal,
+                    SyntaxTokenTest::BTerminal,
+                ]),
+                first_follow_symbols.first_of_sequence(&[
+                    SyntaxTokenTest::A,
+                    SyntaxTokenTest::B,
+                    SyntaxTokenTest::Eof,
+                ])
+            );
+        }
+
+        #[test]
+        fn it_returns_epsilon_on_nullable_sequence() -> () {
+            let first_follow_symbols = FirstFollowSymbols::from(&build_grammar());
+
+            assert_eq!(
+                Some(vec![
+                    SyntaxTokenTest::ATerminal,
+                    SyntaxTokenTest::Epsilon,
+                    SyntaxTokenTest::BTerminal,
+                ]),
+                first_follow_symbols.first_of_sequence(&[SyntaxTokenTest::A, SyntaxTokenTest::B])
+            );
+            assert_eq!(
+                Some(vec![SyntaxTokenTest::Epsilon]),
+                first_follow_symbols.first_of_sequence(&[])
+            );
+        }
+    }
+
+    mod get_expected_symbol_set {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<SyntaxTokenTest> {
+            ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::B, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_returns_follow_symbols_of_the_production_input_on_nullable_sequence() -> () {
+            let grammar = build_grammar();
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let symbol_table = grammar.get_symbol_table();
+
+            let expected_symbol_set = first_follow_symbols
+                .get_expected_symbol_set(
+                    &[grammar.get_symbol_id(&SyntaxTokenTest::B).unwrap()],
+                    grammar.get_symbol_id(&SyntaxTokenTest::A).unwrap(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                vec![SyntaxTokenTest::Eof, SyntaxTokenTest::BTerminal],
+                expected_symbol_set
+                    .iter()
+                    .map(|symbol_id| *symbol_table.get_symbol(symbol_id))
+                    .collect::<Vec<SyntaxTokenTest>>()
+            );
+        }
+
+        #[test]
+        fn it_returns_first_symbols_on_non_nullable_sequence() -> () {
+            let grammar = build_grammar();
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let symbol_table = grammar.get_symbol_table();
+
+            let expected_symbol_set = first_follow_symbols
+                .get_expected_symbol_set(
+                    &[
+                        grammar.get_symbol_id(&SyntaxTokenTest::B).unwrap(),
+                        grammar.get_symbol_id(&SyntaxTokenTest::Eof).unwrap(),
+                    ],
+                    grammar.get_symbol_id(&SyntaxTokenTest::S).unwrap(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                vec![SyntaxTokenTest::Eof, SyntaxTokenTest::BTerminal],
+                expected_symbol_set
+                    .iter()
+                    .map(|symbol_id| *symbol_table.get_symbol(symbol_id))
+                    .collect::<Vec<SyntaxTokenTest>>()
+            );
+        }
+    }
+
+    mod is_nullable {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<SyntaxTokenTest> {
+            ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::B, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_returns_whether_a_symbol_derives_epsilon() -> () {
+            let first_follow_symbols = FirstFollowSymbols::from(&build_grammar());
+
+            assert!(first_follow_symbols.is_nullable(&SyntaxTokenTest::A));
+            assert!(first_follow_symbols.is_nullable(&SyntaxTokenTest::Epsilon));
+            assert!(!first_follow_symbols.is_nullable(&SyntaxTokenTest::S));
+            assert!(!first_follow_symbols.is_nullable(&SyntaxTokenTest::ATerminal));
+        }
+    }
+
+    mod nullable_symbols {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<SyntaxTokenTest> {
+            ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::B, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_returns_symbols_deriving_epsilon() -> () {
+            let first_follow_symbols = FirstFollowSymbols::from(&build_grammar());
+
+            assert_eq!(
+                vec![SyntaxTokenTest::A, SyntaxTokenTest::B],
+                first_follow_symbols.nullable_symbols()
+            );
+        }
+    }
 }
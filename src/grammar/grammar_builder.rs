@@ -0,0 +1,145 @@
+use std::hash::Hash;
+
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+use crate::grammar::grammar_builder_error::GrammarBuilderError;
+use crate::grammar::symbol_map::SymbolMap;
+
+/// Builder of context free grammars
+///
+/// Every rule provides the alternatives of a symbol. Rules of the same symbol are merged, keeping
+/// the order of the alternatives:
+///
+/// ```
+/// use artlr_syntax::grammar::grammar_builder::GrammarBuilder;
+///
+/// let grammar = GrammarBuilder::new("ε")
+///     .start("S")
+///     .rule("S", &[&["A", "b"], &["c"]])
+///     .rule("A", &[&["a"], &["ε"]])
+///     .build()
+///     .unwrap();
+///
+/// assert!(grammar.is_non_terminal(&"A"));
+/// ```
+///
+/// The grammar is validated on build, so invalid rules are reported as `GrammarBuilderError`
+/// instead of causing a panic.
+pub struct GrammarBuilder<T> {
+    epsilon_symbol: T,
+    rules: Vec<(T, Vec<Vec<T>>)>,
+    start_symbol: Option<T>,
+}
+
+impl<T: Clone + PartialEq> GrammarBuilder<T> {
+    pub fn new(epsilon_symbol: T) -> Self {
+        Self {
+            epsilon_symbol,
+            rules: vec![],
+            start_symbol: None,
+        }
+    }
+
+    /// Adds a rule with the given alternatives.
+    pub fn rule(mut self, input: T, alternatives: &[&[T]]) -> Self {
+        self.rules.push((
+            input,
+            alternatives
+                .iter()
+                .map(|alternative| alternative.to_vec())
+                .collect(),
+        ));
+
+        self
+    }
+
+    pub fn start(mut self, start_symbol: T) -> Self {
+        self.start_symbol = Some(start_symbol);
+
+        self
+    }
+
+    /// Builds a grammar whose symbols are looked up with `TMap`.
+    pub fn build_with_symbol_map<TMap: SymbolMap<T>>(
+        self,
+    ) -> Result<ContextFreeGrammar<T, TMap>, GrammarBuilderError<T>> {
+        let start_symbol = self.build_check()?;
+
+        let productions: Vec<ContextFreeGrammarProduction<T>> = self
+            .rules
+            .into_iter()
+            .flat_map(|(input, alternatives)| {
+                alternatives.into_iter().map(move |alternative| {
+                    ContextFreeGrammarProduction::new(input.clone(), alternative)
+                })
+            })
+            .collect();
+
+        Ok(ContextFreeGrammar::with_symbol_map(
+            self.epsilon_symbol,
+            start_symbol,
+            productions,
+        ))
+    }
+
+    fn build_check(&self) -> Result<T, GrammarBuilderError<T>> {
+        let start_symbol = self
+            .start_symbol
+            .clone()
+            .ok_or(GrammarBuilderError::MissingStartSymbol)?;
+
+        for (rule_index, (input, alternatives)) in self.rules.iter().enumerate() {
+            if input.eq(&self.epsilon_symbol) {
+                return Err(GrammarBuilderError::EpsilonInput(rule_index));
+            }
+
+            if alternatives.is_empty() {
+                return Err(GrammarBuilderError::RuleWithoutAlternatives(input.clone()));
+            }
+        }
+
+        if !self.rules.iter().any(|(input, _)| input.eq(&start_symbol)) {
+            return Err(GrammarBuilderError::StartSymbolWithoutRules(start_symbol));
+        }
+
+        for (rule_index, (input, alternatives)) in self.rules.iter().enumerate() {
+            let alternative_index_offset =
+                self.build_check_get_alternative_index_offset(input, rule_index);
+
+            for (alternative_index, alternative) in alternatives.iter().enumerate() {
+                let alternative_index = alternative_index_offset + alternative_index;
+
+                if alternative.is_empty() {
+                    return Err(GrammarBuilderError::EmptyAlternative(
+                        input.clone(),
+                        alternative_index,
+                    ));
+                }
+
+                if alternative.len() > 1 && alternative.contains(&self.epsilon_symbol) {
+                    return Err(GrammarBuilderError::EpsilonWithSymbols(
+                        input.clone(),
+                        alternative_index,
+                    ));
+                }
+            }
+        }
+
+        Ok(start_symbol)
+    }
+
+    /// Number of alternatives of a symbol provided by the rules before the given rule.
+    fn build_check_get_alternative_index_offset(&self, input: &T, rule_index: usize) -> usize {
+        self.rules[..rule_index]
+            .iter()
+            .filter(|(rule_input, _)| rule_input.eq(input))
+            .map(|(_, alternatives)| alternatives.len())
+            .sum()
+    }
+}
+
+impl<T: Clone + Eq + Hash> GrammarBuilder<T> {
+    pub fn build(self) -> Result<ContextFreeGrammar<T>, GrammarBuilderError<T>> {
+        self.build_with_symbol_map()
+    }
+}
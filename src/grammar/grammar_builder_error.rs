@@ -0,0 +1,21 @@
+/// Error found when building a grammar with a `GrammarBuilder`
+///
+/// Alternatives are identified by the input symbol of their rule and their index among the
+/// alternatives of that symbol.
+///
+/// - `EmptyAlternative`: the alternative has no symbols. Epsilon alternatives are expected to
+///   hold the epsilon symbol.
+/// - `EpsilonInput`: the rule at the given index has the epsilon symbol as input.
+/// - `EpsilonWithSymbols`: the alternative has the epsilon symbol along other symbols.
+/// - `MissingStartSymbol`: no start symbol was provided.
+/// - `RuleWithoutAlternatives`: the rule of the given symbol has no alternatives.
+/// - `StartSymbolWithoutRules`: the start symbol is not the input of any rule.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GrammarBuilderError<T> {
+    EmptyAlternative(T, usize),
+    EpsilonInput(usize),
+    EpsilonWithSymbols(T, usize),
+    MissingStartSymbol,
+    RuleWithoutAlternatives(T),
+    StartSymbolWithoutRules(T),
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum SyntaxTokenTest {
+        Epsilon,
+        A,
+        S,
+        ATerminal,
+        BTerminal,
+        CTerminal,
+    }
+
+    mod build {
+        use crate::grammar::grammar_builder::GrammarBuilder;
+        use crate::grammar::grammar_builder_error::GrammarBuilderError;
+        use crate::grammar::grammar_builder_spec::test::SyntaxTokenTest;
+
+        #[test]
+        fn it_builds_a_grammar() -> () {
+            let grammar = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .start(SyntaxTokenTest::S)
+                .rule(
+                    SyntaxTokenTest::S,
+                    &[
+                        &[SyntaxTokenTest::A, SyntaxTokenTest::BTerminal],
+                        &[SyntaxTokenTest::CTerminal],
+                    ],
+                )
+                .rule(
+                    SyntaxTokenTest::A,
+                    &[&[SyntaxTokenTest::ATerminal], &[SyntaxTokenTest::Epsilon]],
+                )
+                .build()
+                .unwrap();
+
+            assert_eq!(&SyntaxTokenTest::S, grammar.get_initial_symbol());
+
+            let s_productions = grammar.get_productions(&SyntaxTokenTest::S).unwrap();
+
+            assert_eq!(2, s_productions.len());
+            assert_eq!(SyntaxTokenTest::S, s_productions[0].input);
+            assert_eq!(
+                vec![SyntaxTokenTest::A, SyntaxTokenTest::BTerminal],
+                s_productions[0].output
+            );
+            assert_eq!(
+                2,
+                grammar.get_productions(&SyntaxTokenTest::A).unwrap().len()
+            );
+        }
+
+        #[test]
+        fn it_returns_an_error_on_missing_start_symbol() -> () {
+            let result = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .rule(SyntaxTokenTest::S, &[&[SyntaxTokenTest::ATerminal]])
+                .build();
+
+            assert_eq!(Some(GrammarBuilderError::MissingStartSymbol), result.err());
+        }
+
+        #[test]
+        fn it_returns_an_error_on_epsilon_input() -> () {
+            let result = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .start(SyntaxTokenTest::S)
+                .rule(SyntaxTokenTest::S, &[&[SyntaxTokenTest::ATerminal]])
+                .rule(SyntaxTokenTest::Epsilon, &[&[SyntaxTokenTest::ATerminal]])
+                .build();
+
+            assert_eq!(Some(GrammarBuilderError::EpsilonInput(1)), result.err());
+        }
+
+        #[test]
+        fn it_returns_an_error_on_start_symbol_without_rules() -> () {
+            let result = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .start(SyntaxTokenTest::S)
+                .rule(SyntaxTokenTest::A, &[&[SyntaxTokenTest::ATerminal]])
+                .build();
+
+            assert_eq!(
+                Some(GrammarBuilderError::StartSymbolWithoutRules(
+                    SyntaxTokenTest::S
+                )),
+                result.err()
+            );
+        }
+
+        #[test]
+        fn it_returns_an_error_with_the_alternative_of_the_symbol() -> () {
+            let result = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .start(SyntaxTokenTest::S)
+                .rule(SyntaxTokenTest::S, &[&[SyntaxTokenTest::A]])
+                .rule(SyntaxTokenTest::A, &[&[SyntaxTokenTest::ATerminal]])
+                .rule(
+                    SyntaxTokenTest::A,
+                    &[
+                        &[SyntaxTokenTest::BTerminal],
+                        &[SyntaxTokenTest::CTerminal, SyntaxTokenTest::Epsilon],
+                    ],
+                )
+                .build();
+
+            assert_eq!(
+                Some(GrammarBuilderError::EpsilonWithSymbols(
+                    SyntaxTokenTest::A,
+                    2
+                )),
+                result.err()
+            );
+        }
+
+        #[test]
+        fn it_returns_an_error_on_empty_alternative() -> () {
+            let result = GrammarBuilder::new(SyntaxTokenTest::Epsilon)
+                .start(SyntaxTokenTest::S)
+                .rule(SyntaxTokenTest::S, &[&[SyntaxTokenTest::ATerminal], &[]])
+                .build();
+
+            assert_eq!(
+                Some(GrammarBuilderError::EmptyAlternative(SyntaxTokenTest::S, 1)),
+                result.err()
+            );
+        }
+    }
+
+    mod grammar_macro {
+        use crate::grammar::grammar_builder::GrammarBuilder;
+        use crate::grammar::grammar_builder_error::GrammarBuilderError;
+        use crate::grammar::grammar_builder_spec::test::SyntaxTokenTest;
+        use crate::grammar::grammar_builder_spec::test::SyntaxTokenTest::*;
+
+        #[test]
+        fn it_builds_the_same_grammar_as_the_builder() -> () {
+            let grammar = crate::grammar! {
+                eps = Epsilon;
+                S -> A BTerminal | CTerminal;
+                A -> ATerminal | eps;
+            }
+            .unwrap();
+
+            let expected_grammar = GrammarBuilder::new(Epsilon)
+                .start(S)
+                .rule(S, &[&[A, BTerminal], &[CTerminal]])
+                .rule(A, &[&[ATerminal], &[Epsilon]])
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                grammar.get_initial_symbol(),
+                expected_grammar.get_initial_symbol()
+            );
+
+            for symbol in [S, A] {
+                let productions = grammar.get_productions(&symbol).unwrap();
+                let expected_productions = expected_grammar.get_productions(&symbol).unwrap();
+
+                assert_eq!(expected_productions.len(), productions.len());
+
+                for (production, expected_production) in
+                    productions.iter().zip(expected_productions)
+                {
+                    assert_eq!(expected_production.input, production.input);
+                    assert_eq!(expected_production.output, production.output);
+                }
+            }
+        }
+
+        #[test]
+        fn it_accepts_parenthesized_symbols() -> () {
+            let grammar = crate::grammar! {
+                eps = SyntaxTokenTest::Epsilon;
+                (SyntaxTokenTest::S) -> (SyntaxTokenTest::ATerminal) | eps;
+            }
+            .unwrap();
+
+            assert_eq!(2, grammar.get_productions(&S).unwrap().len());
+        }
+
+        #[test]
+        fn it_returns_builder_errors() -> () {
+            let result = crate::grammar! {
+                eps = Epsilon;
+                S -> A;
+                A -> ATerminal | ;
+            };
+
+            assert_eq!(
+                Some(GrammarBuilderError::EmptyAlternative(A, 1)),
+                result.err()
+            );
+        }
+    }
+}
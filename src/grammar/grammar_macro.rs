@@ -0,0 +1,98 @@
+/// Builds a grammar from rules
+///
+/// The first line declares the epsilon symbol, every other line is a rule. The input symbol of the
+/// first rule is the start symbol. `eps` stands for the epsilon symbol in alternatives:
+///
+/// ```
+/// use artlr_syntax::grammar;
+///
+/// #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// enum Symbol {
+///     Epsilon,
+///     A,
+///     S,
+///     ATerminal,
+///     BTerminal,
+///     CTerminal,
+/// }
+///
+/// use Symbol::*;
+///
+/// let grammar = grammar! {
+///     eps = Epsilon;
+///     S -> A BTerminal | CTerminal;
+///     A -> ATerminal | eps;
+/// }
+/// .unwrap();
+///
+/// assert!(grammar.is_non_terminal(&A));
+/// ```
+///
+/// Every symbol is a single token tree, so symbols which are not identifiers are wrapped in
+/// parentheses: `(Symbol::A)`. The macro expands to a `GrammarBuilder` and returns the result of
+/// `GrammarBuilder.build`. Symbols are processed one at a time, so big grammars may require a
+/// higher `recursion_limit`.
+///
+/// `eps` is a marker of the macro, so a symbol named `eps` has to be wrapped in parentheses:
+/// `(eps)`.
+#[macro_export]
+macro_rules! grammar {
+    (eps = $epsilon:expr; $start:tt -> $($rules:tt)*) => {
+        $crate::grammar!(
+            @rules
+            $crate::grammar::grammar_builder::GrammarBuilder::new($epsilon).start($crate::grammar!(@symbol $epsilon; $start));
+            $epsilon;
+            $start -> $($rules)*
+        )
+    };
+    (@rules $builder:expr; $epsilon:expr;) => {
+        $builder.build()
+    };
+    (@rules $builder:expr; $epsilon:expr; $input:tt -> $($rules:tt)*) => {
+        $crate::grammar!(@alternatives $builder; $epsilon; $input; []; []; $($rules)*)
+    };
+    (
+        @alternatives $builder:expr; $epsilon:expr; $input:tt; [$($alternatives:tt)*];
+        [$($symbols:tt)*]; | $($rules:tt)*
+    ) => {
+        $crate::grammar!(
+            @alternatives $builder; $epsilon; $input; [$($alternatives)* [$($symbols)*]]; [];
+            $($rules)*
+        )
+    };
+    (
+        @alternatives $builder:expr; $epsilon:expr; $input:tt;
+        [$([$($alternative:tt)*])*]; [$($symbols:tt)*]; ; $($rules:tt)*
+    ) => {
+        $crate::grammar!(
+            @rules
+            $builder.rule(
+                $crate::grammar!(@symbol $epsilon; $input),
+                &[
+                    $(&[$($crate::grammar!(@symbol $epsilon; $alternative)),*],)*
+                    &[$($crate::grammar!(@symbol $epsilon; $symbols)),*],
+                ],
+            );
+            $epsilon;
+            $($rules)*
+        )
+    };
+    (
+        @alternatives $builder:expr; $epsilon:expr; $input:tt; [$($alternatives:tt)*];
+        [$($symbols:tt)*]; $symbol:tt $($rules:tt)*
+    ) => {
+        $crate::grammar!(
+            @alternatives $builder; $epsilon; $input; [$($alternatives)*];
+            [$($symbols)* $symbol]; $($rules)*
+        )
+    };
+    (@symbol $epsilon:expr; eps) => {
+        $epsilon
+    };
+    (@symbol $epsilon:expr; ($symbol:expr)) => {
+        $symbol
+    };
+    (@symbol $epsilon:expr; $symbol:tt) => {
+        $symbol
+    };
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::grammar::symbol_map::SymbolMap;
+
+/// `HashMap` backed symbol map
+pub struct HashSymbolMap<T> {
+    symbol_ids: HashMap<T, u32>,
+}
+
+impl<T: Clone + Eq + Hash> SymbolMap<T> for HashSymbolMap<T> {
+    fn get(&self, symbol: &T) -> Option<u32> {
+        self.symbol_ids.get(symbol).copied()
+    }
+
+    fn insert(&mut self, symbol: T, symbol_id: u32) {
+        self.symbol_ids.insert(symbol, symbol_id);
+    }
+}
+
+impl<T: Clone> Clone for HashSymbolMap<T> {
+    fn clone(&self) -> Self {
+        Self {
+            symbol_ids: self.symbol_ids.clone(),
+        }
+    }
+}
+
+impl<T> Default for HashSymbolMap<T> {
+    fn default() -> Self {
+        Self {
+            symbol_ids: HashMap::new(),
+        }
+    }
+}
@@ -1,5 +1,16 @@
+pub mod btree_symbol_map;
 pub mod context_free_grammar;
 pub mod context_free_grammar_production;
 mod context_free_grammar_spec;
 pub mod first_follow_symbols;
 mod first_follow_symbols_spec;
+pub mod grammar_builder;
+pub mod grammar_builder_error;
+mod grammar_builder_spec;
+mod grammar_macro;
+pub mod hash_symbol_map;
+pub mod symbol_map;
+pub mod symbol_set;
+mod symbol_set_spec;
+pub mod symbol_set_view;
+pub mod symbol_table;
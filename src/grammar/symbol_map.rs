@@ -0,0 +1,9 @@
+/// Map from symbols to symbol IDs
+///
+/// `SymbolTable` looks symbols up through this trait, so grammars are not tied to a specific map.
+/// `HashSymbolMap` requires `T: Eq + Hash` and `BTreeSymbolMap` requires `T: Ord`.
+pub trait SymbolMap<T>: Clone + Default {
+    fn get(&self, symbol: &T) -> Option<u32>;
+
+    fn insert(&mut self, symbol: T, symbol_id: u32) -> ();
+}
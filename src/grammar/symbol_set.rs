@@ -0,0 +1,136 @@
+use std::hash::{Hash, Hasher};
+
+const WORD_BITS: u32 = u64::BITS;
+
+/// Set of interned symbols
+///
+/// Bitset indexed by the IDs of a `SymbolTable`.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolSet {
+    words: Vec<u64>,
+}
+
+impl SymbolSet {
+    pub fn new() -> Self {
+        Self { words: vec![] }
+    }
+
+    pub fn with_capacity(symbols_count: usize) -> Self {
+        Self {
+            words: vec![0; symbols_count.div_ceil(WORD_BITS as usize)],
+        }
+    }
+
+    pub fn contains(&self, symbol_id: u32) -> bool {
+        let (word_index, mask) = Self::locate(symbol_id);
+
+        self.words
+            .get(word_index)
+            .is_some_and(|word| word & mask != 0)
+    }
+
+    /// Inserts a symbol, returning whether the symbol was not in the set.
+    pub fn insert(&mut self, symbol_id: u32) -> bool {
+        let (word_index, mask) = Self::locate(symbol_id);
+
+        if word_index >= self.words.len() {
+            self.words.resize(word_index + 1, 0);
+        }
+
+        let is_inserted = self.words[word_index] & mask == 0;
+
+        self.words[word_index] |= mask;
+
+        is_inserted
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, word)| {
+                let word = *word;
+
+                (0..WORD_BITS)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| word_index as u32 * WORD_BITS + bit)
+            })
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Removes a symbol, returning whether the symbol was in the set.
+    pub fn remove(&mut self, symbol_id: u32) -> bool {
+        let (word_index, mask) = Self::locate(symbol_id);
+
+        match self.words.get_mut(word_index) {
+            Some(word) => {
+                let is_removed = *word & mask != 0;
+
+                *word &= !mask;
+
+                is_removed
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts every symbol of other set, returning whether any symbol was not in the set.
+    pub fn union_with(&mut self, other: &SymbolSet) -> bool {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        let mut is_updated = false;
+
+        for (word, other_word) in self.words.iter_mut().zip(other.words.iter()) {
+            let updated_word = *word | other_word;
+
+            is_updated |= updated_word != *word;
+            *word = updated_word;
+        }
+
+        is_updated
+    }
+
+    /// Words up to the last one with any symbol, so sets are compared regardless of capacity.
+    fn get_significant_words(&self) -> &[u64] {
+        let significant_words_count = self
+            .words
+            .iter()
+            .rposition(|word| *word != 0)
+            .map_or(0, |word_index| word_index + 1);
+
+        &self.words[..significant_words_count]
+    }
+
+    fn locate(symbol_id: u32) -> (usize, u64) {
+        (
+            (symbol_id / WORD_BITS) as usize,
+            1 << (symbol_id % WORD_BITS),
+        )
+    }
+}
+
+impl Eq for SymbolSet {}
+
+impl Hash for SymbolSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_significant_words().hash(state);
+    }
+}
+
+impl PartialEq for SymbolSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_significant_words() == other.get_significant_words()
+    }
+}
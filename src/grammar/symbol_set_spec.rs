@@ -0,0 +1,72 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+
+    mod eq {
+        use crate::grammar::symbol_set::SymbolSet;
+
+        #[test]
+        fn it_compares_sets_regardless_of_capacity() -> () {
+            let mut symbol_set = SymbolSet::with_capacity(256);
+            let mut other_symbol_set = SymbolSet::new();
+
+            symbol_set.insert(2);
+            other_symbol_set.insert(2);
+
+            assert_eq!(symbol_set, other_symbol_set);
+        }
+    }
+
+    mod insert {
+        use crate::grammar::symbol_set::SymbolSet;
+
+        #[test]
+        fn it_inserts_symbols() -> () {
+            let mut symbol_set = SymbolSet::new();
+
+            assert!(symbol_set.insert(3));
+            assert!(symbol_set.insert(130));
+            assert!(!symbol_set.insert(3));
+
+            assert!(symbol_set.contains(3));
+            assert!(symbol_set.contains(130));
+            assert!(!symbol_set.contains(64));
+            assert_eq!(2, symbol_set.len());
+            assert_eq!(vec![3, 130], symbol_set.iter().collect::<Vec<u32>>());
+        }
+    }
+
+    mod remove {
+        use crate::grammar::symbol_set::SymbolSet;
+
+        #[test]
+        fn it_removes_symbols() -> () {
+            let mut symbol_set = SymbolSet::new();
+
+            symbol_set.insert(1);
+
+            assert!(symbol_set.remove(1));
+            assert!(!symbol_set.remove(1));
+            assert!(!symbol_set.remove(200));
+            assert!(symbol_set.is_empty());
+        }
+    }
+
+    mod union_with {
+        use crate::grammar::symbol_set::SymbolSet;
+
+        #[test]
+        fn it_merges_sets() -> () {
+            let mut symbol_set = SymbolSet::new();
+            let mut other_symbol_set = SymbolSet::new();
+
+            symbol_set.insert(0);
+            other_symbol_set.insert(0);
+            other_symbol_set.insert(70);
+
+            assert!(symbol_set.union_with(&other_symbol_set));
+            assert!(!symbol_set.union_with(&other_symbol_set));
+            assert_eq!(vec![0, 70], symbol_set.iter().collect::<Vec<u32>>());
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::grammar::hash_symbol_map::HashSymbolMap;
+use crate::grammar::symbol_map::SymbolMap;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::grammar::symbol_table::SymbolTable;
+
+/// Read only view of a set of interned symbols
+pub struct SymbolSetView<'a, T, TMap = HashSymbolMap<T>> {
+    symbol_set: &'a SymbolSet,
+    symbol_table: &'a SymbolTable<T, TMap>,
+}
+
+impl<'a, T, TMap> SymbolSetView<'a, T, TMap> {
+    pub fn new(symbol_set: &'a SymbolSet, symbol_table: &'a SymbolTable<T, TMap>) -> Self {
+        Self {
+            symbol_set,
+            symbol_table,
+        }
+    }
+
+    pub fn get_symbol_set(&self) -> &'a SymbolSet {
+        self.symbol_set
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbol_set.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let symbol_table = self.symbol_table;
+
+        self.symbol_set
+            .iter()
+            .map(move |symbol_id| symbol_table.get_symbol(symbol_id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbol_set.len()
+    }
+}
+
+impl<'a, T, TMap: SymbolMap<T>> SymbolSetView<'a, T, TMap> {
+    pub fn contains(&self, symbol: &T) -> bool {
+        self.symbol_table
+            .get_id(symbol)
+            .is_some_and(|symbol_id| self.symbol_set.contains(symbol_id))
+    }
+}
+
+impl<'a, T: Clone + Eq + Hash, TMap> SymbolSetView<'a, T, TMap> {
+    pub fn to_hash_set(&self) -> HashSet<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T, TMap> Clone for SymbolSetView<'a, T, TMap> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, TMap> Copy for SymbolSetView<'a, T, TMap> {}
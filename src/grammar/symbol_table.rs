@@ -0,0 +1,83 @@
+use std::hash::Hash;
+
+use crate::grammar::hash_symbol_map::HashSymbolMap;
+use crate::grammar::symbol_map::SymbolMap;
+
+/// Table of interned symbols
+///
+/// Symbols are identified by dense `u32` IDs assigned in order of interning. Symbols are looked
+/// up through a `SymbolMap`, a `HashSymbolMap` by default.
+pub struct SymbolTable<T, TMap = HashSymbolMap<T>> {
+    symbol_ids: TMap,
+    symbols: Vec<T>,
+}
+
+impl<T, TMap> SymbolTable<T, TMap> {
+    pub fn get_symbol(&self, symbol_id: u32) -> &T {
+        &self.symbols[symbol_id as usize]
+    }
+
+    pub fn get_symbols(&self) -> &Vec<T> {
+        &self.symbols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+}
+
+impl<T, TMap: SymbolMap<T>> SymbolTable<T, TMap> {
+    pub fn get_id(&self, symbol: &T) -> Option<u32> {
+        self.symbol_ids.get(symbol)
+    }
+}
+
+impl<T: Clone + Eq + Hash> SymbolTable<T> {
+    pub fn new() -> Self {
+        Self::with_symbol_map()
+    }
+}
+
+impl<T: Clone, TMap: SymbolMap<T>> SymbolTable<T, TMap> {
+    /// Builds an empty table whose symbols are looked up with `TMap`.
+    pub fn with_symbol_map() -> Self {
+        Self {
+            symbol_ids: TMap::default(),
+            symbols: vec![],
+        }
+    }
+
+    /// Interns a symbol, returning its ID.
+    pub fn intern(&mut self, symbol: &T) -> u32 {
+        match self.symbol_ids.get(symbol) {
+            Some(symbol_id) => symbol_id,
+            None => {
+                let symbol_id = self.symbols.len() as u32;
+
+                self.symbol_ids.insert(symbol.clone(), symbol_id);
+                self.symbols.push(symbol.clone());
+
+                symbol_id
+            }
+        }
+    }
+}
+
+impl<T: Clone, TMap: Clone> Clone for SymbolTable<T, TMap> {
+    fn clone(&self) -> Self {
+        Self {
+            symbol_ids: self.symbol_ids.clone(),
+            symbols: self.symbols.clone(),
+        }
+    }
+}
+
+impl<T: Clone, TMap: SymbolMap<T>> Default for SymbolTable<T, TMap> {
+    fn default() -> Self {
+        Self::with_symbol_map()
+    }
+}
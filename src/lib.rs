@@ -1,4 +1,6 @@
 pub mod ast;
+pub mod codegen;
+pub mod diagnostics;
 pub mod grammar;
 pub mod parser;
 pub mod token;
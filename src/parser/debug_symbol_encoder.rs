@@ -0,0 +1,15 @@
+use std::fmt::Debug;
+
+use crate::parser::symbol_encoder::SymbolEncoder;
+
+/// Symbol encoder based on the `Debug` representation of the symbols
+///
+/// Intended for enums whose `Debug` representation has no whitespaces, like enums of unit
+/// variants.
+pub struct DebugSymbolEncoder;
+
+impl<T: Debug> SymbolEncoder<T> for DebugSymbolEncoder {
+    fn encode(&self, symbol: &T) -> String {
+        format!("{:?}", symbol)
+    }
+}
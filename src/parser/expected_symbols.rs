@@ -0,0 +1,21 @@
+/// Symbols expected after a token prefix
+///
+/// `terminal_symbols` are the terminal symbols which could be the next token of the input.
+/// `non_terminal_symbols` are the non terminal symbols being derived at that point or whose
+/// derivation could start at that point.
+///
+/// Symbols are sorted in order of appearance in the grammar, so the same prefix always leads to
+/// the same result.
+pub struct ExpectedSymbols<TSyntax> {
+    pub non_terminal_symbols: Vec<TSyntax>,
+    pub terminal_symbols: Vec<TSyntax>,
+}
+
+impl<TSyntax> ExpectedSymbols<TSyntax> {
+    pub fn new(non_terminal_symbols: Vec<TSyntax>, terminal_symbols: Vec<TSyntax>) -> Self {
+        Self {
+            non_terminal_symbols,
+            terminal_symbols,
+        }
+    }
+}
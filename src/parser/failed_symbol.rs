@@ -1,18 +1,24 @@
 use crate::parser::failed_production::FailedProduction;
 
+/// Symbol which could not be derived
+///
+/// `token_position` is the position of the token the derivation of the symbol started at.
 pub struct FailedSymbol<TLex, TSyntax> {
     pub failed_productions: Vec<FailedProduction<TLex, TSyntax>>,
     pub symbol_to_derive: TSyntax,
+    pub token_position: usize,
 }
 
 impl<TLex, TSyntax> FailedSymbol<TLex, TSyntax> {
     pub fn new(
         failed_productions: Vec<FailedProduction<TLex, TSyntax>>,
         symbol_to_derive: TSyntax,
+        token_position: usize,
     ) -> Self {
         Self {
             failed_productions,
             symbol_to_derive,
+            token_position,
         }
     }
 }
@@ -1,4 +1,30 @@
+use crate::token::token::Token;
+
+/// Part of a fixed production which could not be derived
+///
+/// - `skipped_tokens`: input tokens discarded by the fix.
+/// - `skipped_symbols`: production symbols which were not derived.
 pub struct FixGap<TLex, TSyntax> {
-    pub skipped_tokens: Vec<TLex>,
+    pub skipped_tokens: Vec<Token<TLex, TSyntax>>,
     pub skipped_symbols: Vec<TSyntax>,
 }
+
+impl<TLex, TSyntax> FixGap<TLex, TSyntax> {
+    pub fn new(skipped_tokens: Vec<Token<TLex, TSyntax>>, skipped_symbols: Vec<TSyntax>) -> Self {
+        Self {
+            skipped_tokens,
+            skipped_symbols,
+        }
+    }
+
+    /// Number of skipped tokens and symbols.
+    pub fn get_cost(&self) -> usize {
+        self.skipped_tokens.len() + self.skipped_symbols.len()
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone> Clone for FixGap<TLex, TSyntax> {
+    fn clone(&self) -> Self {
+        Self::new(self.skipped_tokens.clone(), self.skipped_symbols.clone())
+    }
+}
@@ -4,3 +4,12 @@ pub struct FixResult<TLex, TSyntax> {
     pub final_token_position: usize,
     pub production: FixedProduction<TLex, TSyntax>,
 }
+
+impl<TLex, TSyntax> FixResult<TLex, TSyntax> {
+    pub fn new(final_token_position: usize, production: FixedProduction<TLex, TSyntax>) -> Self {
+        Self {
+            final_token_position,
+            production,
+        }
+    }
+}
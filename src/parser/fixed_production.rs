@@ -8,4 +8,25 @@ impl<TLex, TSyntax> FixedProduction<TLex, TSyntax> {
     pub fn new(fixed_parts: Vec<FixedProductionPart<TLex, TSyntax>>) -> Self {
         Self { fixed_parts }
     }
+
+    /// Number of tokens and symbols skipped by the gaps of the production, including the gaps of
+    /// its fixed symbols.
+    pub fn get_cost(&self) -> usize {
+        self.fixed_parts
+            .iter()
+            .map(|fixed_part| match fixed_part {
+                FixedProductionPart::Ok(_) => 0,
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    fixed_symbol.fixed_production.get_cost()
+                }
+                FixedProductionPart::Gap(fix_gap) => fix_gap.get_cost(),
+            })
+            .sum()
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone> Clone for FixedProduction<TLex, TSyntax> {
+    fn clone(&self) -> Self {
+        Self::new(self.fixed_parts.clone())
+    }
 }
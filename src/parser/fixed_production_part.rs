@@ -8,3 +8,15 @@ pub enum FixedProductionPart<TLex, TSyntax> {
     Fixed(FixedSymbol<TLex, TSyntax>),
     Gap(FixGap<TLex, TSyntax>),
 }
+
+impl<TLex: Clone, TSyntax: Clone> Clone for FixedProductionPart<TLex, TSyntax> {
+    fn clone(&self) -> Self {
+        match self {
+            FixedProductionPart::Ok(node) => FixedProductionPart::Ok(node.clone()),
+            FixedProductionPart::Fixed(fixed_symbol) => {
+                FixedProductionPart::Fixed(fixed_symbol.clone())
+            }
+            FixedProductionPart::Gap(fix_gap) => FixedProductionPart::Gap(fix_gap.clone()),
+        }
+    }
+}
@@ -1,4 +1,12 @@
+use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+use crate::ast::abstract_syntax_node_kind::AbstractSyntaxNodeKind;
+use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::symbol_map::SymbolMap;
 use crate::parser::fixed_production::FixedProduction;
+use crate::parser::fixed_production_part::FixedProductionPart;
+use crate::token::token::Token;
+use crate::token::token_edit::TokenEdit;
 
 pub struct FixedSymbol<TLex, TSyntax> {
     pub fixed_production: FixedProduction<TLex, TSyntax>,
@@ -16,3 +24,200 @@ impl<TLex, TSyntax> FixedSymbol<TLex, TSyntax> {
         }
     }
 }
+
+impl<TLex: Clone, TSyntax: Clone> FixedSymbol<TLex, TSyntax> {
+    /// Builds the tree of the fixed symbol.
+    ///
+    /// Fixed symbols are `Error` nodes. Gaps are replaced by a `Skipped` leaf for each skipped
+    /// token followed by a `Missing` leaf for each skipped symbol.
+    pub fn to_abstract_syntax_tree(&self) -> AbstractSyntaxTree<Token<TLex, TSyntax>> {
+        AbstractSyntaxTree::new(self.to_abstract_syntax_node())
+    }
+
+    fn to_abstract_syntax_node(&self) -> AbstractSyntaxNode<Token<TLex, TSyntax>> {
+        let mut child_nodes = vec![];
+
+        for fixed_part in self.fixed_production.fixed_parts.iter() {
+            match fixed_part {
+                FixedProductionPart::Ok(node) => child_nodes.push(node.clone()),
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    child_nodes.push(fixed_symbol.to_abstract_syntax_node())
+                }
+                FixedProductionPart::Gap(fix_gap) => {
+                    child_nodes.extend(fix_gap.skipped_tokens.iter().map(|token| {
+                        AbstractSyntaxNode::with_kind(
+                            vec![],
+                            AbstractSyntaxNodeKind::Skipped,
+                            token.clone(),
+                        )
+                    }));
+                    child_nodes.extend(fix_gap.skipped_symbols.iter().map(|symbol| {
+                        AbstractSyntaxNode::with_kind(
+                            vec![],
+                            AbstractSyntaxNodeKind::Missing,
+                            Token::new(None, symbol.clone()),
+                        )
+                    }));
+                }
+            }
+        }
+
+        AbstractSyntaxNode::with_kind(
+            child_nodes,
+            AbstractSyntaxNodeKind::Error,
+            Token::new(None, self.symbol_to_derive.clone()),
+        )
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone> FixedSymbol<TLex, TSyntax> {
+    /// Edits turning the input into the fixed tokens, for a fixed symbol derived from the first
+    /// token.
+    ///
+    /// Skipped tokens are deleted. Skipped symbols are replaced by the tokens of their shortest
+    /// terminal derivation, inserted without lexeme. A fixed symbol whose parts are gaps skipping
+    /// no symbol is replaced by its shortest terminal derivation as well.
+    pub fn get_token_edits<TMap: SymbolMap<TSyntax>>(
+        &self,
+        grammar: &ContextFreeGrammar<TSyntax, TMap>,
+    ) -> Vec<TokenEdit<TLex, TSyntax>> {
+        self.to_fixed_tokens_and_edits(grammar).1
+    }
+
+    /// Tokens of the input once the fix is applied, the edits of `get_token_edits` being applied.
+    pub fn to_fixed_tokens<TMap: SymbolMap<TSyntax>>(
+        &self,
+        grammar: &ContextFreeGrammar<TSyntax, TMap>,
+    ) -> Vec<Token<TLex, TSyntax>> {
+        self.to_fixed_tokens_and_edits(grammar).0
+    }
+
+    /// Tokens of `to_fixed_tokens` and edits of `get_token_edits`, built at once.
+    #[allow(clippy::type_complexity)]
+    pub fn to_fixed_tokens_and_edits<TMap: SymbolMap<TSyntax>>(
+        &self,
+        grammar: &ContextFreeGrammar<TSyntax, TMap>,
+    ) -> (Vec<Token<TLex, TSyntax>>, Vec<TokenEdit<TLex, TSyntax>>) {
+        let shortest_derivation_ids = grammar.get_shortest_derivation_ids();
+        let mut fixed_tokens = vec![];
+        let mut token_edits = vec![];
+
+        self.push_fixed_tokens(
+            grammar,
+            &shortest_derivation_ids,
+            &mut 0,
+            &mut fixed_tokens,
+            &mut token_edits,
+        );
+
+        (fixed_tokens, token_edits)
+    }
+
+    fn push_fixed_tokens<TMap: SymbolMap<TSyntax>>(
+        &self,
+        grammar: &ContextFreeGrammar<TSyntax, TMap>,
+        shortest_derivation_ids: &[Option<Vec<u32>>],
+        token_position: &mut usize,
+        fixed_tokens: &mut Vec<Token<TLex, TSyntax>>,
+        token_edits: &mut Vec<TokenEdit<TLex, TSyntax>>,
+    ) {
+        for fixed_part in self.fixed_production.fixed_parts.iter() {
+            match fixed_part {
+                FixedProductionPart::Ok(node) => {
+                    let mut pending_nodes = vec![node];
+
+                    while let Some(node) = pending_nodes.pop() {
+                        if !node.child_nodes.is_empty() {
+                            pending_nodes.extend(node.child_nodes.iter().rev());
+                        } else if grammar.get_symbol_id(&node.token.t_type)
+                            != Some(grammar.get_epsilon_symbol_id())
+                        {
+                            fixed_tokens.push(node.token.clone());
+                            *token_position += 1;
+                        }
+                    }
+                }
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    fixed_symbol.push_fixed_tokens(
+                        grammar,
+                        shortest_derivation_ids,
+                        token_position,
+                        fixed_tokens,
+                        token_edits,
+                    );
+                }
+                FixedProductionPart::Gap(fix_gap) => {
+                    for token in fix_gap.skipped_tokens.iter() {
+                        token_edits.push(TokenEdit::Delete(*token_position, token.clone()));
+                        *token_position += 1;
+                    }
+
+                    for symbol in fix_gap.skipped_symbols.iter() {
+                        Self::push_derivation_tokens(
+                            grammar,
+                            shortest_derivation_ids,
+                            symbol,
+                            *token_position,
+                            fixed_tokens,
+                            token_edits,
+                        );
+                    }
+                }
+            }
+        }
+
+        let derives_no_symbol =
+            self.fixed_production
+                .fixed_parts
+                .iter()
+                .all(|fixed_part| match fixed_part {
+                    FixedProductionPart::Gap(fix_gap) => fix_gap.skipped_symbols.is_empty(),
+                    _ => false,
+                });
+
+        if derives_no_symbol {
+            Self::push_derivation_tokens(
+                grammar,
+                shortest_derivation_ids,
+                &self.symbol_to_derive,
+                *token_position,
+                fixed_tokens,
+                token_edits,
+            );
+        }
+    }
+
+    /// Inserts the tokens of the shortest terminal derivation of a symbol.
+    fn push_derivation_tokens<TMap: SymbolMap<TSyntax>>(
+        grammar: &ContextFreeGrammar<TSyntax, TMap>,
+        shortest_derivation_ids: &[Option<Vec<u32>>],
+        symbol: &TSyntax,
+        token_position: usize,
+        fixed_tokens: &mut Vec<Token<TLex, TSyntax>>,
+        token_edits: &mut Vec<TokenEdit<TLex, TSyntax>>,
+    ) {
+        let derivation_ids = grammar
+            .get_symbol_id(symbol)
+            .and_then(|symbol_id| shortest_derivation_ids[symbol_id as usize].as_ref())
+            .map_or(&[][..], Vec::as_slice);
+
+        for derivation_id in derivation_ids {
+            let token = Token::new(
+                None,
+                grammar
+                    .get_symbol_table()
+                    .get_symbol(*derivation_id)
+                    .clone(),
+            );
+
+            fixed_tokens.push(token.clone());
+            token_edits.push(TokenEdit::Insert(token_position, token));
+        }
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone> Clone for FixedSymbol<TLex, TSyntax> {
+    fn clone(&self) -> Self {
+        Self::new(self.fixed_production.clone(), self.symbol_to_derive.clone())
+    }
+}
@@ -0,0 +1,349 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::parser::fix_gap::FixGap;
+    use crate::parser::fixed_production::FixedProduction;
+    use crate::parser::fixed_production_part::FixedProductionPart;
+    use crate::parser::fixed_symbol::FixedSymbol;
+    use crate::token::token::Token;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum DummySyntaxTokenTest {
+        A,
+        ATerminal,
+        B,
+        BTerminal,
+        CTerminal,
+        Epsilon,
+        S,
+    }
+
+    fn build_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                DummySyntaxTokenTest::S,
+                vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::CTerminal],
+            ),
+            ContextFreeGrammarProduction::new(
+                DummySyntaxTokenTest::A,
+                vec![
+                    DummySyntaxTokenTest::ATerminal,
+                    DummySyntaxTokenTest::BTerminal,
+                ],
+            ),
+        ];
+
+        ContextFreeGrammar::new(
+            DummySyntaxTokenTest::Epsilon,
+            DummySyntaxTokenTest::S,
+            grammar_productions,
+        )
+    }
+
+    /// Fix of the input `ATerminal ATerminal CTerminal`, replacing the second token by
+    /// `BTerminal`.
+    fn build_fixed_symbol() -> FixedSymbol<u64, DummySyntaxTokenTest> {
+        FixedSymbol::new(
+            FixedProduction::new(vec![
+                FixedProductionPart::Fixed(FixedSymbol::new(
+                    FixedProduction::new(vec![
+                        FixedProductionPart::Ok(AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        )),
+                        FixedProductionPart::Gap(FixGap::new(
+                            vec![Token::new(Some(1u64), DummySyntaxTokenTest::ATerminal)],
+                            vec![DummySyntaxTokenTest::BTerminal],
+                        )),
+                    ]),
+                    DummySyntaxTokenTest::A,
+                )),
+                FixedProductionPart::Ok(AbstractSyntaxNode::new(
+                    vec![],
+                    Token::new(Some(2u64), DummySyntaxTokenTest::CTerminal),
+                )),
+            ]),
+            DummySyntaxTokenTest::S,
+        )
+    }
+
+    mod get_token_edits {
+        use crate::parser::fixed_symbol_spec::test::{
+            build_fixed_symbol, build_grammar, DummySyntaxTokenTest,
+        };
+        use crate::token::token_edit::TokenEdit;
+
+        #[test]
+        fn it_deletes_skipped_tokens_and_inserts_skipped_terminal_symbols() -> () {
+            let token_edits = build_fixed_symbol().get_token_edits(&build_grammar());
+
+            assert_eq!(
+                vec![
+                    (false, 1, DummySyntaxTokenTest::ATerminal, Some(1u64)),
+                    (true, 2, DummySyntaxTokenTest::BTerminal, None),
+                ],
+                token_edits
+                    .into_iter()
+                    .map(|token_edit| match token_edit {
+                        TokenEdit::Delete(token_position, token) => {
+                            (false, token_position, token.t_type, token.lex)
+                        }
+                        TokenEdit::Insert(token_position, token) => {
+                            (true, token_position, token.t_type, token.lex)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    mod to_abstract_syntax_tree {
+        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+        use crate::ast::abstract_syntax_node_kind::AbstractSyntaxNodeKind;
+        use crate::parser::fix_gap::FixGap;
+        use crate::parser::fixed_production::FixedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::fixed_symbol_spec::test::DummySyntaxTokenTest;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_returns_an_error_node_for_each_fixed_symbol() -> () {
+            let fixed_symbol: FixedSymbol<u64, DummySyntaxTokenTest> = FixedSymbol::new(
+                FixedProduction::new(vec![
+                    FixedProductionPart::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    )),
+                    FixedProductionPart::Fixed(FixedSymbol::new(
+                        FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                            vec![],
+                            vec![DummySyntaxTokenTest::BTerminal],
+                        ))]),
+                        DummySyntaxTokenTest::A,
+                    )),
+                ]),
+                DummySyntaxTokenTest::S,
+            );
+
+            let tree = fixed_symbol.to_abstract_syntax_tree();
+
+            assert!(
+                AbstractSyntaxNode::with_kind(
+                    vec![
+                        AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        ),
+                        AbstractSyntaxNode::with_kind(
+                            vec![AbstractSyntaxNode::with_kind(
+                                vec![],
+                                AbstractSyntaxNodeKind::Missing,
+                                Token::new(None, DummySyntaxTokenTest::BTerminal),
+                            )],
+                            AbstractSyntaxNodeKind::Error,
+                            Token::new(None, DummySyntaxTokenTest::A),
+                        ),
+                    ],
+                    AbstractSyntaxNodeKind::Error,
+                    Token::new(None, DummySyntaxTokenTest::S),
+                ) == tree.root
+            );
+        }
+
+        #[test]
+        fn it_returns_skipped_nodes_followed_by_missing_nodes_for_a_gap() -> () {
+            let fixed_symbol: FixedSymbol<u64, DummySyntaxTokenTest> = FixedSymbol::new(
+                FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                    vec![
+                        Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                        Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+                    ],
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                    ],
+                ))]),
+                DummySyntaxTokenTest::S,
+            );
+
+            let tree = fixed_symbol.to_abstract_syntax_tree();
+
+            assert_eq!(
+                vec![
+                    (
+                        AbstractSyntaxNodeKind::Skipped,
+                        DummySyntaxTokenTest::CTerminal,
+                        Some(0u64)
+                    ),
+                    (
+                        AbstractSyntaxNodeKind::Skipped,
+                        DummySyntaxTokenTest::CTerminal,
+                        Some(1u64)
+                    ),
+                    (
+                        AbstractSyntaxNodeKind::Missing,
+                        DummySyntaxTokenTest::ATerminal,
+                        None
+                    ),
+                    (
+                        AbstractSyntaxNodeKind::Missing,
+                        DummySyntaxTokenTest::BTerminal,
+                        None
+                    ),
+                ],
+                tree.root
+                    .child_nodes
+                    .iter()
+                    .map(|node| (node.kind, node.token.t_type, node.token.lex))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    mod to_fixed_tokens {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::fix_gap::FixGap;
+        use crate::parser::fixed_production::FixedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::fixed_symbol_spec::test::{
+            build_fixed_symbol, build_grammar, DummySyntaxTokenTest,
+        };
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::single_token_solver::SingleTokenSolver;
+        use crate::token::token::Token;
+        use crate::token::token_edit::TokenEdit;
+
+        #[test]
+        fn it_returns_the_tokens_of_the_fixed_input() -> () {
+            let fixed_tokens = build_fixed_symbol().to_fixed_tokens(&build_grammar());
+
+            assert_eq!(
+                vec![
+                    (DummySyntaxTokenTest::ATerminal, Some(0u64)),
+                    (DummySyntaxTokenTest::BTerminal, None),
+                    (DummySyntaxTokenTest::CTerminal, Some(2u64)),
+                ],
+                fixed_tokens
+                    .into_iter()
+                    .map(|token| (token.t_type, token.lex))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn it_inserts_the_shortest_derivation_of_skipped_non_terminal_symbols() -> () {
+            let fixed_symbol: FixedSymbol<u64, DummySyntaxTokenTest> = FixedSymbol::new(
+                FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                    vec![],
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::CTerminal],
+                ))]),
+                DummySyntaxTokenTest::S,
+            );
+
+            assert_eq!(
+                vec![
+                    DummySyntaxTokenTest::ATerminal,
+                    DummySyntaxTokenTest::BTerminal,
+                    DummySyntaxTokenTest::CTerminal,
+                ],
+                fixed_symbol
+                    .to_fixed_tokens(&build_grammar())
+                    .into_iter()
+                    .map(|token| token.t_type)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn it_returns_tokens_parsed_without_errors() -> () {
+            let grammar = build_grammar();
+            let recursive_descent_parser =
+                RecursiveDescentParser::from_grammar_and_solver(&grammar, SingleTokenSolver::new());
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+            ];
+
+            let fixed_tokens = match recursive_descent_parser.parse_from_tokens(tokens.into_iter())
+            {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol.to_fixed_tokens(&grammar),
+                _ => panic!("Expected a fix!"),
+            };
+
+            assert_eq!(3, fixed_tokens.len());
+            assert!(matches!(
+                recursive_descent_parser.parse_from_tokens(fixed_tokens.into_iter()),
+                ParseResult::Ok(_)
+            ));
+        }
+
+        #[test]
+        fn it_returns_tokens_parsed_without_errors_if_non_terminal_symbols_are_skipped() -> () {
+            let grammar = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::S,
+                        vec![
+                            DummySyntaxTokenTest::ATerminal,
+                            DummySyntaxTokenTest::A,
+                            DummySyntaxTokenTest::BTerminal,
+                        ],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![DummySyntaxTokenTest::B],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::B,
+                        vec![DummySyntaxTokenTest::ATerminal],
+                    ),
+                ],
+            );
+            let recursive_descent_parser =
+                RecursiveDescentParser::from_grammar_and_solver(&grammar, PanicModeSolver::new());
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::BTerminal),
+            ];
+
+            let (fixed_tokens, token_edits) = match recursive_descent_parser
+                .parse_from_tokens(tokens.into_iter())
+            {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol.to_fixed_tokens_and_edits(&grammar),
+                _ => panic!("Expected a fix!"),
+            };
+
+            assert_eq!(
+                vec![
+                    DummySyntaxTokenTest::ATerminal,
+                    DummySyntaxTokenTest::ATerminal,
+                    DummySyntaxTokenTest::BTerminal,
+                ],
+                fixed_tokens
+                    .iter()
+                    .map(|token| token.t_type)
+                    .collect::<Vec<_>>()
+            );
+            assert!(matches!(
+                token_edits.as_slice(),
+                [TokenEdit::Insert(1, token)] if token.t_type == DummySyntaxTokenTest::ATerminal
+            ));
+            assert!(matches!(
+                recursive_descent_parser.parse_from_tokens(fixed_tokens.into_iter()),
+                ParseResult::Ok(_)
+            ));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// Input which can not be parsed by a parser
+///
+/// - `EmptyInput`: no tokens were provided.
+/// - `UnknownSymbol`: a symbol to derive is not a symbol of the grammar.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidInput<TSyntax> {
+    EmptyInput,
+    UnknownSymbol(TSyntax),
+}
@@ -0,0 +1,503 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+use crate::parser::failed_production::FailedProduction;
+use crate::parser::fix_gap::FixGap;
+use crate::parser::fix_result::FixResult;
+use crate::parser::fixed_production::FixedProduction;
+use crate::parser::fixed_production_part::FixedProductionPart;
+use crate::parser::fixed_symbol::FixedSymbol;
+use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+use crate::parser::recovery_context::RecoveryContext;
+use crate::parser::repair_costs::RepairCosts;
+use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+use crate::token::token::Token;
+
+enum RepairAction<TSyntax> {
+    Delete,
+    Expand(TSyntax, usize),
+    Insert(TSyntax),
+    Match,
+    Start,
+    Substitute(TSyntax),
+}
+
+struct RepairNode<TSyntax> {
+    action: RepairAction<TSyntax>,
+    parent_index: Option<usize>,
+    /// Symbols pending to be derived, the next one being the last one.
+    pending_symbols: Vec<TSyntax>,
+    token_position: usize,
+}
+
+impl<TSyntax> RepairNode<TSyntax> {
+    pub fn new(
+        action: RepairAction<TSyntax>,
+        pending_symbols: Vec<TSyntax>,
+        token_position: usize,
+        parent_index: Option<usize>,
+    ) -> Self {
+        Self {
+            action,
+            parent_index,
+            pending_symbols,
+            token_position,
+        }
+    }
+}
+
+/// Production being rebuilt from the actions of a repair.
+struct RepairFrame<TLex, TSyntax> {
+    fixed_parts: Vec<FixedProductionPart<TLex, TSyntax>>,
+    is_fixed: bool,
+    pending_symbols_count: usize,
+    symbol: Option<TSyntax>,
+}
+
+impl<TLex, TSyntax> RepairFrame<TLex, TSyntax> {
+    pub fn new(symbol: Option<TSyntax>, pending_symbols_count: usize) -> Self {
+        Self {
+            fixed_parts: vec![],
+            is_fixed: false,
+            pending_symbols_count,
+            symbol,
+        }
+    }
+}
+
+/// Minimum cost syntax error solver
+///
+/// Given a failed production A → αXβ, searches the cheapest sequence of token deletions,
+/// terminal insertions and token substitutions deriving Xβ from the position X failed at, so
+/// the first token after the derivation is in FOLLOW(A). The cost of each edit is given by the
+/// `RepairCosts` of the solver.
+///
+/// The search is a uniform cost search over the derivations of Xβ. Among repairs of the same
+/// cost, the one consuming more tokens is preferred. The search gives up once `search_budget`
+/// states are explored. Fixes are reported to a `SolverChain` with their repair costs.
+pub struct MinimumCostSolver<TSyntax> {
+    costs: RepairCosts<TSyntax>,
+    search_budget: usize,
+}
+
+impl<TSyntax: Clone + Eq + Hash> MinimumCostSolver<TSyntax> {
+    pub fn new(costs: RepairCosts<TSyntax>, search_budget: usize) -> Self {
+        Self {
+            costs,
+            search_budget,
+        }
+    }
+
+    /// Cheapest fix of a failed production and its cost, if any is found within the budget.
+    fn fix_failed_production_with_cost<TLex: Clone>(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<(FixResult<TLex, TSyntax>, u32)> {
+        let symbols: Vec<TSyntax> =
+            std::iter::once(failed_production.failed_symbol.symbol_to_derive.clone())
+                .chain(failed_production.pending_symbols.iter().cloned())
+                .collect();
+
+        let (repair_nodes, goal_index, cost) = self.search(
+            context,
+            tokens,
+            failed_production.failed_symbol.token_position,
+            &symbols,
+        )?;
+
+        let fixed_parts = failed_production
+            .parsed_symbols
+            .iter()
+            .map(ProductionParsedSymbol::to_fixed_production_part)
+            .chain(Self::build_fixed_parts(
+                tokens,
+                &repair_nodes,
+                goal_index,
+                symbols.len(),
+            ))
+            .collect();
+
+        Some((
+            FixResult::new(
+                repair_nodes[goal_index].token_position,
+                FixedProduction::new(fixed_parts),
+            ),
+            cost,
+        ))
+    }
+
+    /// Cost of the gaps of a fixed production, including the gaps of its fixed symbols, as given
+    /// by the repair costs. A gap replacing a single token by a single symbol is a substitution.
+    fn get_fixed_production_cost<TLex>(
+        &self,
+        fixed_production: &FixedProduction<TLex, TSyntax>,
+    ) -> u32 {
+        fixed_production
+            .fixed_parts
+            .iter()
+            .map(|fixed_part| match fixed_part {
+                FixedProductionPart::Ok(_) => 0,
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    self.get_fixed_production_cost(&fixed_symbol.fixed_production)
+                }
+                FixedProductionPart::Gap(fix_gap) => {
+                    match (
+                        fix_gap.skipped_tokens.as_slice(),
+                        fix_gap.skipped_symbols.as_slice(),
+                    ) {
+                        ([_], [symbol]) => self.costs.get_substitution_cost(symbol),
+                        (skipped_tokens, skipped_symbols) => {
+                            skipped_tokens
+                                .iter()
+                                .map(|token| self.costs.get_deletion_cost(&token.t_type))
+                                .sum::<u32>()
+                                + skipped_symbols
+                                    .iter()
+                                    .map(|symbol| self.costs.get_insertion_cost(symbol))
+                                    .sum::<u32>()
+                        }
+                    }
+                }
+            })
+            .sum()
+    }
+
+    /// Uniform cost search of a repair, returning the explored nodes, the goal node index and
+    /// the cost of the repair.
+    fn search<TLex>(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        token_position: usize,
+        symbols: &[TSyntax],
+    ) -> Option<(Vec<RepairNode<TSyntax>>, usize, u32)> {
+        let mut repair_nodes: Vec<RepairNode<TSyntax>> = vec![RepairNode::new(
+            RepairAction::Start,
+            symbols.iter().rev().cloned().collect(),
+            token_position,
+            None,
+        )];
+        let mut best_costs: HashMap<(usize, Vec<TSyntax>), u32> = HashMap::new();
+        let mut queue: BinaryHeap<(Reverse<u32>, usize, Reverse<usize>)> = BinaryHeap::new();
+
+        queue.push((Reverse(0), token_position, Reverse(0)));
+
+        let mut explored_count: usize = 0;
+
+        while let Some((Reverse(cost), _, Reverse(node_index))) = queue.pop() {
+            if explored_count >= self.search_budget {
+                return None;
+            }
+
+            explored_count += 1;
+
+            let node = &repair_nodes[node_index];
+
+            if node.pending_symbols.is_empty()
+                && Self::is_goal_position(context, tokens, node.token_position)
+            {
+                return Some((repair_nodes, node_index, cost));
+            }
+
+            let successors = self.get_successors(context, tokens, node_index, node);
+
+            for (successor, action_cost) in successors {
+                let successor_cost = cost + action_cost;
+                let state = (successor.token_position, successor.pending_symbols.clone());
+
+                if best_costs
+                    .get(&state)
+                    .is_some_and(|best_cost| *best_cost <= successor_cost)
+                {
+                    continue;
+                }
+
+                best_costs.insert(state, successor_cost);
+                queue.push((
+                    Reverse(successor_cost),
+                    successor.token_position,
+                    Reverse(repair_nodes.len()),
+                ));
+                repair_nodes.push(successor);
+            }
+        }
+
+        None
+    }
+
+    /// Nodes reached by a single action from a node, with the cost of the action.
+    fn get_successors<TLex>(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        node_index: usize,
+        node: &RepairNode<TSyntax>,
+    ) -> Vec<(RepairNode<TSyntax>, u32)> {
+        let mut successors = vec![];
+        let token_option = tokens.get(node.token_position);
+
+        if let Some(token) = token_option {
+            successors.push((
+                RepairNode::new(
+                    RepairAction::Delete,
+                    node.pending_symbols.clone(),
+                    node.token_position + 1,
+                    Some(node_index),
+                ),
+                self.costs.get_deletion_cost(&token.t_type),
+            ));
+        }
+
+        let symbol = match node.pending_symbols.last() {
+            Some(symbol) => symbol,
+            None => return successors,
+        };
+
+        let mut pending_symbols = node.pending_symbols.clone();
+        pending_symbols.pop();
+
+        if context.get_grammar().get_epsilon_symbol().eq(symbol) {
+            successors.push((
+                RepairNode::new(
+                    RepairAction::Match,
+                    pending_symbols,
+                    node.token_position,
+                    Some(node_index),
+                ),
+                0,
+            ));
+        } else if let Some(productions) = context.get_grammar().get_productions(symbol) {
+            for production in productions {
+                let mut expanded_symbols = pending_symbols.clone();
+                expanded_symbols.extend(production.output.iter().rev().cloned());
+
+                successors.push((
+                    RepairNode::new(
+                        RepairAction::Expand(symbol.clone(), production.output.len()),
+                        expanded_symbols,
+                        node.token_position,
+                        Some(node_index),
+                    ),
+                    0,
+                ));
+            }
+        } else {
+            if let Some(token) = token_option {
+                let (action, cost) = if token.t_type.eq(symbol) {
+                    (RepairAction::Match, 0)
+                } else {
+                    (
+                        RepairAction::Substitute(symbol.clone()),
+                        self.costs.get_substitution_cost(symbol),
+                    )
+                };
+
+                successors.push((
+                    RepairNode::new(
+                        action,
+                        pending_symbols.clone(),
+                        node.token_position + 1,
+                        Some(node_index),
+                    ),
+                    cost,
+                ));
+            }
+
+            successors.push((
+                RepairNode::new(
+                    RepairAction::Insert(symbol.clone()),
+                    pending_symbols,
+                    node.token_position,
+                    Some(node_index),
+                ),
+                self.costs.get_insertion_cost(symbol),
+            ));
+        }
+
+        successors
+    }
+
+    /// Determines whether a derivation of the production ending at a position is valid: the
+    /// token at the position must be in FOLLOW(A). The end of the input is only valid if
+    /// FOLLOW(A) is empty.
+    fn is_goal_position<TLex>(
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        token_position: usize,
+    ) -> bool {
+        let follow_symbols = match context
+            .get_first_follow_symbols()
+            .get_follow_symbols(context.get_symbol_to_derive())
+        {
+            Some(follow_symbols) => follow_symbols,
+            None => return token_position == tokens.len(),
+        };
+
+        match tokens.get(token_position) {
+            Some(token) => follow_symbols.contains(&token.t_type),
+            None => follow_symbols.is_empty(),
+        }
+    }
+
+    /// Rebuilds the fixed parts of the repaired symbols from the actions leading to the goal.
+    fn build_fixed_parts<TLex: Clone>(
+        tokens: &[Token<TLex, TSyntax>],
+        repair_nodes: &[RepairNode<TSyntax>],
+        goal_index: usize,
+        symbols_count: usize,
+    ) -> Vec<FixedProductionPart<TLex, TSyntax>> {
+        let mut node_indexes: Vec<usize> = vec![];
+        let mut node_index_option = Some(goal_index);
+
+        while let Some(node_index) = node_index_option {
+            node_indexes.push(node_index);
+            node_index_option = repair_nodes[node_index].parent_index;
+        }
+
+        let mut frames: Vec<RepairFrame<TLex, TSyntax>> =
+            vec![RepairFrame::new(None, symbols_count)];
+
+        for window in node_indexes.windows(2).rev() {
+            let (node, parent_node) = (&repair_nodes[window[0]], &repair_nodes[window[1]]);
+            let token_position = parent_node.token_position;
+
+            match &node.action {
+                RepairAction::Delete => Self::build_fixed_parts_push_part(
+                    &mut frames,
+                    FixedProductionPart::Gap(FixGap::new(
+                        vec![tokens[token_position].clone()],
+                        vec![],
+                    )),
+                    true,
+                    false,
+                ),
+                RepairAction::Expand(symbol, symbols_count) => {
+                    frames.push(RepairFrame::new(Some(symbol.clone()), *symbols_count));
+                }
+                RepairAction::Insert(symbol) => Self::build_fixed_parts_push_part(
+                    &mut frames,
+                    FixedProductionPart::Gap(FixGap::new(vec![], vec![symbol.clone()])),
+                    true,
+                    true,
+                ),
+                RepairAction::Match => {
+                    let token = if node.token_position > token_position {
+                        tokens[token_position].clone()
+                    } else {
+                        Token::new(None, parent_node.pending_symbols.last().unwrap().clone())
+                    };
+
+                    Self::build_fixed_parts_push_part(
+                        &mut frames,
+                        FixedProductionPart::Ok(AbstractSyntaxNode::new(vec![], token)),
+                        false,
+                        true,
+                    )
+                }
+                RepairAction::Start => {}
+                RepairAction::Substitute(symbol) => Self::build_fixed_parts_push_part(
+                    &mut frames,
+                    FixedProductionPart::Gap(FixGap::new(
+                        vec![tokens[token_position].clone()],
+                        vec![symbol.clone()],
+                    )),
+                    true,
+                    true,
+                ),
+            }
+        }
+
+        frames.pop().unwrap().fixed_parts
+    }
+
+    /// Pushes a part to the current frame, completing the frames with no pending symbols.
+    fn build_fixed_parts_push_part<TLex>(
+        frames: &mut Vec<RepairFrame<TLex, TSyntax>>,
+        fixed_part: FixedProductionPart<TLex, TSyntax>,
+        is_fixed: bool,
+        completes_symbol: bool,
+    ) {
+        let frame = frames.last_mut().unwrap();
+
+        frame.fixed_parts.push(fixed_part);
+        frame.is_fixed |= is_fixed;
+
+        if !completes_symbol {
+            return;
+        }
+
+        frame.pending_symbols_count -= 1;
+
+        while frames.len() > 1 && frames.last().unwrap().pending_symbols_count == 0 {
+            let frame = frames.pop().unwrap();
+            let symbol = frame.symbol.unwrap();
+
+            let fixed_part = if frame.is_fixed {
+                FixedProductionPart::Fixed(FixedSymbol::new(
+                    FixedProduction::new(frame.fixed_parts),
+                    symbol,
+                ))
+            } else {
+                let child_nodes = frame
+                    .fixed_parts
+                    .into_iter()
+                    .map(|fixed_part| match fixed_part {
+                        FixedProductionPart::Ok(node) => node,
+                        _ => panic!("Unexpected fixed part!"),
+                    })
+                    .collect();
+
+                FixedProductionPart::Ok(AbstractSyntaxNode::new(
+                    child_nodes,
+                    Token::new(None, symbol),
+                ))
+            };
+
+            let parent_frame = frames.last_mut().unwrap();
+
+            parent_frame.fixed_parts.push(fixed_part);
+            parent_frame.is_fixed |= frame.is_fixed;
+            parent_frame.pending_symbols_count -= 1;
+        }
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone + Eq + Hash> SyntaxErrorSolver<TLex, TSyntax>
+    for MinimumCostSolver<TSyntax>
+{
+    fn fix_failed_production(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        _tokens_position: usize,
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        self.fix_failed_production_with_cost(context, tokens, failed_production)
+            .map(|(fix_result, _)| fix_result)
+    }
+
+    fn fix_failed_productions(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        _tokens_position: usize,
+        failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        failed_productions
+            .iter()
+            .filter_map(|failed_production| {
+                self.fix_failed_production_with_cost(context, tokens, failed_production)
+            })
+            .enumerate()
+            .min_by_key(|(index, (_, cost))| (*cost, *index))
+            .map(|(_, (fix_result, _))| fix_result)
+    }
+
+    fn get_fix_cost(&self, fix_result: &FixResult<TLex, TSyntax>) -> usize {
+        self.get_fixed_production_cost(&fix_result.production) as usize
+    }
+}
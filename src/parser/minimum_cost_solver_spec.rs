@@ -0,0 +1,255 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::parser::fixed_production_part::FixedProductionPart;
+    use crate::parser::minimum_cost_solver::MinimumCostSolver;
+    use crate::parser::parse_result::ParseResult;
+    use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+    use crate::parser::repair_costs::RepairCosts;
+    use crate::token::token::Token;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum StatementSyntaxTokenTest {
+        Assign,
+        Eof,
+        Epsilon,
+        Id,
+        Number,
+        S,
+        Semicolon,
+        Stmt,
+        Value,
+    }
+
+    fn parse(
+        tokens: Vec<StatementSyntaxTokenTest>,
+        costs: RepairCosts<StatementSyntaxTokenTest>,
+        search_budget: usize,
+    ) -> ParseResult<u64, StatementSyntaxTokenTest> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::S,
+                vec![
+                    StatementSyntaxTokenTest::Stmt,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Value,
+                    StatementSyntaxTokenTest::Semicolon,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Number],
+            ),
+        ];
+
+        let grammar = Arc::new(ContextFreeGrammar::new(
+            StatementSyntaxTokenTest::Epsilon,
+            StatementSyntaxTokenTest::S,
+            grammar_productions,
+        ));
+
+        let recursive_descent_parser: RecursiveDescentParser<
+            u64,
+            StatementSyntaxTokenTest,
+            MinimumCostSolver<StatementSyntaxTokenTest>,
+        > = RecursiveDescentParser::from_shared_grammar_and_solver(
+            grammar,
+            MinimumCostSolver::new(costs, search_budget),
+        );
+
+        recursive_descent_parser.parse_from_tokens(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(index, t_type)| Token::new(Some(index as u64), t_type)),
+        )
+    }
+
+    fn get_statement_parts(
+        parse_result: &ParseResult<u64, StatementSyntaxTokenTest>,
+    ) -> &Vec<FixedProductionPart<u64, StatementSyntaxTokenTest>> {
+        let fixed_symbol = match parse_result {
+            ParseResult::Fix(fixed_symbol) => fixed_symbol,
+            _ => panic!("expected a fix"),
+        };
+
+        match fixed_symbol.fixed_production.fixed_parts.as_slice() {
+            [FixedProductionPart::Fixed(statement_fixed_symbol), FixedProductionPart::Ok(_)] => {
+                &statement_fixed_symbol.fixed_production.fixed_parts
+            }
+            _ => panic!("expected a fixed statement"),
+        }
+    }
+
+    mod fix_failed_production {
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::minimum_cost_solver_spec::test::{
+            get_statement_parts, parse, StatementSyntaxTokenTest,
+        };
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::repair_costs::RepairCosts;
+
+        #[test]
+        fn it_inserts_a_missing_terminal() -> () {
+            let parse_result = parse(
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+                RepairCosts::default(),
+                1000,
+            );
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                ] if fix_gap.skipped_tokens.is_empty()
+                    && fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Semicolon]
+            ));
+        }
+
+        #[test]
+        fn it_substitutes_an_unexpected_token() -> () {
+            let parse_result = parse(
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+                RepairCosts::default(),
+                1000,
+            );
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                    FixedProductionPart::Ok(value_node),
+                    FixedProductionPart::Ok(_),
+                ] if fix_gap.skipped_tokens.len() == 1
+                    && fix_gap.skipped_tokens[0].lex == Some(1u64)
+                    && fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Assign]
+                    && value_node.token.t_type == StatementSyntaxTokenTest::Value
+            ));
+        }
+
+        #[test]
+        fn it_applies_the_costs_of_each_terminal() -> () {
+            let tokens = vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Semicolon,
+                StatementSyntaxTokenTest::Eof,
+            ];
+
+            let default_parse_result = parse(tokens.clone(), RepairCosts::default(), 1000);
+
+            assert!(matches!(
+                &get_statement_parts(&default_parse_result)[3..],
+                [FixedProductionPart::Gap(fix_gap), FixedProductionPart::Ok(_)]
+                    if fix_gap.skipped_tokens[0].lex == Some(3u64)
+                        && fix_gap.skipped_symbols.is_empty()
+            ));
+
+            let mut costs: RepairCosts<StatementSyntaxTokenTest> = RepairCosts::default();
+            costs
+                .deletion_costs
+                .insert(StatementSyntaxTokenTest::Number, 5);
+
+            let parse_result = parse(tokens, costs, 1000);
+
+            assert!(matches!(
+                &get_statement_parts(&parse_result)[3..],
+                [FixedProductionPart::Gap(substitution_gap), FixedProductionPart::Gap(deletion_gap)]
+                    if substitution_gap.skipped_tokens[0].lex == Some(3u64)
+                        && substitution_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Semicolon]
+                        && deletion_gap.skipped_tokens[0].lex == Some(4u64)
+                        && deletion_gap.skipped_symbols.is_empty()
+            ));
+        }
+
+        #[test]
+        fn it_does_not_fix_once_the_search_budget_is_exhausted() -> () {
+            let parse_result = parse(
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+                RepairCosts::default(),
+                1,
+            );
+
+            assert!(matches!(parse_result, ParseResult::Err(_)));
+        }
+    }
+
+    mod get_fix_cost {
+        use crate::parser::fix_gap::FixGap;
+        use crate::parser::fix_result::FixResult;
+        use crate::parser::fixed_production::FixedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::minimum_cost_solver::MinimumCostSolver;
+        use crate::parser::minimum_cost_solver_spec::test::StatementSyntaxTokenTest;
+        use crate::parser::repair_costs::RepairCosts;
+        use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_returns_the_repair_costs_of_the_gaps() -> () {
+            let mut costs: RepairCosts<StatementSyntaxTokenTest> = RepairCosts::new(2, 3, 4);
+            costs
+                .deletion_costs
+                .insert(StatementSyntaxTokenTest::Number, 5);
+
+            let solver = MinimumCostSolver::new(costs, 1000);
+
+            let fix_result: FixResult<u64, StatementSyntaxTokenTest> = FixResult::new(
+                3,
+                FixedProduction::new(vec![
+                    FixedProductionPart::Gap(FixGap::new(
+                        vec![Token::new(Some(0u64), StatementSyntaxTokenTest::Number)],
+                        vec![],
+                    )),
+                    FixedProductionPart::Fixed(FixedSymbol::new(
+                        FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                            vec![Token::new(Some(1u64), StatementSyntaxTokenTest::Id)],
+                            vec![StatementSyntaxTokenTest::Assign],
+                        ))]),
+                        StatementSyntaxTokenTest::Stmt,
+                    )),
+                    FixedProductionPart::Gap(FixGap::new(
+                        vec![],
+                        vec![StatementSyntaxTokenTest::Semicolon],
+                    )),
+                ]),
+            );
+
+            assert_eq!(12, solver.get_fix_cost(&fix_result));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod debug_symbol_encoder;
+pub mod expected_symbols;
 pub mod failed_production;
 pub mod failed_symbol;
 pub mod fix_gap;
@@ -5,11 +7,37 @@ pub mod fix_result;
 pub mod fixed_production;
 pub mod fixed_production_part;
 pub mod fixed_symbol;
+pub mod invalid_input;
+pub mod minimum_cost_solver;
+pub mod panic_mode_solver;
+pub mod parse_abort;
+pub mod parse_all_result;
+pub mod parse_limits;
+pub mod parse_observer;
 pub mod parse_result;
+pub mod precomputed_parse_tables;
+pub mod precomputed_parse_tables_error;
 pub mod production_parsed_symbol;
+pub mod recovery_context;
+pub mod recovery_parse_result;
 pub mod recursive_descent_parser;
 pub mod recursive_descent_parser_transitions;
+pub mod repair_costs;
+pub mod single_token_solver;
+pub mod solver_chain;
+pub mod solver_chain_strategy;
+pub mod symbol_encoder;
+pub mod symbol_sequence_matcher;
 pub mod syntax_error_solver;
+pub mod trace_parse_observer;
 
+mod fixed_symbol_spec;
+mod minimum_cost_solver_spec;
+mod panic_mode_solver_spec;
+mod precomputed_parse_tables_spec;
+mod recovery_context_spec;
 mod recursive_descent_parser_spec;
 mod recursive_descent_parser_transitions_spec;
+mod single_token_solver_spec;
+mod solver_chain_spec;
+mod symbol_sequence_matcher_spec;
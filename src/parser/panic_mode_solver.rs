@@ -0,0 +1,134 @@
+use std::hash::Hash;
+
+use crate::parser::failed_production::FailedProduction;
+use crate::parser::fix_gap::FixGap;
+use crate::parser::fix_result::FixResult;
+use crate::parser::fixed_production::FixedProduction;
+use crate::parser::fixed_production_part::FixedProductionPart;
+use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+use crate::parser::recovery_context::RecoveryContext;
+use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+use crate::token::token::Token;
+
+/// Panic mode syntax error solver
+///
+/// Given a failed production A → αXβ, tokens are skipped from the position X failed at until a
+/// token in FOLLOW(A) is found. The symbols parsed before the error are kept and a gap records
+/// the skipped tokens and the symbols X and β.
+///
+/// No fix is provided if the input ends before a synchronizing token is found, unless the solver
+/// synchronizes on the end of the input. Symbols with multiple candidate productions are fixed
+/// with the production whose error is the furthest one. Symbols with no candidate productions are
+/// fixed with a gap of the skipped tokens.
+pub struct PanicModeSolver {
+    synchronizes_on_end_of_input: bool,
+}
+
+impl PanicModeSolver {
+    pub fn new() -> Self {
+        Self {
+            synchronizes_on_end_of_input: false,
+        }
+    }
+
+    /// Builds a solver skipping every remaining token if no synchronizing token is found.
+    pub fn synchronizing_on_end_of_input() -> Self {
+        Self {
+            synchronizes_on_end_of_input: true,
+        }
+    }
+
+    /// Position of the first token in FOLLOW of the symbol to fix, if any.
+    fn get_synchronizing_position<TLex, TSyntax: Clone + Eq + Hash>(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        tokens_position: usize,
+    ) -> Option<usize> {
+        let synchronizing_position_option = context
+            .get_first_follow_symbols()
+            .get_follow_symbols(context.get_symbol_to_derive())
+            .and_then(|follow_symbols| {
+                tokens
+                    .iter()
+                    .enumerate()
+                    .skip(tokens_position)
+                    .find(|(_, token)| follow_symbols.contains(&token.t_type))
+                    .map(|(position, _)| position)
+            });
+
+        match synchronizing_position_option {
+            None if self.synchronizes_on_end_of_input => Some(tokens.len()),
+            synchronizing_position_option => synchronizing_position_option,
+        }
+    }
+}
+
+impl Default for PanicModeSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone + Eq + Hash> SyntaxErrorSolver<TLex, TSyntax> for PanicModeSolver {
+    fn fix_failed_production(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        _tokens_position: usize,
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        let error_position = failed_production.failed_symbol.token_position;
+        let synchronizing_position =
+            self.get_synchronizing_position(context, tokens, error_position)?;
+
+        let mut fixed_parts: Vec<FixedProductionPart<TLex, TSyntax>> = failed_production
+            .parsed_symbols
+            .iter()
+            .map(ProductionParsedSymbol::to_fixed_production_part)
+            .collect();
+
+        let mut skipped_symbols = vec![failed_production.failed_symbol.symbol_to_derive.clone()];
+        skipped_symbols.extend(failed_production.pending_symbols.iter().cloned());
+
+        fixed_parts.push(FixedProductionPart::Gap(FixGap::new(
+            tokens[error_position..synchronizing_position].to_vec(),
+            skipped_symbols,
+        )));
+
+        Some(FixResult::new(
+            synchronizing_position,
+            FixedProduction::new(fixed_parts),
+        ))
+    }
+
+    fn fix_failed_productions(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        match failed_productions
+            .iter()
+            .rev()
+            .max_by_key(|failed_production| failed_production.failed_symbol.token_position)
+        {
+            Some(failed_production) => {
+                self.fix_failed_production(context, tokens, tokens_position, failed_production)
+            }
+            None => {
+                let synchronizing_position =
+                    self.get_synchronizing_position(context, tokens, tokens_position)?;
+
+                Some(FixResult::new(
+                    synchronizing_position,
+                    FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                        tokens[tokens_position..synchronizing_position].to_vec(),
+                        vec![],
+                    ))]),
+                ))
+            }
+        }
+    }
+}
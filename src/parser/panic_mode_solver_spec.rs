@@ -0,0 +1,307 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+    use crate::parser::recovery_context::RecoveryContext;
+    use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum DummySyntaxTokenTest {
+        A,
+        ATerminal,
+        B,
+        BTerminal,
+        CTerminal,
+        Eof,
+        Epsilon,
+        S,
+    }
+
+    fn build_grammar() -> Arc<ContextFreeGrammar<DummySyntaxTokenTest>> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                DummySyntaxTokenTest::S,
+                vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+            ),
+            ContextFreeGrammarProduction::new(
+                DummySyntaxTokenTest::A,
+                vec![
+                    DummySyntaxTokenTest::ATerminal,
+                    DummySyntaxTokenTest::BTerminal,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                DummySyntaxTokenTest::B,
+                vec![DummySyntaxTokenTest::CTerminal],
+            ),
+        ];
+
+        Arc::new(ContextFreeGrammar::new(
+            DummySyntaxTokenTest::Epsilon,
+            DummySyntaxTokenTest::S,
+            grammar_productions,
+        ))
+    }
+
+    /// Runs a function with the context of a fix of the non terminal symbol `A`.
+    fn with_recovery_context<TResult>(
+        function: impl FnOnce(&RecoveryContext<DummySyntaxTokenTest>) -> TResult,
+    ) -> TResult {
+        let grammar = build_grammar();
+        let first_follow_symbols = FirstFollowSymbols::from(grammar.as_ref());
+        let transitions = RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+        let non_terminal_stack = [DummySyntaxTokenTest::S, DummySyntaxTokenTest::A];
+
+        function(&RecoveryContext::new(
+            &first_follow_symbols,
+            &grammar,
+            &non_terminal_stack,
+            &transitions,
+        ))
+    }
+
+    mod fix_failed_production {
+        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+        use crate::parser::failed_production::FailedProduction;
+        use crate::parser::failed_symbol::FailedSymbol;
+        use crate::parser::fixed_production::FixedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::panic_mode_solver_spec::test::{
+            with_recovery_context, DummySyntaxTokenTest,
+        };
+        use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+        use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+        use crate::token::token::Token;
+
+        fn build_failed_production() -> FailedProduction<u64, DummySyntaxTokenTest> {
+            FailedProduction::new(
+                FailedSymbol::new(vec![], DummySyntaxTokenTest::BTerminal, 1),
+                vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                    vec![],
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                ))],
+                vec![],
+            )
+        }
+
+        #[test]
+        fn it_skips_tokens_until_a_follow_symbol() -> () {
+            let solver = PanicModeSolver::new();
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+                Token::new(Some(2u64), DummySyntaxTokenTest::CTerminal),
+                Token::new(Some(3u64), DummySyntaxTokenTest::Eof),
+            ];
+
+            let fix_result = with_recovery_context(|context| {
+                solver.fix_failed_production(context, &tokens, 0, &build_failed_production())
+            })
+            .unwrap();
+
+            assert_eq!(3, fix_result.final_token_position);
+            assert_eq!(2, fix_result.production.fixed_parts.len());
+
+            match &fix_result.production.fixed_parts[0] {
+                FixedProductionPart::Ok(node) => {
+                    assert_eq!(DummySyntaxTokenTest::ATerminal, node.token.t_type)
+                }
+                _ => panic!("expected an ok part"),
+            }
+
+            match &fix_result.production.fixed_parts[1] {
+                FixedProductionPart::Gap(fix_gap) => {
+                    assert_eq!(
+                        vec![Some(1u64), Some(2u64)],
+                        fix_gap
+                            .skipped_tokens
+                            .iter()
+                            .map(|token| token.lex)
+                            .collect::<Vec<Option<u64>>>(),
+                    );
+                    assert_eq!(
+                        vec![DummySyntaxTokenTest::BTerminal],
+                        fix_gap.skipped_symbols
+                    );
+                }
+                _ => panic!("expected a gap part"),
+            }
+        }
+
+        #[test]
+        fn it_does_not_fix_if_no_follow_symbol_is_found() -> () {
+            let solver = PanicModeSolver::new();
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+            ];
+
+            assert!(with_recovery_context(|context| {
+                solver.fix_failed_production(context, &tokens, 0, &build_failed_production())
+            })
+            .is_none());
+        }
+
+        #[test]
+        fn it_skips_every_remaining_token_if_it_synchronizes_on_end_of_input() -> () {
+            let solver = PanicModeSolver::synchronizing_on_end_of_input();
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+            ];
+
+            let fix_result = with_recovery_context(|context| {
+                solver.fix_failed_production(context, &tokens, 0, &build_failed_production())
+            })
+            .unwrap();
+
+            assert_eq!(2, fix_result.final_token_position);
+
+            match &fix_result.production.fixed_parts[1] {
+                FixedProductionPart::Gap(fix_gap) => assert_eq!(1, fix_gap.skipped_tokens.len()),
+                _ => panic!("expected a gap part"),
+            }
+        }
+        #[test]
+        fn it_keeps_fixed_parsed_symbols() -> () {
+            let solver = PanicModeSolver::new();
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::Eof),
+            ];
+
+            let failed_production = FailedProduction::new(
+                FailedSymbol::new(vec![], DummySyntaxTokenTest::BTerminal, 0),
+                vec![ProductionParsedSymbol::Fix(FixedSymbol::new(
+                    FixedProduction::new(vec![]),
+                    DummySyntaxTokenTest::ATerminal,
+                ))],
+                vec![],
+            );
+
+            let fix_result = with_recovery_context(|context| {
+                solver.fix_failed_production(context, &tokens, 0, &failed_production)
+            })
+            .unwrap();
+
+            assert_eq!(1, fix_result.final_token_position);
+
+            match &fix_result.production.fixed_parts[0] {
+                FixedProductionPart::Fixed(fixed_symbol) => {
+                    assert_eq!(
+                        DummySyntaxTokenTest::ATerminal,
+                        fixed_symbol.symbol_to_derive
+                    )
+                }
+                _ => panic!("expected a fixed part"),
+            }
+        }
+    }
+
+    mod fix_failed_productions {
+        use crate::parser::failed_production::FailedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::panic_mode_solver_spec::test::{
+            with_recovery_context, DummySyntaxTokenTest,
+        };
+        use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_skips_tokens_until_a_follow_symbol_without_failed_productions() -> () {
+            let solver = PanicModeSolver::new();
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::Eof),
+            ];
+            let failed_productions: Vec<FailedProduction<u64, DummySyntaxTokenTest>> = vec![];
+
+            let fix_result = with_recovery_context(|context| {
+                solver.fix_failed_productions(context, &tokens, 0, &failed_productions)
+            })
+            .unwrap();
+
+            assert_eq!(1, fix_result.final_token_position);
+            assert!(matches!(
+                fix_result.production.fixed_parts.as_slice(),
+                [FixedProductionPart::Gap(fix_gap)]
+                    if fix_gap.skipped_tokens.len() == 1 && fix_gap.skipped_symbols.is_empty()
+            ));
+        }
+    }
+
+    mod parse_from_tokens {
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::panic_mode_solver_spec::test::{build_grammar, DummySyntaxTokenTest};
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_recovers_from_a_syntax_error() -> () {
+            let grammar = build_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                PanicModeSolver,
+            > = RecursiveDescentParser::from_shared_grammar_and_solver(
+                grammar,
+                PanicModeSolver::new(),
+            );
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(1u64), DummySyntaxTokenTest::CTerminal),
+                    Token::new(Some(2u64), DummySyntaxTokenTest::CTerminal),
+                    Token::new(Some(3u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
+
+            let fixed_symbol = match parse_result {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol,
+                _ => panic!("expected a fix"),
+            };
+
+            assert_eq!(DummySyntaxTokenTest::S, fixed_symbol.symbol_to_derive);
+
+            let fixed_parts = &fixed_symbol.fixed_production.fixed_parts;
+
+            assert_eq!(2, fixed_parts.len());
+
+            match &fixed_parts[0] {
+                FixedProductionPart::Fixed(a_fixed_symbol) => {
+                    assert_eq!(DummySyntaxTokenTest::A, a_fixed_symbol.symbol_to_derive);
+                    assert!(matches!(
+                        a_fixed_symbol.fixed_production.fixed_parts.as_slice(),
+                        [FixedProductionPart::Ok(_), FixedProductionPart::Gap(fix_gap)]
+                            if fix_gap.skipped_tokens.len() == 2
+                    ));
+                }
+                _ => panic!("expected a fixed part"),
+            }
+
+            match &fixed_parts[1] {
+                FixedProductionPart::Ok(node) => {
+                    assert_eq!(DummySyntaxTokenTest::Eof, node.token.t_type)
+                }
+                _ => panic!("expected an ok part"),
+            }
+        }
+    }
+}
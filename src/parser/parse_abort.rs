@@ -0,0 +1,9 @@
+/// Reason of a parse abort
+///
+/// A parse is aborted when any of the `ParseLimits` of the parser is reached.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseAbort {
+    Cancelled,
+    MaxDepthExceeded,
+    MaxStepsExceeded,
+}
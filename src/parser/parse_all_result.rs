@@ -0,0 +1,13 @@
+use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use crate::parser::invalid_input::InvalidInput;
+use crate::parser::parse_abort::ParseAbort;
+use crate::token::token::Token;
+
+/// Result of enumerating every parse of an input
+///
+/// `Ok` contains every distinct tree found, which is empty if the input can not be parsed.
+pub enum ParseAllResult<TLex, TSyntax> {
+    Ok(Vec<AbstractSyntaxTree<Token<TLex, TSyntax>>>),
+    Abort(ParseAbort),
+    InvalidInput(InvalidInput<TSyntax>),
+}
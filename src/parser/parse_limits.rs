@@ -0,0 +1,47 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// Limits of a parse
+///
+/// - `cancellation_flag`: the parse is cancelled once the flag is set. The flag is checked when
+///   the parse starts and periodically afterwards, so a few additional steps may be performed
+///   after the flag is set.
+/// - `max_depth`: maximum number of nested non terminal symbols being derived.
+/// - `max_steps`: maximum number of symbols the parser attempts to parse, backtracking included.
+///
+/// A `None` value means no limit.
+pub struct ParseLimits {
+    pub cancellation_flag: Option<Arc<AtomicBool>>,
+    pub max_depth: Option<usize>,
+    pub max_steps: Option<usize>,
+}
+
+impl ParseLimits {
+    pub fn new(
+        cancellation_flag: Option<Arc<AtomicBool>>,
+        max_depth: Option<usize>,
+        max_steps: Option<usize>,
+    ) -> Self {
+        Self {
+            cancellation_flag,
+            max_depth,
+            max_steps,
+        }
+    }
+}
+
+impl Clone for ParseLimits {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.cancellation_flag.clone(),
+            self.max_depth,
+            self.max_steps,
+        )
+    }
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::new(None, None, None)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::token::token::Token;
+
+/// Parse observer
+///
+/// Receives the events of a RecursiveDescentParser parse. Every callback does nothing by
+/// default, so observers implement only the callbacks they are interested in.
+pub trait ParseObserver<TLex, TSyntax> {
+    /// Called when the parser starts deriving a non terminal symbol.
+    fn on_enter_non_terminal(&mut self, _symbol: &TSyntax, _token_position: usize) {}
+
+    /// Called when the parser finishes deriving a non terminal symbol.
+    ///
+    /// `is_success` is true if the symbol was parsed or fixed.
+    fn on_exit_non_terminal(
+        &mut self,
+        _symbol: &TSyntax,
+        _token_position: usize,
+        _is_success: bool,
+    ) {
+    }
+
+    /// Called when the parser tries a production of a non terminal symbol.
+    fn on_try_production(
+        &mut self,
+        _symbol: &TSyntax,
+        _production_output: &[TSyntax],
+        _token_position: usize,
+    ) {
+    }
+
+    /// Called when a production tried by the parser could not be parsed.
+    fn on_reject_production(
+        &mut self,
+        _symbol: &TSyntax,
+        _production_output: &[TSyntax],
+        _token_position: usize,
+    ) {
+    }
+
+    /// Called when a token is matched by a terminal symbol.
+    fn on_match_terminal(&mut self, _token: &Token<TLex, TSyntax>, _token_position: usize) {}
+
+    /// Called when the parser backtracks to try the remaining productions of an already parsed
+    /// non terminal symbol.
+    fn on_backtrack(&mut self, _symbol: &TSyntax, _token_position: usize) {}
+
+    /// Called when the parser invokes the syntax error solver.
+    fn on_invoke_solver(&mut self, _symbol: &TSyntax, _token_position: usize) {}
+}
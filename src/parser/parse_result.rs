@@ -1,10 +1,14 @@
 use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
 use crate::parser::failed_symbol::FailedSymbol;
 use crate::parser::fixed_symbol::FixedSymbol;
+use crate::parser::invalid_input::InvalidInput;
+use crate::parser::parse_abort::ParseAbort;
 use crate::token::token::Token;
 
 pub enum ParseResult<TLex, TSyntax> {
     Ok(AbstractSyntaxTree<Token<TLex, TSyntax>>),
     Err(FailedSymbol<TLex, TSyntax>),
     Fix(FixedSymbol<TLex, TSyntax>),
+    Abort(ParseAbort),
+    InvalidInput(InvalidInput<TSyntax>),
 }
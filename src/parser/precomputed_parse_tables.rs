@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::first_follow_symbols::{FirstFollowSymbols, SymbolsMap};
+use crate::grammar::symbol_set_view::SymbolSetView;
+use crate::parser::precomputed_parse_tables_error::PrecomputedParseTablesError;
+use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+use crate::parser::symbol_encoder::SymbolEncoder;
+
+const FINGERPRINT_KEYWORD: &str = "fingerprint";
+const FIRST_KEYWORD: &str = "first";
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+const FOLLOW_KEYWORD: &str = "follow";
+const HEADER: &str = "artlr_syntax parse tables v1";
+const TRANSITION_KEYWORD: &str = "transition";
+
+/// Precomputed parse tables
+///
+/// FIRST / FOLLOW symbols and transitions table of a grammar. Tables can be serialized in a
+/// textual format and loaded later on in order to avoid computing them at every start.
+///
+/// Serialized tables include a fingerprint of the grammar, so tables computed for a different
+/// grammar are rejected when loaded. Transitions are serialized as indexes of the productions of
+/// each non terminal symbol.
+pub struct PrecomputedParseTables<T> {
+    first_follow_symbols: FirstFollowSymbols<T>,
+    transitions: RecursiveDescentParserTransitions<T>,
+}
+
+impl<T> PrecomputedParseTables<T> {
+    pub fn new(
+        first_follow_symbols: FirstFollowSymbols<T>,
+        transitions: RecursiveDescentParserTransitions<T>,
+    ) -> Self {
+        Self {
+            first_follow_symbols,
+            transitions,
+        }
+    }
+
+    pub fn get_first_follow_symbols(&self) -> &FirstFollowSymbols<T> {
+        &self.first_follow_symbols
+    }
+
+    pub fn get_transitions(&self) -> &RecursiveDescentParserTransitions<T> {
+        &self.transitions
+    }
+
+    pub fn into_parts(self) -> (FirstFollowSymbols<T>, RecursiveDescentParserTransitions<T>) {
+        (self.first_follow_symbols, self.transitions)
+    }
+
+    fn hash_fnv_1a(value: &str) -> u64 {
+        value.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
+impl<T: Clone + Eq + Hash> PrecomputedParseTables<T> {
+    pub fn from_grammar(grammar: &ContextFreeGrammar<T>) -> Self {
+        let first_follow_symbols = FirstFollowSymbols::from(grammar);
+        let transitions = RecursiveDescentParserTransitions::from(grammar, &first_follow_symbols);
+
+        Self::new(first_follow_symbols, transitions)
+    }
+
+    /// Loads tables serialized by `PrecomputedParseTables.serialize`.
+    ///
+    /// The encoder must be equivalent to the one used to serialize the tables.
+    pub fn deserialize<TEncoder: SymbolEncoder<T>>(
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+        serialized_tables: &str,
+    ) -> Result<Self, PrecomputedParseTablesError> {
+        let symbols_map = Self::build_decoded_symbols_map(grammar, encoder)?;
+        let mut lines = serialized_tables.lines().enumerate();
+
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => (),
+            _ => return Err(PrecomputedParseTablesError::InvalidHeader),
+        }
+
+        Self::deserialize_fingerprint(grammar, encoder, lines.next())?;
+
+        let mut first_symbols: SymbolsMap<T> = vec![];
+        let mut follow_symbols: SymbolsMap<T> = vec![];
+        let mut cells: Vec<((T, T), Vec<usize>)> = vec![];
+
+        for (line_index, line) in lines {
+            let line_number = line_index + 1;
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            match words.as_slice() {
+                [] => (),
+                [FIRST_KEYWORD, symbol, symbols @ ..] => {
+                    first_symbols.push((
+                        Self::decode_symbol(&symbols_map, line_number, symbol)?,
+                        Self::decode_symbols(&symbols_map, line_number, symbols)?,
+                    ));
+                }
+                [FOLLOW_KEYWORD, symbol, symbols @ ..] => {
+                    follow_symbols.push((
+                        Self::decode_symbol(&symbols_map, line_number, symbol)?,
+                        Self::decode_symbols(&symbols_map, line_number, symbols)?,
+                    ));
+                }
+                [TRANSITION_KEYWORD, non_terminal_symbol, terminal_symbol, production_indexes @ ..] =>
+                {
+                    Self::deserialize_transition(
+                        grammar,
+                        &symbols_map,
+                        &mut cells,
+                        line_number,
+                        (non_terminal_symbol, terminal_symbol),
+                        production_indexes,
+                    )?;
+                }
+                _ => return Err(PrecomputedParseTablesError::InvalidLine(line_number)),
+            }
+        }
+
+        Ok(Self::new(
+            FirstFollowSymbols::new(
+                grammar.get_epsilon_symbol().clone(),
+                first_symbols,
+                follow_symbols,
+            ),
+            RecursiveDescentParserTransitions::new(grammar, cells),
+        ))
+    }
+
+    /// Computes a fingerprint of a grammar.
+    ///
+    /// The fingerprint depends on the encoded symbols and the productions of the grammar,
+    /// including their order.
+    pub fn get_grammar_fingerprint<TEncoder: SymbolEncoder<T>>(
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+    ) -> u64 {
+        let mut production_lines: Vec<String> = vec![];
+
+        for non_terminal_symbol in grammar.get_non_terminal_symbols() {
+            let productions = grammar.get_productions(&non_terminal_symbol).unwrap();
+
+            for (production_index, production) in productions.iter().enumerate() {
+                let production_output: Vec<String> = production
+                    .output
+                    .iter()
+                    .map(|symbol| encoder.encode(symbol))
+                    .collect();
+
+                production_lines.push(format!(
+                    "{} {} {}",
+                    encoder.encode(&non_terminal_symbol),
+                    production_index,
+                    production_output.join(" ")
+                ));
+            }
+        }
+
+        production_lines.sort();
+
+        let grammar_description = format!(
+            "{}\n{}\n{}",
+            encoder.encode(grammar.get_epsilon_symbol()),
+            encoder.encode(grammar.get_initial_symbol()),
+            production_lines.join("\n")
+        );
+
+        Self::hash_fnv_1a(&grammar_description)
+    }
+
+    /// Serializes the tables in a textual format.
+    ///
+    /// Lines are sorted so the same tables are always serialized the same way.
+    pub fn serialize<TEncoder: SymbolEncoder<T>>(
+        &self,
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+    ) -> String {
+        let mut symbols = grammar.get_non_terminal_symbols();
+        symbols.extend(grammar.get_terminal_symbols());
+
+        let mut first_lines: Vec<String> = vec![];
+        let mut follow_lines: Vec<String> = vec![];
+
+        for symbol in &symbols {
+            if let Some(first_symbols) = self.first_follow_symbols.get_first_symbols(symbol) {
+                first_lines.push(Self::serialize_symbols_line(
+                    encoder,
+                    FIRST_KEYWORD,
+                    symbol,
+                    first_symbols,
+                ));
+            }
+
+            if let Some(follow_symbols) = self.first_follow_symbols.get_follow_symbols(symbol) {
+                follow_lines.push(Self::serialize_symbols_line(
+                    encoder,
+                    FOLLOW_KEYWORD,
+                    symbol,
+                    follow_symbols,
+                ));
+            }
+        }
+
+        let mut transition_lines = self.serialize_transition_lines(grammar, encoder);
+
+        first_lines.sort();
+        follow_lines.sort();
+        transition_lines.sort();
+
+        let mut lines: Vec<String> = vec![
+            String::from(HEADER),
+            format!(
+                "{} {:016x}",
+                FINGERPRINT_KEYWORD,
+                Self::get_grammar_fingerprint(grammar, encoder)
+            ),
+        ];
+
+        lines.extend(first_lines);
+        lines.extend(follow_lines);
+        lines.extend(transition_lines);
+
+        lines.join("\n") + "\n"
+    }
+
+    fn build_decoded_symbols_map<TEncoder: SymbolEncoder<T>>(
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+    ) -> Result<HashMap<String, T>, PrecomputedParseTablesError> {
+        let mut symbols_map: HashMap<String, T> = HashMap::new();
+
+        let symbols = grammar
+            .get_non_terminal_symbols()
+            .into_iter()
+            .chain(grammar.get_terminal_symbols());
+
+        for symbol in symbols {
+            let encoded_symbol = encoder.encode(&symbol);
+
+            if symbols_map.contains_key(&encoded_symbol) {
+                return Err(PrecomputedParseTablesError::DuplicatedSymbolEncoding(
+                    encoded_symbol,
+                ));
+            }
+
+            symbols_map.insert(encoded_symbol, symbol);
+        }
+
+        Ok(symbols_map)
+    }
+
+    fn decode_symbol(
+        symbols_map: &HashMap<String, T>,
+        line_number: usize,
+        encoded_symbol: &str,
+    ) -> Result<T, PrecomputedParseTablesError> {
+        symbols_map.get(encoded_symbol).cloned().ok_or_else(|| {
+            PrecomputedParseTablesError::UnknownSymbol(line_number, String::from(encoded_symbol))
+        })
+    }
+
+    fn decode_symbols(
+        symbols_map: &HashMap<String, T>,
+        line_number: usize,
+        encoded_symbols: &[&str],
+    ) -> Result<Vec<T>, PrecomputedParseTablesError> {
+        encoded_symbols
+            .iter()
+            .map(|encoded_symbol| Self::decode_symbol(symbols_map, line_number, encoded_symbol))
+            .collect()
+    }
+
+    fn deserialize_fingerprint<TEncoder: SymbolEncoder<T>>(
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+        line_option: Option<(usize, &str)>,
+    ) -> Result<(), PrecomputedParseTablesError> {
+        let (line_index, line) =
+            line_option.ok_or(PrecomputedParseTablesError::MissingFingerprint)?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let found_fingerprint = match words.as_slice() {
+            [FINGERPRINT_KEYWORD, fingerprint] => u64::from_str_radix(fingerprint, 16)
+                .map_err(|_| PrecomputedParseTablesError::InvalidLine(line_index + 1))?,
+            _ => return Err(PrecomputedParseTablesError::MissingFingerprint),
+        };
+
+        let expected_fingerprint = Self::get_grammar_fingerprint(grammar, encoder);
+
+        if expected_fingerprint == found_fingerprint {
+            Ok(())
+        } else {
+            Err(PrecomputedParseTablesError::FingerprintMismatch(
+                expected_fingerprint,
+                found_fingerprint,
+            ))
+        }
+    }
+
+    fn deserialize_transition(
+        grammar: &ContextFreeGrammar<T>,
+        symbols_map: &HashMap<String, T>,
+        cells: &mut Vec<((T, T), Vec<usize>)>,
+        line_number: usize,
+        (encoded_non_terminal_symbol, encoded_terminal_symbol): (&str, &str),
+        encoded_production_indexes: &[&str],
+    ) -> Result<(), PrecomputedParseTablesError> {
+        let invalid_line_error = PrecomputedParseTablesError::InvalidLine(line_number);
+
+        let non_terminal_symbol =
+            Self::decode_symbol(symbols_map, line_number, encoded_non_terminal_symbol)?;
+        let terminal_symbol =
+            Self::decode_symbol(symbols_map, line_number, encoded_terminal_symbol)?;
+
+        let productions = grammar
+            .get_productions(&non_terminal_symbol)
+            .ok_or_else(|| invalid_line_error.clone())?;
+
+        if !grammar.is_terminal(&terminal_symbol) {
+            return Err(invalid_line_error);
+        }
+
+        let production_indexes = encoded_production_indexes
+            .iter()
+            .map(|encoded_production_index| {
+                encoded_production_index
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|production_index| *production_index < productions.len())
+                    .ok_or_else(|| invalid_line_error.clone())
+            })
+            .collect::<Result<Vec<usize>, PrecomputedParseTablesError>>()?;
+
+        cells.push(((non_terminal_symbol, terminal_symbol), production_indexes));
+
+        Ok(())
+    }
+
+    fn serialize_symbols_line<TEncoder: SymbolEncoder<T>>(
+        encoder: &TEncoder,
+        keyword: &str,
+        symbol: &T,
+        symbols: SymbolSetView<T>,
+    ) -> String {
+        let mut encoded_symbols: Vec<String> = symbols
+            .iter()
+            .map(|symbol| encoder.encode(symbol))
+            .collect();
+
+        encoded_symbols.sort();
+
+        let mut words = vec![String::from(keyword), encoder.encode(symbol)];
+        words.extend(encoded_symbols);
+
+        words.join(" ")
+    }
+
+    fn serialize_transition_lines<TEncoder: SymbolEncoder<T>>(
+        &self,
+        grammar: &ContextFreeGrammar<T>,
+        encoder: &TEncoder,
+    ) -> Vec<String> {
+        let symbol_table = grammar.get_symbol_table();
+        let terminal_symbol_ids = grammar.get_terminal_symbol_ids();
+        let mut transition_lines: Vec<String> = vec![];
+
+        for non_terminal_symbol_id in grammar.get_non_terminal_symbol_ids() {
+            for terminal_symbol_id in &terminal_symbol_ids {
+                let production_indexes = match self
+                    .transitions
+                    .get_production_indexes_by_id(non_terminal_symbol_id, *terminal_symbol_id)
+                {
+                    Some(production_indexes) if !production_indexes.is_empty() => {
+                        production_indexes
+                    }
+                    _ => continue,
+                };
+
+                let mut words = vec![
+                    String::from(TRANSITION_KEYWORD),
+                    encoder.encode(symbol_table.get_symbol(non_terminal_symbol_id)),
+                    encoder.encode(symbol_table.get_symbol(*terminal_symbol_id)),
+                ];
+
+                words.extend(
+                    production_indexes
+                        .iter()
+                        .map(|production_index| production_index.to_string()),
+                );
+
+                transition_lines.push(words.join(" "));
+            }
+        }
+
+        transition_lines
+    }
+}
@@ -0,0 +1,19 @@
+/// Error found when loading precomputed parse tables
+///
+/// - `DuplicatedSymbolEncoding`: several symbols of the grammar share the given encoding.
+/// - `FingerprintMismatch`: the tables were computed for a different grammar. Holds the
+///   fingerprint of the grammar and the fingerprint of the tables.
+/// - `InvalidHeader`: the tables do not start with the expected header.
+/// - `InvalidLine`: the line at the given number is malformed.
+/// - `MissingFingerprint`: the tables do not provide a grammar fingerprint.
+/// - `UnknownSymbol`: the line at the given number references an encoded symbol which is not a
+///   symbol of the grammar.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PrecomputedParseTablesError {
+    DuplicatedSymbolEncoding(String),
+    FingerprintMismatch(u64, u64),
+    InvalidHeader,
+    InvalidLine(usize),
+    MissingFingerprint,
+    UnknownSymbol(usize, String),
+}
@@ -0,0 +1,234 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum SyntaxTokenTest {
+        A,
+        ATerminal,
+        BTerminal,
+        Eof,
+        Epsilon,
+        S,
+    }
+
+    pub fn build_grammar() -> ContextFreeGrammar<SyntaxTokenTest> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<SyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                SyntaxTokenTest::S,
+                vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+            ),
+            ContextFreeGrammarProduction::new(
+                SyntaxTokenTest::A,
+                vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A],
+            ),
+            ContextFreeGrammarProduction::new(SyntaxTokenTest::A, vec![SyntaxTokenTest::Epsilon]),
+        ];
+
+        ContextFreeGrammar::new(
+            SyntaxTokenTest::Epsilon,
+            SyntaxTokenTest::S,
+            grammar_productions,
+        )
+    }
+
+    mod serialize {
+        use crate::parser::debug_symbol_encoder::DebugSymbolEncoder;
+        use crate::parser::precomputed_parse_tables::PrecomputedParseTables;
+        use crate::parser::precomputed_parse_tables_spec::test::build_grammar;
+
+        #[test]
+        fn it_serializes_tables() -> () {
+            let grammar = build_grammar();
+            let precomputed_parse_tables = PrecomputedParseTables::from_grammar(&grammar);
+
+            let expected_lines: Vec<&str> = vec![
+                "first A ATerminal Epsilon",
+                "first ATerminal ATerminal",
+                "first Eof Eof",
+                "first Epsilon Epsilon",
+                "first S ATerminal Eof",
+                "follow A Eof",
+                "follow S",
+                "transition A ATerminal 0",
+                "transition A Eof 1",
+                "transition S ATerminal 0",
+                "transition S Eof 0",
+            ];
+
+            let serialized_tables =
+                precomputed_parse_tables.serialize(&grammar, &DebugSymbolEncoder);
+            let lines: Vec<&str> = serialized_tables.lines().collect();
+
+            assert_eq!(lines[0], "artlr_syntax parse tables v1");
+            assert_eq!(
+                lines[1],
+                format!(
+                    "fingerprint {:016x}",
+                    PrecomputedParseTables::get_grammar_fingerprint(&grammar, &DebugSymbolEncoder)
+                )
+            );
+            assert_eq!(lines[2..].to_vec(), expected_lines);
+        }
+    }
+
+    mod deserialize {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::debug_symbol_encoder::DebugSymbolEncoder;
+        use crate::parser::precomputed_parse_tables::PrecomputedParseTables;
+        use crate::parser::precomputed_parse_tables_error::PrecomputedParseTablesError;
+        use crate::parser::precomputed_parse_tables_spec::test::{build_grammar, SyntaxTokenTest};
+
+        #[test]
+        fn it_deserializes_serialized_tables() -> () {
+            let grammar = build_grammar();
+            let serialized_tables = PrecomputedParseTables::from_grammar(&grammar)
+                .serialize(&grammar, &DebugSymbolEncoder);
+
+            let deserialized_tables = PrecomputedParseTables::deserialize(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            )
+            .ok()
+            .unwrap();
+
+            assert_eq!(
+                deserialized_tables.serialize(&grammar, &DebugSymbolEncoder),
+                serialized_tables
+            );
+
+            let a_terminal_productions = deserialized_tables
+                .get_transitions()
+                .get_productions(&SyntaxTokenTest::A, &SyntaxTokenTest::ATerminal)
+                .unwrap();
+
+            assert_eq!(a_terminal_productions.len(), 1);
+            assert_eq!(
+                a_terminal_productions[0].output,
+                vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A]
+            );
+        }
+
+        #[test]
+        fn it_rejects_tables_of_a_different_grammar() -> () {
+            let grammar = build_grammar();
+            let serialized_tables = PrecomputedParseTables::from_grammar(&grammar)
+                .serialize(&grammar, &DebugSymbolEncoder);
+
+            let other_grammar = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::BTerminal, SyntaxTokenTest::A],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            );
+
+            let result = PrecomputedParseTables::deserialize(
+                &other_grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(PrecomputedParseTablesError::FingerprintMismatch(
+                    PrecomputedParseTables::get_grammar_fingerprint(
+                        &other_grammar,
+                        &DebugSymbolEncoder
+                    ),
+                    PrecomputedParseTables::get_grammar_fingerprint(&grammar, &DebugSymbolEncoder),
+                ))
+            );
+        }
+
+        #[test]
+        fn it_rejects_tables_with_an_invalid_header() -> () {
+            let grammar = build_grammar();
+
+            let result = PrecomputedParseTables::deserialize(
+                &grammar,
+                &DebugSymbolEncoder,
+                "not parse tables\n",
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(PrecomputedParseTablesError::InvalidHeader)
+            );
+        }
+
+        #[test]
+        fn it_rejects_tables_with_unknown_symbols() -> () {
+            let grammar = build_grammar();
+            let serialized_tables = format!(
+                "artlr_syntax parse tables v1\nfingerprint {:016x}\nfirst Unknown Eof\n",
+                PrecomputedParseTables::get_grammar_fingerprint(&grammar, &DebugSymbolEncoder)
+            );
+
+            let result = PrecomputedParseTables::deserialize(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(PrecomputedParseTablesError::UnknownSymbol(
+                    3,
+                    String::from("Unknown")
+                ))
+            );
+        }
+
+        #[test]
+        fn it_rejects_tables_with_unknown_productions() -> () {
+            let grammar = build_grammar();
+            let serialized_tables = format!(
+                "artlr_syntax parse tables v1\nfingerprint {:016x}\ntransition A Eof 2\n",
+                PrecomputedParseTables::get_grammar_fingerprint(&grammar, &DebugSymbolEncoder)
+            );
+
+            let result = PrecomputedParseTables::deserialize(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(PrecomputedParseTablesError::InvalidLine(3))
+            );
+        }
+
+        #[test]
+        fn it_rejects_tables_without_fingerprint() -> () {
+            let grammar = build_grammar();
+
+            let result = PrecomputedParseTables::deserialize(
+                &grammar,
+                &DebugSymbolEncoder,
+                "artlr_syntax parse tables v1\nfirst Eof Eof\n",
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(PrecomputedParseTablesError::MissingFingerprint)
+            );
+        }
+    }
+}
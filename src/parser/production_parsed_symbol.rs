@@ -1,4 +1,5 @@
 use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+use crate::parser::fixed_production_part::FixedProductionPart;
 use crate::parser::fixed_symbol::FixedSymbol;
 use crate::token::token::Token;
 
@@ -6,3 +7,14 @@ pub enum ProductionParsedSymbol<TLex, TSyntax> {
     Ok(AbstractSyntaxNode<Token<TLex, TSyntax>>),
     Fix(FixedSymbol<TLex, TSyntax>),
 }
+
+impl<TLex: Clone, TSyntax: Clone> ProductionParsedSymbol<TLex, TSyntax> {
+    pub fn to_fixed_production_part(&self) -> FixedProductionPart<TLex, TSyntax> {
+        match self {
+            ProductionParsedSymbol::Ok(node) => FixedProductionPart::Ok(node.clone()),
+            ProductionParsedSymbol::Fix(fixed_symbol) => {
+                FixedProductionPart::Fixed(fixed_symbol.clone())
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::hash::Hash;
+
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+use crate::parser::failed_production::FailedProduction;
+use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+/// Context of a syntax error fix attempt
+///
+/// Exposes the tables of the parser invoking the solver and the stack of non terminal symbols
+/// being derived, from the initial symbol to the symbol to fix.
+pub struct RecoveryContext<'a, TSyntax> {
+    first_follow_symbols: &'a FirstFollowSymbols<TSyntax>,
+    grammar: &'a ContextFreeGrammar<TSyntax>,
+    non_terminal_stack: &'a [TSyntax],
+    transitions: &'a RecursiveDescentParserTransitions<TSyntax>,
+}
+
+impl<'a, TSyntax> RecoveryContext<'a, TSyntax> {
+    pub fn new(
+        first_follow_symbols: &'a FirstFollowSymbols<TSyntax>,
+        grammar: &'a ContextFreeGrammar<TSyntax>,
+        non_terminal_stack: &'a [TSyntax],
+        transitions: &'a RecursiveDescentParserTransitions<TSyntax>,
+    ) -> Self {
+        Self {
+            first_follow_symbols,
+            grammar,
+            non_terminal_stack,
+            transitions,
+        }
+    }
+
+    pub fn get_first_follow_symbols(&self) -> &'a FirstFollowSymbols<TSyntax> {
+        self.first_follow_symbols
+    }
+
+    pub fn get_grammar(&self) -> &'a ContextFreeGrammar<TSyntax> {
+        self.grammar
+    }
+
+    /// Non terminal symbols being derived, the symbol to fix being the last one.
+    pub fn get_non_terminal_stack(&self) -> &'a [TSyntax] {
+        self.non_terminal_stack
+    }
+
+    /// Non terminal symbol to fix.
+    pub fn get_symbol_to_derive(&self) -> &'a TSyntax {
+        self.non_terminal_stack.last().unwrap()
+    }
+
+    pub fn get_transitions(&self) -> &'a RecursiveDescentParserTransitions<TSyntax> {
+        self.transitions
+    }
+}
+
+impl<'a, TSyntax: Clone + Eq + Hash> RecoveryContext<'a, TSyntax> {
+    /// Terminal symbols expected where a production failed, in order of appearance in the
+    /// grammar.
+    ///
+    /// Given the failed production A → αXβ, these are FIRST(Xβ), FOLLOW(A) replacing epsilon if
+    /// Xβ is nullable.
+    pub fn get_expected_symbols<TLex>(
+        &self,
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Vec<TSyntax> {
+        let symbol_ids_option: Option<Vec<u32>> =
+            std::iter::once(&failed_production.failed_symbol.symbol_to_derive)
+                .chain(failed_production.pending_symbols.iter())
+                .map(|symbol| self.grammar.get_symbol_id(symbol))
+                .collect();
+
+        let expected_symbol_set = match symbol_ids_option
+            .zip(self.grammar.get_symbol_id(self.get_symbol_to_derive()))
+            .and_then(|(symbol_ids, production_input_id)| {
+                self.first_follow_symbols
+                    .get_expected_symbol_set(&symbol_ids, production_input_id)
+            }) {
+            Some(expected_symbol_set) => expected_symbol_set,
+            None => return vec![],
+        };
+
+        let symbol_table = self.grammar.get_symbol_table();
+
+        expected_symbol_set
+            .iter()
+            .map(|symbol_id| symbol_table.get_symbol(symbol_id).clone())
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+    use crate::parser::recovery_context::RecoveryContext;
+    use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum StatementSyntaxTokenTest {
+        Eof,
+        Epsilon,
+        Id,
+        Number,
+        S,
+        Semicolon,
+        Stmt,
+        Value,
+    }
+
+    /// Runs a function with the context of a fix of the non terminal symbol `Stmt`.
+    fn with_recovery_context<TResult>(
+        function: impl FnOnce(&RecoveryContext<StatementSyntaxTokenTest>) -> TResult,
+    ) -> TResult {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::S,
+                vec![
+                    StatementSyntaxTokenTest::Stmt,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Value,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Stmt,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Number],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Epsilon],
+            ),
+        ];
+
+        let grammar = ContextFreeGrammar::new(
+            StatementSyntaxTokenTest::Epsilon,
+            StatementSyntaxTokenTest::S,
+            grammar_productions,
+        );
+        let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+        let transitions = RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+        let non_terminal_stack = [StatementSyntaxTokenTest::S, StatementSyntaxTokenTest::Stmt];
+
+        function(&RecoveryContext::new(
+            &first_follow_symbols,
+            &grammar,
+            &non_terminal_stack,
+            &transitions,
+        ))
+    }
+
+    mod get_expected_symbols {
+        use std::collections::HashSet;
+
+        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+        use crate::parser::failed_production::FailedProduction;
+        use crate::parser::failed_symbol::FailedSymbol;
+        use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+        use crate::parser::recovery_context_spec::test::{
+            with_recovery_context, StatementSyntaxTokenTest,
+        };
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_returns_the_first_symbols_of_the_failed_symbols() -> () {
+            let failed_production: FailedProduction<u64, StatementSyntaxTokenTest> =
+                FailedProduction::new(
+                    FailedSymbol::new(vec![], StatementSyntaxTokenTest::Stmt, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), StatementSyntaxTokenTest::Semicolon),
+                    ))],
+                    vec![],
+                );
+
+            let expected_symbols: HashSet<StatementSyntaxTokenTest> =
+                with_recovery_context(|context| context.get_expected_symbols(&failed_production))
+                    .into_iter()
+                    .collect();
+
+            assert_eq!(
+                HashSet::from([
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Semicolon,
+                ]),
+                expected_symbols,
+            );
+        }
+
+        #[test]
+        fn it_returns_the_follow_symbols_of_nullable_failed_symbols() -> () {
+            let failed_production: FailedProduction<u64, StatementSyntaxTokenTest> =
+                FailedProduction::new(
+                    FailedSymbol::new(vec![], StatementSyntaxTokenTest::Value, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), StatementSyntaxTokenTest::Id),
+                    ))],
+                    vec![],
+                );
+
+            let expected_symbols: HashSet<StatementSyntaxTokenTest> =
+                with_recovery_context(|context| context.get_expected_symbols(&failed_production))
+                    .into_iter()
+                    .collect();
+
+            assert_eq!(
+                HashSet::from([
+                    StatementSyntaxTokenTest::Eof,
+                    StatementSyntaxTokenTest::Number,
+                ]),
+                expected_symbols,
+            );
+        }
+    }
+
+    mod get_symbol_to_derive {
+        use crate::parser::recovery_context_spec::test::{
+            with_recovery_context, StatementSyntaxTokenTest,
+        };
+
+        #[test]
+        fn it_returns_the_last_non_terminal_of_the_stack() -> () {
+            assert_eq!(
+                StatementSyntaxTokenTest::Stmt,
+                with_recovery_context(|context| *context.get_symbol_to_derive()),
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::diagnostics::diagnostic::Diagnostic;
+use crate::parser::parse_result::ParseResult;
+
+/// Result of a parse recovering from every syntax error
+///
+/// - `diagnostics`: syntax errors found, in order of appearance.
+/// - `parse_result`: best-effort result of the parse, a `Fix` if errors were found.
+pub struct RecoveryParseResult<TLex, TSyntax> {
+    pub diagnostics: Vec<Diagnostic<TLex, TSyntax>>,
+    pub parse_result: ParseResult<TLex, TSyntax>,
+}
+
+impl<TLex, TSyntax> RecoveryParseResult<TLex, TSyntax> {
+    pub fn new(
+        diagnostics: Vec<Diagnostic<TLex, TSyntax>>,
+        parse_result: ParseResult<TLex, TSyntax>,
+    ) -> Self {
+        Self {
+            diagnostics,
+            parse_result,
+        }
+    }
+}
@@ -1,18 +1,39 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
 
 use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
 use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
+use crate::diagnostics::diagnostic::Diagnostic;
 use crate::grammar::context_free_grammar::ContextFreeGrammar;
 use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::parser::expected_symbols::ExpectedSymbols;
 use crate::parser::failed_production::FailedProduction;
 use crate::parser::failed_symbol::FailedSymbol;
+use crate::parser::fix_gap::FixGap;
 use crate::parser::fix_result::FixResult;
 use crate::parser::fixed_production::FixedProduction;
 use crate::parser::fixed_production_part::FixedProductionPart;
 use crate::parser::fixed_symbol::FixedSymbol;
+use crate::parser::invalid_input::InvalidInput;
+use crate::parser::panic_mode_solver::PanicModeSolver;
+use crate::parser::parse_abort::ParseAbort;
+use crate::parser::parse_all_result::ParseAllResult;
+use crate::parser::parse_limits::ParseLimits;
+use crate::parser::parse_observer::ParseObserver;
 use crate::parser::parse_result::ParseResult;
+use crate::parser::precomputed_parse_tables::PrecomputedParseTables;
+use crate::parser::precomputed_parse_tables_error::PrecomputedParseTablesError;
 use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+use crate::parser::recovery_context::RecoveryContext;
+use crate::parser::recovery_parse_result::RecoveryParseResult;
 use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+use crate::parser::symbol_encoder::SymbolEncoder;
 use crate::parser::syntax_error_solver::SyntaxErrorSolver;
 use crate::token::token::Token;
 use std::marker::PhantomData;
@@ -21,12 +42,117 @@ enum ParseSymbolResult<'a, TLex, TSyntax> {
     Ok(ParsingState<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>),
     Err(FailedSymbol<TLex, TSyntax>),
     Fix(FixedState<TLex, TSyntax>),
+    Abort(ParseAbort),
 }
 
 enum ParseProductionResult<TLex, TSyntax> {
     Ok(AbstractSyntaxNode<Token<TLex, TSyntax>>),
     Err(FailedProduction<TLex, TSyntax>),
     Fix(FixedProduction<TLex, TSyntax>),
+    Abort(ParseAbort),
+}
+
+const CANCELLATION_CHECK_STEPS: usize = 256;
+
+/// Symbol ID of the tokens whose type is not a terminal symbol of the grammar.
+const UNKNOWN_TOKEN_ID: u32 = u32::MAX;
+
+/// Diagnostics of the regions skipped by a parse with recovery, by gap bounds.
+type RecoveryDiagnostics<TLex, TSyntax> =
+    RefCell<HashMap<(usize, usize), Diagnostic<TLex, TSyntax>>>;
+
+/// Trees found by a parse enumerating every tree, up to a limit.
+struct ParseAllTrees<TLex, TSyntax> {
+    limit: usize,
+    trees: RefCell<Vec<AbstractSyntaxTree<Token<TLex, TSyntax>>>>,
+}
+
+impl<TLex, TSyntax> ParseAllTrees<TLex, TSyntax> {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            trees: RefCell::new(vec![]),
+        }
+    }
+}
+
+struct ParseContext<'a, TLex, TSyntax> {
+    depth: Cell<usize>,
+    limits: &'a ParseLimits,
+    non_terminal_stack: RefCell<Vec<TSyntax>>,
+    observer: Option<RefCell<&'a mut dyn ParseObserver<TLex, TSyntax>>>,
+    parse_all_trees: Option<&'a ParseAllTrees<TLex, TSyntax>>,
+    recovery_diagnostics: Option<&'a RecoveryDiagnostics<TLex, TSyntax>>,
+    steps: Cell<usize>,
+    token_ids: Vec<u32>,
+}
+
+impl<'a, TLex, TSyntax> ParseContext<'a, TLex, TSyntax> {
+    pub fn new(
+        limits: &'a ParseLimits,
+        observer: Option<&'a mut dyn ParseObserver<TLex, TSyntax>>,
+        parse_all_trees: Option<&'a ParseAllTrees<TLex, TSyntax>>,
+        recovery_diagnostics: Option<&'a RecoveryDiagnostics<TLex, TSyntax>>,
+        token_ids: Vec<u32>,
+    ) -> Self {
+        Self {
+            depth: Cell::new(0),
+            limits,
+            non_terminal_stack: RefCell::new(vec![]),
+            observer: observer.map(RefCell::new),
+            parse_all_trees,
+            recovery_diagnostics,
+            steps: Cell::new(0),
+            token_ids,
+        }
+    }
+
+    pub fn notify<F: FnOnce(&mut dyn ParseObserver<TLex, TSyntax>)>(&self, callback: F) {
+        if let Some(observer) = self.observer.as_ref() {
+            callback(*observer.borrow_mut());
+        }
+    }
+
+    pub fn enter_symbol(&self) -> Result<(), ParseAbort> {
+        let depth = self.depth.get() + 1;
+
+        self.depth.set(depth);
+
+        match self.limits.max_depth {
+            Some(max_depth) if depth > max_depth => Err(ParseAbort::MaxDepthExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn exit_symbol(&self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+
+    pub fn step(&self) -> Result<(), ParseAbort> {
+        let steps = self.steps.get() + 1;
+
+        self.steps.set(steps);
+
+        if let Some(max_steps) = self.limits.max_steps {
+            if steps > max_steps {
+                return Err(ParseAbort::MaxStepsExceeded);
+            }
+        }
+
+        if steps.is_multiple_of(CANCELLATION_CHECK_STEPS) && self.is_cancelled() {
+            return Err(ParseAbort::Cancelled);
+        }
+
+        Ok(())
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.limits
+            .cancellation_flag
+            .as_ref()
+            .map(|cancellation_flag| cancellation_flag.load(Ordering::Relaxed))
+            .unwrap_or(false)
+    }
 }
 
 struct FixedState<TLex, TSyntax> {
@@ -81,8 +207,54 @@ impl<'a, TLex, TSyntax: 'a, TIter: Iterator<Item = &'a Vec<TSyntax>>>
     }
 }
 
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum CompletionFrame<TSyntax> {
+    Exit(TSyntax),
+    Symbol(TSyntax),
+}
+
+struct CompletionState<TSyntax> {
+    derived_symbols: Vec<TSyntax>,
+    frames: Vec<CompletionFrame<TSyntax>>,
+}
+
+impl<TSyntax> CompletionState<TSyntax> {
+    pub fn new(derived_symbols: Vec<TSyntax>, frames: Vec<CompletionFrame<TSyntax>>) -> Self {
+        Self {
+            derived_symbols,
+            frames,
+        }
+    }
+}
+
+enum GrammarHandle<'a, TSyntax> {
+    Borrowed(&'a ContextFreeGrammar<TSyntax>),
+    Shared(Arc<ContextFreeGrammar<TSyntax>>),
+}
+
+impl<'a, TSyntax> Deref for GrammarHandle<'a, TSyntax> {
+    type Target = ContextFreeGrammar<TSyntax>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            GrammarHandle::Borrowed(grammar) => grammar,
+            GrammarHandle::Shared(grammar) => grammar,
+        }
+    }
+}
+
+/// Recursive descent parser which owns its grammar
+///
+/// The grammar is shared behind an `Arc`, so the parser can be stored without lifetimes, cached
+/// in a global or shared across threads. The parser is `Send + Sync` as long as the lex, syntax
+/// and solver types are.
+pub type OwnedRecursiveDescentParser<TLex, TSyntax, TSolver> =
+    RecursiveDescentParser<'static, TLex, TSyntax, TSolver>;
+
 pub struct RecursiveDescentParser<'a, TLex, TSyntax, TSolver: SyntaxErrorSolver<TLex, TSyntax>> {
-    grammar: &'a ContextFreeGrammar<TSyntax>,
+    first_follow_symbols: FirstFollowSymbols<TSyntax>,
+    grammar: GrammarHandle<'a, TSyntax>,
+    limits: ParseLimits,
     marker: PhantomData<TLex>,
     syntax_error_solver: Option<TSolver>,
     transitions: RecursiveDescentParserTransitions<TSyntax>,
@@ -105,7 +277,7 @@ impl<
         grammar: &'a ContextFreeGrammar<TSyntax>,
         first_follow_symbols: &FirstFollowSymbols<TSyntax>,
     ) -> Self {
-        Self::from(grammar, first_follow_symbols, None)
+        Self::from(GrammarHandle::Borrowed(grammar), first_follow_symbols, None)
     }
 
     pub fn from_grammar_and_solver(
@@ -114,59 +286,646 @@ impl<
     ) -> Self {
         let first_follow_symbols = FirstFollowSymbols::from(grammar);
 
-        Self::from(grammar, &first_follow_symbols, Some(syntax_error_solver))
+        Self::from(
+            GrammarHandle::Borrowed(grammar),
+            &first_follow_symbols,
+            Some(syntax_error_solver),
+        )
+    }
+
+    /// Builds a parser from tables serialized by `PrecomputedParseTables.serialize`.
+    ///
+    /// Fails if the tables can not be loaded or if they were computed for a different grammar.
+    pub fn from_precomputed<TEncoder: SymbolEncoder<TSyntax>>(
+        grammar: &'a ContextFreeGrammar<TSyntax>,
+        encoder: &TEncoder,
+        serialized_tables: &str,
+    ) -> Result<Self, PrecomputedParseTablesError> {
+        Self::from_precomputed_tables(grammar, encoder, serialized_tables, None)
+    }
+
+    /// Builds a parser with a syntax error solver from tables serialized by
+    /// `PrecomputedParseTables.serialize`.
+    ///
+    /// Fails if the tables can not be loaded or if they were computed for a different grammar.
+    pub fn from_precomputed_and_solver<TEncoder: SymbolEncoder<TSyntax>>(
+        grammar: &'a ContextFreeGrammar<TSyntax>,
+        encoder: &TEncoder,
+        serialized_tables: &str,
+        syntax_error_solver: TSolver,
+    ) -> Result<Self, PrecomputedParseTablesError> {
+        Self::from_precomputed_tables(
+            grammar,
+            encoder,
+            serialized_tables,
+            Some(syntax_error_solver),
+        )
+    }
+
+    /// Builds a parser sharing the ownership of a grammar.
+    ///
+    /// Use `OwnedRecursiveDescentParser` to refer to a parser built this way.
+    pub fn from_shared_grammar(grammar: Arc<ContextFreeGrammar<TSyntax>>) -> Self {
+        let first_follow_symbols = FirstFollowSymbols::from(&*grammar);
+
+        Self::from(GrammarHandle::Shared(grammar), &first_follow_symbols, None)
+    }
+
+    /// Builds a parser sharing the ownership of a grammar with a syntax error solver.
+    ///
+    /// Use `OwnedRecursiveDescentParser` to refer to a parser built this way.
+    pub fn from_shared_grammar_and_solver(
+        grammar: Arc<ContextFreeGrammar<TSyntax>>,
+        syntax_error_solver: TSolver,
+    ) -> Self {
+        let first_follow_symbols = FirstFollowSymbols::from(&*grammar);
+
+        Self::from(
+            GrammarHandle::Shared(grammar),
+            &first_follow_symbols,
+            Some(syntax_error_solver),
+        )
+    }
+
+    /// Determines the symbols expected after a token prefix.
+    ///
+    /// Every derivation compatible with the prefix is taken into account, so the result is the
+    /// union of the symbols expected by each of them. An empty result is returned if the prefix
+    /// can not be derived from the grammar.
+    pub fn get_expected_symbols<TIter: Iterator<Item = Token<TLex, TSyntax>>>(
+        &self,
+        tokens_iterator: TIter,
+    ) -> ExpectedSymbols<TSyntax> {
+        let initial_frame = CompletionFrame::Symbol(self.grammar.get_initial_symbol().clone());
+        let mut completion_states = vec![CompletionState::new(vec![], vec![initial_frame])];
+
+        for token in tokens_iterator {
+            completion_states =
+                self.inner_get_expected_symbols_consume_token(completion_states, &token.t_type);
+        }
+
+        let mut non_terminal_symbol_ids = SymbolSet::new();
+        let mut terminal_symbol_ids = SymbolSet::new();
+
+        for completion_state in completion_states {
+            self.inner_get_expected_symbols_process_frames(
+                &completion_state.frames,
+                &mut non_terminal_symbol_ids,
+                &mut terminal_symbol_ids,
+            );
+        }
+
+        ExpectedSymbols::new(
+            self.inner_symbol_set_to_symbols(&non_terminal_symbol_ids),
+            self.inner_symbol_set_to_symbols(&terminal_symbol_ids),
+        )
+    }
+
+    /// Parses an input enumerating every distinct tree up to `limit` trees.
+    ///
+    /// Trees are enumerated with the backtracking of `parse_from_tokens`: once a derivation of the
+    /// initial symbol is found, the parse backtracks as if it had failed, until every candidate
+    /// production is explored or `limit` trees are found. Parse limits are taken into account the
+    /// same way, the syntax error solver is not used.
+    pub fn parse_all<TIter: Iterator<Item = Token<TLex, TSyntax>>>(
+        &self,
+        tokens_iterator: TIter,
+        limit: usize,
+    ) -> ParseAllResult<TLex, TSyntax> {
+        let tokens_vector = Self::iterator_to_vec(tokens_iterator);
+        let parse_all_trees = ParseAllTrees::new(limit);
+
+        match self.inner_parse_tokens_vector(&tokens_vector, None, Some(&parse_all_trees), None) {
+            Ok(ParseSymbolResult::Abort(parse_abort)) => ParseAllResult::Abort(parse_abort),
+            Ok(_) => ParseAllResult::Ok(parse_all_trees.trees.into_inner()),
+            Err(invalid_input) => ParseAllResult::InvalidInput(invalid_input),
+        }
     }
 
     pub fn parse_from_tokens<TIter: Iterator<Item = Token<TLex, TSyntax>>>(
         &self,
         tokens_iterator: TIter,
     ) -> ParseResult<TLex, TSyntax> {
+        self.inner_parse_from_tokens_with_observer(tokens_iterator, None)
+    }
+
+    /// Parses an input notifying every parse event to an observer.
+    pub fn parse_from_tokens_with_observer<TIter: Iterator<Item = Token<TLex, TSyntax>>>(
+        &self,
+        tokens_iterator: TIter,
+        observer: &mut dyn ParseObserver<TLex, TSyntax>,
+    ) -> ParseResult<TLex, TSyntax> {
+        self.inner_parse_from_tokens_with_observer(tokens_iterator, Some(observer))
+    }
+
+    /// Parses several inputs concurrently.
+    ///
+    /// Inputs are split among the available threads. Results are returned in the order of the
+    /// inputs.
+    pub fn parse_many(
+        &self,
+        inputs: Vec<Vec<Token<TLex, TSyntax>>>,
+    ) -> Vec<ParseResult<TLex, TSyntax>>
+    where
+        Self: Sync,
+        TLex: Send,
+        TSyntax: Send,
+    {
+        if inputs.is_empty() {
+            return vec![];
+        }
+
+        let threads_count = thread::available_parallelism()
+            .map_or(1, |parallelism| parallelism.get())
+            .min(inputs.len());
+        let chunk_size = inputs.len().div_ceil(threads_count);
+
+        let mut inputs_iterator = inputs.into_iter();
+        let mut chunks: Vec<Vec<Vec<Token<TLex, TSyntax>>>> = vec![];
+
+        loop {
+            let chunk: Vec<Vec<Token<TLex, TSyntax>>> =
+                inputs_iterator.by_ref().take(chunk_size).collect();
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            chunks.push(chunk);
+        }
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .into_iter()
+                .map(|chunk| {
+                    scope.spawn(move || -> Vec<ParseResult<TLex, TSyntax>> {
+                        chunk
+                            .into_iter()
+                            .map(|tokens| self.parse_from_tokens(tokens.into_iter()))
+                            .collect()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect()
+        })
+    }
+
+    /// Parses an input recovering from every syntax error.
+    ///
+    /// Symbols the syntax error solver can not fix are fixed by skipping tokens until one which
+    /// can follow them, or until the end of the input, so the parse goes on after every error and
+    /// a tree is always built. Tokens following the derivation of the initial symbol are skipped
+    /// as well. The diagnostics of the fixes are returned in order of appearance, skipped regions
+    /// being described by their farthest failure.
+    pub fn parse_from_tokens_with_recovery<TIter: Iterator<Item = Token<TLex, TSyntax>>>(
+        &self,
+        tokens_iterator: TIter,
+    ) -> RecoveryParseResult<TLex, TSyntax> {
         let tokens_vector = Self::iterator_to_vec(tokens_iterator);
+        let recovery_diagnostics: RecoveryDiagnostics<TLex, TSyntax> = RefCell::new(HashMap::new());
 
-        if tokens_vector.len() == 0 {
-            panic!("Expecting at least one token!")
+        let parse_result = match self.inner_parse_tokens_vector(
+            &tokens_vector,
+            None,
+            None,
+            Some(&recovery_diagnostics),
+        ) {
+            Ok(parse_symbol_result) => Self::inner_parse_from_tokens_skip_trailing_tokens(
+                &recovery_diagnostics,
+                &tokens_vector,
+                parse_symbol_result,
+            ),
+            Err(invalid_input) => ParseResult::InvalidInput(invalid_input),
+        };
+
+        let diagnostics = match &parse_result {
+            ParseResult::Err(failed_symbol) => vec![Diagnostic::from_failed_symbol(
+                &self.first_follow_symbols,
+                failed_symbol,
+                &tokens_vector,
+            )],
+            ParseResult::Fix(fixed_symbol) => Diagnostic::from_fixed_symbol_with_gap_diagnostics(
+                &self.first_follow_symbols,
+                fixed_symbol,
+                &tokens_vector,
+                recovery_diagnostics.into_inner(),
+            ),
+            _ => vec![],
+        };
+
+        RecoveryParseResult::new(diagnostics, parse_result)
+    }
+
+    fn inner_parse_from_tokens_with_observer<'o, TIter: Iterator<Item = Token<TLex, TSyntax>>>(
+        &'o self,
+        tokens_iterator: TIter,
+        observer: Option<&'o mut dyn ParseObserver<TLex, TSyntax>>,
+    ) -> ParseResult<TLex, TSyntax> {
+        let tokens_vector = Self::iterator_to_vec(tokens_iterator);
+
+        match self.inner_parse_tokens_vector(&tokens_vector, observer, None, None) {
+            Ok(parse_symbol_result) => {
+                Self::parse_symbol_result_to_parse_result(parse_symbol_result)
+            }
+            Err(invalid_input) => ParseResult::InvalidInput(invalid_input),
         }
+    }
+
+    /// Skips the tokens following the derivation of the initial symbol, recording the diagnostic
+    /// of the first of them.
+    fn inner_parse_from_tokens_skip_trailing_tokens(
+        recovery_diagnostics: &RecoveryDiagnostics<TLex, TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        parse_symbol_result: ParseSymbolResult<TLex, TSyntax>,
+    ) -> ParseResult<TLex, TSyntax> {
+        let (final_token_position, mut fixed_symbol) = match parse_symbol_result {
+            ParseSymbolResult::Ok(parsing_state)
+                if parsing_state.final_token_position < tokens.len() =>
+            {
+                let node = parsing_state.node;
+
+                (
+                    parsing_state.final_token_position,
+                    FixedSymbol::new(
+                        FixedProduction::new(
+                            node.child_nodes
+                                .into_iter()
+                                .map(FixedProductionPart::Ok)
+                                .collect(),
+                        ),
+                        node.token.t_type,
+                    ),
+                )
+            }
+            ParseSymbolResult::Fix(fixed_state)
+                if fixed_state.final_token_position < tokens.len() =>
+            {
+                (fixed_state.final_token_position, fixed_state.fixed_symbol)
+            }
+            parse_symbol_result => {
+                return Self::parse_symbol_result_to_parse_result(parse_symbol_result)
+            }
+        };
+
+        recovery_diagnostics.borrow_mut().insert(
+            (final_token_position, tokens.len()),
+            Diagnostic::expecting_end_of_input(
+                tokens[final_token_position].clone(),
+                final_token_position,
+            ),
+        );
+
+        fixed_symbol
+            .fixed_production
+            .fixed_parts
+            .push(FixedProductionPart::Gap(FixGap::new(
+                tokens[final_token_position..].to_vec(),
+                vec![],
+            )));
+
+        ParseResult::Fix(fixed_symbol)
+    }
+
+    fn inner_parse_tokens_vector<'o>(
+        &'o self,
+        tokens_vector: &Vec<Token<TLex, TSyntax>>,
+        observer: Option<&'o mut dyn ParseObserver<TLex, TSyntax>>,
+        parse_all_trees: Option<&'o ParseAllTrees<TLex, TSyntax>>,
+        recovery_diagnostics: Option<&'o RecoveryDiagnostics<TLex, TSyntax>>,
+    ) -> Result<ParseSymbolResult<'o, TLex, TSyntax>, InvalidInput<TSyntax>> {
+        let token_ids = self.inner_check_input(tokens_vector)?;
 
         let symbol_to_derive = self.grammar.get_initial_symbol();
+        let symbol_to_derive_id = self.grammar.get_symbol_id(symbol_to_derive).unwrap();
         let token_position: usize = 0;
-        let first_token = tokens_vector.get(token_position).unwrap();
+
+        // Fixes are not explored while enumerating every tree
+        let is_single_path = parse_all_trees.is_none();
+
+        let context = ParseContext::new(
+            &self.limits,
+            observer,
+            parse_all_trees,
+            recovery_diagnostics,
+            token_ids,
+        );
+
+        if context.is_cancelled() {
+            return Ok(ParseSymbolResult::Abort(ParseAbort::Cancelled));
+        }
 
         let first_token_productions =
-            self.inner_get_token_productions(symbol_to_derive, &first_token.t_type);
+            self.inner_get_position_productions(&context, symbol_to_derive_id, token_position);
 
         let first_token_productions_iter = first_token_productions.into_iter();
 
         let parse_symbol_result = self.inner_parse_from_tokens(
+            &context,
             symbol_to_derive,
-            &tokens_vector,
+            tokens_vector,
             token_position,
             first_token_productions_iter,
-            true,
+            is_single_path,
         );
 
-        Self::parse_symbol_result_to_parse_result(parse_symbol_result)
+        Ok(parse_symbol_result)
+    }
+
+    /// Sets the limits of every subsequent parse.
+    ///
+    /// Parses are unlimited by default.
+    pub fn set_limits(&mut self, limits: ParseLimits) {
+        self.limits = limits;
     }
 
     fn from(
-        grammar: &'a ContextFreeGrammar<TSyntax>,
+        grammar: GrammarHandle<'a, TSyntax>,
         first_follow_symbols: &FirstFollowSymbols<TSyntax>,
         syntax_error_solver: Option<TSolver>,
+    ) -> Self {
+        let transitions = RecursiveDescentParserTransitions::from(&grammar, first_follow_symbols);
+
+        Self::from_tables(
+            grammar,
+            first_follow_symbols.clone(),
+            transitions,
+            syntax_error_solver,
+        )
+    }
+
+    fn from_precomputed_tables<TEncoder: SymbolEncoder<TSyntax>>(
+        grammar: &'a ContextFreeGrammar<TSyntax>,
+        encoder: &TEncoder,
+        serialized_tables: &str,
+        syntax_error_solver: Option<TSolver>,
+    ) -> Result<Self, PrecomputedParseTablesError> {
+        let precomputed_parse_tables =
+            PrecomputedParseTables::deserialize(grammar, encoder, serialized_tables)?;
+
+        let (first_follow_symbols, transitions) = precomputed_parse_tables.into_parts();
+
+        Ok(Self::from_tables(
+            GrammarHandle::Borrowed(grammar),
+            first_follow_symbols,
+            transitions,
+            syntax_error_solver,
+        ))
+    }
+
+    fn from_tables(
+        grammar: GrammarHandle<'a, TSyntax>,
+        first_follow_symbols: FirstFollowSymbols<TSyntax>,
+        transitions: RecursiveDescentParserTransitions<TSyntax>,
+        syntax_error_solver: Option<TSolver>,
     ) -> Self {
         Self {
+            first_follow_symbols,
             grammar,
+            limits: ParseLimits::default(),
             marker: PhantomData,
             syntax_error_solver,
-            transitions: RecursiveDescentParserTransitions::from(grammar, first_follow_symbols),
+            transitions,
         }
     }
 
     fn build_token_failed_symbol(
         production_symbol: &TSyntax,
+        token_position: usize,
     ) -> ParseSymbolResult<'a, TLex, TSyntax> {
-        let failed_symbol = FailedSymbol::new(vec![], production_symbol.clone());
+        let failed_symbol = FailedSymbol::new(vec![], production_symbol.clone(), token_position);
         ParseSymbolResult::Err(failed_symbol)
     }
 
+    fn inner_get_expected_symbols_consume_token(
+        &self,
+        completion_states: Vec<CompletionState<TSyntax>>,
+        token_type: &TSyntax,
+    ) -> Vec<CompletionState<TSyntax>> {
+        let mut pending_states: Vec<CompletionState<TSyntax>> = completion_states;
+        let mut next_states: Vec<CompletionState<TSyntax>> = vec![];
+        let mut next_states_frames: HashSet<Vec<CompletionFrame<TSyntax>>> = HashSet::new();
+
+        while let Some(mut completion_state) = pending_states.pop() {
+            let frame = match completion_state.frames.pop() {
+                Some(frame) => frame,
+                None => continue,
+            };
+
+            match frame {
+                CompletionFrame::Exit(symbol) => {
+                    // A completely derived non terminal symbol can be derived again
+                    if let Some(symbol_index) = completion_state
+                        .derived_symbols
+                        .iter()
+                        .rposition(|derived_symbol| derived_symbol.eq(&symbol))
+                    {
+                        completion_state.derived_symbols.remove(symbol_index);
+                    }
+
+                    pending_states.push(completion_state);
+                }
+                CompletionFrame::Symbol(symbol) => {
+                    if self.grammar.is_non_terminal(&symbol) {
+                        self.inner_get_expected_symbols_expand_non_terminal(
+                            &mut pending_states,
+                            completion_state,
+                            symbol,
+                            token_type,
+                        );
+                    } else if self.grammar.get_epsilon_symbol().eq(&symbol) {
+                        pending_states.push(completion_state);
+                    } else if symbol.eq(token_type) {
+                        let mut frames = completion_state.frames;
+
+                        // Non terminal symbols with no pending symbols are completely derived
+                        while let Some(CompletionFrame::Exit(_)) = frames.last() {
+                            frames.pop();
+                        }
+
+                        if next_states_frames.insert(frames.clone()) {
+                            next_states.push(CompletionState::new(vec![], frames));
+                        }
+                    }
+                }
+            }
+        }
+
+        next_states
+    }
+
+    fn inner_get_expected_symbols_expand_non_terminal(
+        &self,
+        pending_states: &mut Vec<CompletionState<TSyntax>>,
+        completion_state: CompletionState<TSyntax>,
+        symbol: TSyntax,
+        token_type: &TSyntax,
+    ) {
+        // A non terminal symbol derived twice without consuming any token is a left recursion
+        if completion_state.derived_symbols.contains(&symbol) {
+            return;
+        }
+
+        for production_output in self.inner_get_token_productions(&symbol, token_type) {
+            let mut derived_symbols = completion_state.derived_symbols.clone();
+            derived_symbols.push(symbol.clone());
+
+            let mut frames = completion_state.frames.clone();
+            frames.push(CompletionFrame::Exit(symbol.clone()));
+            frames.extend(
+                production_output
+                    .iter()
+                    .rev()
+                    .map(|output_symbol| CompletionFrame::Symbol(output_symbol.clone())),
+            );
+
+            pending_states.push(CompletionState::new(derived_symbols, frames));
+        }
+    }
+
+    fn inner_get_expected_symbols_process_frames(
+        &self,
+        frames: &[CompletionFrame<TSyntax>],
+        non_terminal_symbol_ids: &mut SymbolSet,
+        terminal_symbol_ids: &mut SymbolSet,
+    ) {
+        let mut is_next_frame_reachable: bool = true;
+
+        for frame in frames.iter().rev() {
+            match frame {
+                CompletionFrame::Exit(symbol) => {
+                    self.inner_insert_symbol(non_terminal_symbol_ids, symbol);
+                }
+                CompletionFrame::Symbol(symbol) => {
+                    if is_next_frame_reachable {
+                        is_next_frame_reachable = self.inner_get_expected_symbols_process_symbol(
+                            symbol,
+                            non_terminal_symbol_ids,
+                            terminal_symbol_ids,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn inner_get_expected_symbols_process_symbol(
+        &self,
+        symbol: &TSyntax,
+        non_terminal_symbol_ids: &mut SymbolSet,
+        terminal_symbol_ids: &mut SymbolSet,
+    ) -> bool {
+        let epsilon_symbol = self.grammar.get_epsilon_symbol();
+
+        if self.grammar.is_non_terminal(symbol) {
+            self.inner_insert_symbol(non_terminal_symbol_ids, symbol);
+
+            match self.first_follow_symbols.get_first_symbols(symbol) {
+                Some(first_symbols) => {
+                    first_symbols
+                        .iter()
+                        .filter(|first_symbol| epsilon_symbol.ne(*first_symbol))
+                        .for_each(|first_symbol| {
+                            self.inner_insert_symbol(terminal_symbol_ids, first_symbol);
+                        });
+
+                    first_symbols.contains(epsilon_symbol)
+                }
+                None => false,
+            }
+        } else if epsilon_symbol.eq(symbol) {
+            true
+        } else {
+            self.inner_insert_symbol(terminal_symbol_ids, symbol);
+
+            false
+        }
+    }
+
+    /// Checks the input, resolving the symbol ID of every token type.
+    ///
+    /// Tokens whose type is not a terminal symbol of the grammar are given an ID no transition
+    /// matches, so they fail once the parse reaches them.
+    fn inner_check_input(
+        &self,
+        tokens: &[Token<TLex, TSyntax>],
+    ) -> Result<Vec<u32>, InvalidInput<TSyntax>> {
+        let initial_symbol = self.grammar.get_initial_symbol();
+
+        if tokens.is_empty() {
+            return Err(InvalidInput::EmptyInput);
+        }
+
+        if !self.grammar.is_non_terminal(initial_symbol) {
+            return Err(InvalidInput::UnknownSymbol(initial_symbol.clone()));
+        }
+
+        let epsilon_symbol_id = self.grammar.get_epsilon_symbol_id();
+
+        Ok(tokens
+            .iter()
+            .map(|token| {
+                self.grammar
+                    .get_symbol_id(&token.t_type)
+                    .filter(|symbol_id| {
+                        self.grammar.is_terminal_id(*symbol_id) && *symbol_id != epsilon_symbol_id
+                    })
+                    .unwrap_or(UNKNOWN_TOKEN_ID)
+            })
+            .collect())
+    }
+
+    fn inner_get_position_productions(
+        &self,
+        context: &ParseContext<TLex, TSyntax>,
+        symbol_to_derive_id: u32,
+        token_position: usize,
+    ) -> Vec<&Vec<TSyntax>> {
+        context
+            .token_ids
+            .get(token_position)
+            .and_then(|token_id| {
+                self.transitions
+                    .get_productions_by_id(symbol_to_derive_id, *token_id)
+            })
+            .map_or(vec![], |productions| {
+                productions
+                    .iter()
+                    .map(|production| &production.output)
+                    .collect()
+            })
+    }
+
+    fn inner_insert_symbol(&self, symbol_ids: &mut SymbolSet, symbol: &TSyntax) {
+        if let Some(symbol_id) = self.grammar.get_symbol_id(symbol) {
+            symbol_ids.insert(symbol_id);
+        }
+    }
+
+    /// Symbols of a set of grammar symbol IDs, in order of appearance in the grammar.
+    fn inner_symbol_set_to_symbols(&self, symbol_ids: &SymbolSet) -> Vec<TSyntax> {
+        let symbol_table = self.grammar.get_symbol_table();
+
+        symbol_ids
+            .iter()
+            .map(|symbol_id| symbol_table.get_symbol(symbol_id).clone())
+            .collect()
+    }
+
+    /// Symbol ID of a symbol, as long as it is a non terminal symbol.
+    fn inner_get_non_terminal_symbol_id(&self, symbol: &TSyntax) -> Option<u32> {
+        self.grammar
+            .get_symbol_id(symbol)
+            .filter(|symbol_id| self.grammar.is_non_terminal_id(*symbol_id))
+    }
+
     fn inner_get_token_productions(
         &self,
         symbol_to_derive: &TSyntax,
@@ -185,6 +944,50 @@ impl<
 
     fn inner_parse_from_tokens(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
+        symbol_to_derive: &TSyntax,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        production_outputs: std::vec::IntoIter<&'a Vec<TSyntax>>,
+        is_single_path: bool,
+    ) -> ParseSymbolResult<'a, TLex, TSyntax> {
+        if let Err(parse_abort) = context.enter_symbol() {
+            return ParseSymbolResult::Abort(parse_abort);
+        }
+
+        context
+            .notify(|observer| observer.on_enter_non_terminal(symbol_to_derive, tokens_position));
+        context
+            .non_terminal_stack
+            .borrow_mut()
+            .push(symbol_to_derive.clone());
+
+        let parse_symbol_result = self.inner_parse_from_tokens_symbol(
+            context,
+            symbol_to_derive,
+            tokens,
+            tokens_position,
+            production_outputs,
+            is_single_path,
+        );
+
+        let is_success = matches!(
+            parse_symbol_result,
+            ParseSymbolResult::Ok(_) | ParseSymbolResult::Fix(_)
+        );
+
+        context.non_terminal_stack.borrow_mut().pop();
+        context.notify(|observer| {
+            observer.on_exit_non_terminal(symbol_to_derive, tokens_position, is_success)
+        });
+        context.exit_symbol();
+
+        parse_symbol_result
+    }
+
+    fn inner_parse_from_tokens_symbol(
+        &self,
+        context: &ParseContext<TLex, TSyntax>,
         symbol_to_derive: &TSyntax,
         tokens: &Vec<Token<TLex, TSyntax>>,
         tokens_position: usize,
@@ -193,6 +996,7 @@ impl<
     ) -> ParseSymbolResult<'a, TLex, TSyntax> {
         let child_is_single_path = Self::is_child_single_path(is_single_path, &production_outputs);
         let parse_productions_result = self.inner_parse_from_tokens_try_parse_productions(
+            context,
             symbol_to_derive,
             tokens,
             tokens_position,
@@ -203,27 +1007,108 @@ impl<
         match parse_productions_result {
             Ok(parse_symbol_result) => parse_symbol_result,
             Err(failed_productions) => {
-                let fix_option = self.inner_parse_from_tokens_try_fix_productions(
-                    tokens,
-                    tokens_position,
-                    is_single_path,
-                    child_is_single_path,
-                    &failed_productions,
-                );
-
-                Self::inner_parse_from_tokens_fix_option_to_parse_symbol_result(
-                    failed_productions,
-                    fix_option,
-                    symbol_to_derive,
-                )
+                let fix_option = if is_single_path {
+                    self.inner_parse_from_tokens_try_fix_productions(
+                        context,
+                        symbol_to_derive,
+                        tokens,
+                        tokens_position,
+                        child_is_single_path,
+                        &failed_productions,
+                    )
+                } else {
+                    None
+                };
+
+                match (fix_option, context.recovery_diagnostics) {
+                    (None, Some(recovery_diagnostics)) if is_single_path => self
+                        .inner_parse_from_tokens_recover(
+                            context,
+                            recovery_diagnostics,
+                            tokens,
+                            child_is_single_path,
+                            FailedSymbol::new(
+                                failed_productions,
+                                symbol_to_derive.clone(),
+                                tokens_position,
+                            ),
+                        ),
+                    (fix_option, _) => {
+                        Self::inner_parse_from_tokens_fix_option_to_parse_symbol_result(
+                            failed_productions,
+                            fix_option,
+                            symbol_to_derive,
+                            tokens_position,
+                        )
+                    }
+                }
             }
         }
     }
 
+    /// Fixes a symbol the syntax error solver could not fix by skipping tokens, recording the
+    /// diagnostic of its farthest failure for the skipped region.
+    fn inner_parse_from_tokens_recover(
+        &self,
+        context: &ParseContext<TLex, TSyntax>,
+        recovery_diagnostics: &RecoveryDiagnostics<TLex, TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        child_is_single_path: bool,
+        failed_symbol: FailedSymbol<TLex, TSyntax>,
+    ) -> ParseSymbolResult<'a, TLex, TSyntax> {
+        let fix_option = {
+            let non_terminal_stack = context.non_terminal_stack.borrow();
+            let recovery_context = RecoveryContext::new(
+                &self.first_follow_symbols,
+                &self.grammar,
+                &non_terminal_stack,
+                &self.transitions,
+            );
+
+            Self::inner_parse_from_tokens_try_fix_productions_with_solver(
+                &PanicModeSolver::synchronizing_on_end_of_input(),
+                &recovery_context,
+                tokens,
+                failed_symbol.token_position,
+                child_is_single_path,
+                &failed_symbol.failed_productions,
+            )
+        };
+
+        let fix_result = match fix_option {
+            Some(fix_result) => fix_result,
+            None => return ParseSymbolResult::Err(failed_symbol),
+        };
+
+        if let Some(FixedProductionPart::Gap(fix_gap)) = fix_result.production.fixed_parts.last() {
+            let gap_bounds = (
+                fix_result.final_token_position - fix_gap.skipped_tokens.len(),
+                fix_result.final_token_position,
+            );
+
+            recovery_diagnostics
+                .borrow_mut()
+                .entry(gap_bounds)
+                .or_insert_with(|| {
+                    Diagnostic::from_failed_symbol(
+                        &self.first_follow_symbols,
+                        &failed_symbol,
+                        tokens,
+                    )
+                });
+        }
+
+        ParseSymbolResult::Fix(FixedState::new(
+            fix_result.final_token_position,
+            FixedSymbol::new(fix_result.production, failed_symbol.symbol_to_derive),
+        ))
+    }
+
     fn inner_parse_from_tokens_fix_option_to_parse_symbol_result(
         failed_productions: Vec<FailedProduction<TLex, TSyntax>>,
         fix_option: Option<FixResult<TLex, TSyntax>>,
         symbol_to_derive: &TSyntax,
+        tokens_position: usize,
     ) -> ParseSymbolResult<'a, TLex, TSyntax> {
         match fix_option {
             Some(fix_result) => {
@@ -237,43 +1122,77 @@ impl<
             None => ParseSymbolResult::Err(FailedSymbol::new(
                 failed_productions,
                 symbol_to_derive.clone(),
+                tokens_position,
             )),
         }
     }
 
     fn inner_parse_from_tokens_try_fix_productions(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
+        symbol_to_derive: &TSyntax,
         tokens: &Vec<Token<TLex, TSyntax>>,
         tokens_position: usize,
-        is_single_path: bool,
         child_is_single_path: bool,
         failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
     ) -> Option<FixResult<TLex, TSyntax>> {
-        if self.syntax_error_solver.is_some() && is_single_path {
-            let syntax_error_solver = self.syntax_error_solver.as_ref().unwrap();
-
-            if child_is_single_path {
-                let failed_production = failed_productions.get(0).unwrap();
+        match self.syntax_error_solver.as_ref() {
+            Some(syntax_error_solver) => {
+                context.notify(|observer| {
+                    observer.on_invoke_solver(symbol_to_derive, tokens_position)
+                });
+
+                let non_terminal_stack = context.non_terminal_stack.borrow();
+                let recovery_context = RecoveryContext::new(
+                    &self.first_follow_symbols,
+                    &self.grammar,
+                    &non_terminal_stack,
+                    &self.transitions,
+                );
 
-                syntax_error_solver.fix_failed_production(
-                    &tokens,
-                    tokens_position,
-                    failed_production,
-                )
-            } else {
-                syntax_error_solver.fix_failed_productions(
-                    &tokens,
+                Self::inner_parse_from_tokens_try_fix_productions_with_solver(
+                    syntax_error_solver,
+                    &recovery_context,
+                    tokens,
                     tokens_position,
+                    child_is_single_path,
                     failed_productions,
                 )
             }
+            None => None,
+        }
+    }
+
+    fn inner_parse_from_tokens_try_fix_productions_with_solver(
+        syntax_error_solver: &impl SyntaxErrorSolver<TLex, TSyntax>,
+        recovery_context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        child_is_single_path: bool,
+        failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        if child_is_single_path {
+            let failed_production = failed_productions.first().unwrap();
+
+            syntax_error_solver.fix_failed_production(
+                recovery_context,
+                tokens,
+                tokens_position,
+                failed_production,
+            )
         } else {
-            None
+            syntax_error_solver.fix_failed_productions(
+                recovery_context,
+                tokens,
+                tokens_position,
+                failed_productions,
+            )
         }
     }
 
     fn inner_parse_from_tokens_try_parse_productions(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
         symbol_to_derive: &TSyntax,
         tokens: &Vec<Token<TLex, TSyntax>>,
         tokens_position: usize,
@@ -285,7 +1204,13 @@ impl<
 
         for production_output in &mut production_outputs {
             let mut current_token_position = tokens_position;
+
+            context.notify(|observer| {
+                observer.on_try_production(symbol_to_derive, production_output, tokens_position)
+            });
+
             let parse_production_result = self.inner_parse_from_tokens_production(
+                context,
                 symbol_to_derive,
                 tokens,
                 &mut current_token_position,
@@ -303,6 +1228,14 @@ impl<
                     )));
                 }
                 ParseProductionResult::Err(failed_production) => {
+                    context.notify(|observer| {
+                        observer.on_reject_production(
+                            symbol_to_derive,
+                            production_output,
+                            tokens_position,
+                        )
+                    });
+
                     failed_productions.push(failed_production);
                 }
                 ParseProductionResult::Fix(fixed_production) => {
@@ -311,6 +1244,9 @@ impl<
 
                     return Ok(ParseSymbolResult::Fix(fixed_state));
                 }
+                ParseProductionResult::Abort(parse_abort) => {
+                    return Ok(ParseSymbolResult::Abort(parse_abort));
+                }
             }
         }
 
@@ -319,18 +1255,59 @@ impl<
 
     fn inner_parse_from_tokens_production(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
         symbol_to_derive: &TSyntax,
         tokens: &Vec<Token<TLex, TSyntax>>,
         current_token_position: &mut usize,
-        production_output: &'a Vec<TSyntax>,
+        production_output: &'a [TSyntax],
         is_single_path: bool,
     ) -> ParseProductionResult<TLex, TSyntax> {
         let mut states: Vec<State<'_, TLex, TSyntax, std::vec::IntoIter<&Vec<TSyntax>>>> =
             Vec::new();
 
-        while states.len() < production_output.len() {
-            let production_symbol = production_output.get(states.len()).unwrap();
+        loop {
+            let production_symbol = match production_output.get(states.len()) {
+                Some(production_symbol) => production_symbol,
+                None if self.inner_parse_all_accept_derivation(
+                    context,
+                    symbol_to_derive,
+                    &states,
+                    tokens,
+                    *current_token_position,
+                ) =>
+                {
+                    break
+                }
+                None => {
+                    // The derivation is recorded, so the next one is searched as if it had failed
+                    let failed_symbol = FailedSymbol::new(
+                        vec![],
+                        production_output.last().unwrap().clone(),
+                        *current_token_position,
+                    );
+
+                    match self.inner_parse_from_tokens_production_backtrack(
+                        context,
+                        &mut states,
+                        tokens,
+                        current_token_position,
+                        is_single_path,
+                    ) {
+                        Err(parse_abort) => return ParseProductionResult::Abort(parse_abort),
+                        Ok(Some(production_parsing_states)) => {
+                            return Self::inner_parse_from_tokens_production_build_failed_symbol(
+                                failed_symbol,
+                                production_output,
+                                production_parsing_states,
+                            );
+                        }
+                        Ok(None) => continue,
+                    }
+                }
+            };
+
             let state_option = self.inner_parse_from_tokens_production_symbol(
+                context,
                 production_symbol,
                 tokens,
                 *current_token_position,
@@ -343,35 +1320,107 @@ impl<
                     states.push(State::Parsing(parsing_state));
                 }
                 ParseSymbolResult::Err(failed_symbol) => {
-                    match self.inner_parse_pop_states(&mut states, tokens, is_single_path) {
-                        Some(production_parsing_states) => {
+                    match self.inner_parse_from_tokens_production_backtrack(
+                        context,
+                        &mut states,
+                        tokens,
+                        current_token_position,
+                        is_single_path,
+                    ) {
+                        Err(parse_abort) => return ParseProductionResult::Abort(parse_abort),
+                        Ok(Some(production_parsing_states)) => {
                             return Self::inner_parse_from_tokens_production_build_failed_symbol(
                                 failed_symbol,
                                 production_output,
                                 production_parsing_states,
                             );
                         }
-                        None => {
-                            *current_token_position = match states.get(states.len() - 1).unwrap() {
-                                State::Parsing(parsing_state) => parsing_state.final_token_position,
-                                State::Fixed(fixed_state) => fixed_state.final_token_position,
-                            }
-                        }
+                        Ok(None) => {}
                     }
                 }
                 ParseSymbolResult::Fix(fixed_state) => {
                     *current_token_position = fixed_state.final_token_position;
                     states.push(State::Fixed(fixed_state));
                 }
+                ParseSymbolResult::Abort(parse_abort) => {
+                    return ParseProductionResult::Abort(parse_abort);
+                }
             }
         }
 
         Self::inner_parse_from_tokens_production_build_node(symbol_to_derive, states)
     }
 
+    /// Backtracks to the last state of a production which can be derived again, updating the
+    /// token position. Returns the states of the production if none can.
+    #[allow(clippy::type_complexity)]
+    fn inner_parse_from_tokens_production_backtrack(
+        &self,
+        context: &ParseContext<TLex, TSyntax>,
+        states: &mut Vec<State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        current_token_position: &mut usize,
+        is_single_path: bool,
+    ) -> Result<
+        Option<Vec<State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>>>,
+        ParseAbort,
+    > {
+        let production_states_option =
+            self.inner_parse_pop_states(context, states, tokens, is_single_path)?;
+
+        if production_states_option.is_none() {
+            *current_token_position = match states.last().unwrap() {
+                State::Parsing(parsing_state) => parsing_state.final_token_position,
+                State::Fixed(fixed_state) => fixed_state.final_token_position,
+            }
+        }
+
+        Ok(production_states_option)
+    }
+
+    /// Whether a complete derivation of a production ends the parse.
+    ///
+    /// Parses enumerating every tree record the derivations of the initial symbol spanning the
+    /// whole input, rejecting them until the limit of trees is reached. Any other derivation is
+    /// accepted.
+    fn inner_parse_all_accept_derivation<'b>(
+        &self,
+        context: &ParseContext<TLex, TSyntax>,
+        symbol_to_derive: &TSyntax,
+        production_states: &[State<'b, TLex, TSyntax, std::vec::IntoIter<&'b Vec<TSyntax>>>],
+        tokens: &[Token<TLex, TSyntax>],
+        token_position: usize,
+    ) -> bool {
+        let parse_all_trees = match context.parse_all_trees {
+            Some(parse_all_trees) if context.depth.get() == 1 => parse_all_trees,
+            _ => return true,
+        };
+
+        let mut trees = parse_all_trees.trees.borrow_mut();
+
+        if trees.len() < parse_all_trees.limit && token_position == tokens.len() {
+            let child_nodes: Vec<AbstractSyntaxNode<Token<TLex, TSyntax>>> = production_states
+                .iter()
+                .map(|state| match state {
+                    State::Parsing(parsing_state) => parsing_state.node.clone(),
+                    _ => panic!("Unexpected state!"),
+                })
+                .collect();
+
+            let node: AbstractSyntaxNode<Token<TLex, TSyntax>> =
+                AbstractSyntaxNode::new(child_nodes, Token::new(None, symbol_to_derive.clone()));
+
+            if !trees.iter().any(|tree| tree.root.eq(&node)) {
+                trees.push(AbstractSyntaxTree::new(node));
+            }
+        }
+
+        trees.len() >= parse_all_trees.limit
+    }
+
     fn inner_parse_from_tokens_production_build_failed_symbol<'b>(
         failed_symbol: FailedSymbol<TLex, TSyntax>,
-        production_output: &Vec<TSyntax>,
+        production_output: &[TSyntax],
         production_states: Vec<State<'b, TLex, TSyntax, std::vec::IntoIter<&'b Vec<TSyntax>>>>,
     ) -> ParseProductionResult<TLex, TSyntax> {
         let mut pending_symbols: Vec<TSyntax> = vec![];
@@ -435,19 +1484,19 @@ impl<
 
     fn inner_parse_from_tokens_production_non_terminal(
         &self,
-        production_symbol: &TSyntax,
+        context: &ParseContext<TLex, TSyntax>,
+        (production_symbol, production_symbol_id): (&TSyntax, u32),
         tokens: &Vec<Token<TLex, TSyntax>>,
         token_position: usize,
         is_single_path: bool,
-    ) -> ParseSymbolResult<TLex, TSyntax> {
-        let current_token_symbol = tokens.get(token_position).unwrap();
-
+    ) -> ParseSymbolResult<'_, TLex, TSyntax> {
         let token_productions =
-            self.inner_get_token_productions(production_symbol, &current_token_symbol.t_type);
+            self.inner_get_position_productions(context, production_symbol_id, token_position);
 
         let token_productions_iter = token_productions.into_iter();
 
         self.inner_parse_from_tokens(
+            context,
             production_symbol,
             tokens,
             token_position,
@@ -459,7 +1508,7 @@ impl<
     fn inner_parse_terminal_symbol(
         &self,
         production_symbol: &TSyntax,
-        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens: &[Token<TLex, TSyntax>],
         token_position: usize,
     ) -> ParseSymbolResult<'a, TLex, TSyntax> {
         if self.grammar.get_epsilon_symbol().eq(production_symbol) {
@@ -479,7 +1528,7 @@ impl<
 
     fn inner_parse_non_epsilon_terminal_symbol(
         production_symbol: &TSyntax,
-        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens: &[Token<TLex, TSyntax>],
         token_position: usize,
     ) -> ParseSymbolResult<'a, TLex, TSyntax> {
         let current_token_symbol_option = tokens.get(token_position);
@@ -501,44 +1550,67 @@ impl<
 
                     ParseSymbolResult::Ok(state)
                 } else {
-                    Self::build_token_failed_symbol(production_symbol)
+                    Self::build_token_failed_symbol(production_symbol, token_position)
                 }
             }
-            None => Self::build_token_failed_symbol(production_symbol),
+            None => Self::build_token_failed_symbol(production_symbol, token_position),
         }
     }
 
     fn inner_parse_from_tokens_production_symbol(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
         production_symbol: &TSyntax,
         tokens: &Vec<Token<TLex, TSyntax>>,
         token_position: usize,
         is_single_path: bool,
-    ) -> ParseSymbolResult<TLex, TSyntax> {
-        if self.grammar.is_non_terminal(production_symbol) {
+    ) -> ParseSymbolResult<'_, TLex, TSyntax> {
+        if let Err(parse_abort) = context.step() {
+            return ParseSymbolResult::Abort(parse_abort);
+        }
+
+        if let Some(production_symbol_id) = self.inner_get_non_terminal_symbol_id(production_symbol)
+        {
             self.inner_parse_from_tokens_production_non_terminal(
-                production_symbol,
+                context,
+                (production_symbol, production_symbol_id),
                 tokens,
                 token_position,
                 is_single_path,
             )
         } else {
-            self.inner_parse_terminal_symbol(production_symbol, tokens, token_position)
+            let parse_symbol_result =
+                self.inner_parse_terminal_symbol(production_symbol, tokens, token_position);
+
+            if let ParseSymbolResult::Ok(parsing_state) = &parse_symbol_result {
+                if parsing_state.final_token_position > token_position {
+                    context.notify(|observer| {
+                        observer.on_match_terminal(&parsing_state.node.token, token_position)
+                    });
+                }
+            }
+
+            parse_symbol_result
         }
     }
 
+    #[allow(clippy::type_complexity)]
     fn inner_parse_pop_states(
         &self,
+        context: &ParseContext<TLex, TSyntax>,
         states: &mut Vec<State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>>,
         tokens: &Vec<Token<TLex, TSyntax>>,
         is_single_path: bool,
-    ) -> Option<Vec<State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>>> {
+    ) -> Result<
+        Option<Vec<State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>>>,
+        ParseAbort,
+    > {
         let mut reversed_failed_symbols: Vec<
             State<'a, TLex, TSyntax, std::vec::IntoIter<&'a Vec<TSyntax>>>,
         > = vec![];
         let mut states_pop_success: bool = false;
 
-        while states.len() > 0 && !states_pop_success {
+        while !states.is_empty() && !states_pop_success {
             let last_state = states.pop().unwrap();
 
             match last_state {
@@ -546,10 +1618,16 @@ impl<
                     reversed_failed_symbols.push(State::Fixed(fixed_state));
                 }
                 State::Parsing(parsing_state) => {
-                    if parsing_state.prod_iter_option.is_some() {
-                        let productions_iterator = parsing_state.prod_iter_option.unwrap();
+                    if let Some(productions_iterator) = parsing_state.prod_iter_option {
+                        let symbol_to_derive = &parsing_state.node.token.t_type;
+                        let initial_token_position = parsing_state.initial_token_position;
+
+                        context.notify(|observer| {
+                            observer.on_backtrack(symbol_to_derive, initial_token_position)
+                        });
 
                         let state_option = self.inner_parse_from_tokens(
+                            context,
                             &parsing_state.node.token.t_type,
                             tokens,
                             parsing_state.initial_token_position,
@@ -562,7 +1640,16 @@ impl<
                                 states.push(State::Parsing(state));
                                 states_pop_success = true;
                             }
-                            _ => {}
+                            ParseSymbolResult::Abort(parse_abort) => return Err(parse_abort),
+                            _ => {
+                                // Keeps the previous derivation in the failed production
+                                reversed_failed_symbols.push(State::Parsing(ParsingState::new(
+                                    parsing_state.initial_token_position,
+                                    parsing_state.final_token_position,
+                                    None,
+                                    parsing_state.node,
+                                )));
+                            }
                         }
                     } else {
                         reversed_failed_symbols.push(State::Parsing(parsing_state));
@@ -572,10 +1659,10 @@ impl<
         }
 
         if states_pop_success {
-            None
+            Ok(None)
         } else {
             reversed_failed_symbols.reverse();
-            Some(reversed_failed_symbols)
+            Ok(Some(reversed_failed_symbols))
         }
     }
 
@@ -611,6 +1698,7 @@ impl<'a, TLex, TSyntax, TSolver: SyntaxErrorSolver<TLex, TSyntax>>
             }
             ParseSymbolResult::Err(failed_symbol) => ParseResult::Err(failed_symbol),
             ParseSymbolResult::Fix(fixing_state) => ParseResult::Fix(fixing_state.fixed_symbol),
+            ParseSymbolResult::Abort(parse_abort) => ParseResult::Abort(parse_abort),
         }
     }
 }
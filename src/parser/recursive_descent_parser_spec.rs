@@ -1,7 +1,9 @@
 #[cfg(test)]
+#[allow(clippy::unused_unit)]
 mod test {
     use crate::parser::failed_production::FailedProduction;
     use crate::parser::fix_result::FixResult;
+    use crate::parser::recovery_context::RecoveryContext;
     use crate::parser::syntax_error_solver::SyntaxErrorSolver;
     use crate::token::token::Token;
     use std::marker::PhantomData;
@@ -14,6 +16,7 @@ mod test {
     impl<TLex, TSyntax> SyntaxErrorSolver<TLex, TSyntax> for SyntaxErrorSolverMock<TLex, TSyntax> {
         fn fix_failed_production(
             &self,
+            _context: &RecoveryContext<TSyntax>,
             _tokens: &Vec<Token<TLex, TSyntax>>,
             _tokens_position: usize,
             _failed_production: &FailedProduction<TLex, TSyntax>,
@@ -23,6 +26,7 @@ mod test {
 
         fn fix_failed_productions(
             &self,
+            _context: &RecoveryContext<TSyntax>,
             _tokens: &Vec<Token<TLex, TSyntax>>,
             _tokens_position: usize,
             _failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
@@ -31,222 +35,961 @@ mod test {
         }
     }
 
-    mod parse_from_tokens {
-        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
-        use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
+    mod from_precomputed {
         use crate::grammar::context_free_grammar::ContextFreeGrammar;
         use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
-        use crate::parser::failed_production::FailedProduction;
-        use crate::parser::failed_symbol::FailedSymbol;
-        use crate::parser::fix_gap::FixGap;
-        use crate::parser::fixed_production::FixedProduction;
-        use crate::parser::fixed_production_part::FixedProductionPart;
-        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::debug_symbol_encoder::DebugSymbolEncoder;
         use crate::parser::parse_result::ParseResult;
-        use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+        use crate::parser::precomputed_parse_tables::PrecomputedParseTables;
+        use crate::parser::precomputed_parse_tables_error::PrecomputedParseTablesError;
         use crate::parser::recursive_descent_parser::RecursiveDescentParser;
         use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
         use crate::token::token::Token;
 
-        fn ast_equals<T: PartialEq>(
-            first_ast: &AbstractSyntaxTree<T>,
-            second_ast: &AbstractSyntaxTree<T>,
-        ) -> bool {
-            asn_equals(&first_ast.root, &second_ast.root)
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            Eof,
+            Epsilon,
+            S,
         }
 
-        fn asn_equals<T: PartialEq>(
-            first_asn: &AbstractSyntaxNode<T>,
-            second_asn: &AbstractSyntaxNode<T>,
-        ) -> bool {
-            if first_asn.token.ne(&second_asn.token) {
-                return false;
-            }
+        fn build_grammar(
+            a_production_output: Vec<DummySyntaxTokenTest>,
+        ) -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(DummySyntaxTokenTest::A, a_production_output),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::Epsilon],
+                ),
+            ];
 
-            if first_asn
-                .child_nodes
-                .len()
-                .ne(&second_asn.child_nodes.len())
-            {
-                return false;
-            }
+            ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            )
+        }
 
-            for i in 0..first_asn.child_nodes.len() {
-                if !asn_equals(
-                    first_asn.child_nodes.get(i).unwrap(),
-                    second_asn.child_nodes.get(i).unwrap(),
-                ) {
-                    return false;
-                }
-            }
+        #[test]
+        fn it_parses_with_precomputed_tables() -> () {
+            let grammar = build_grammar(vec![
+                DummySyntaxTokenTest::ATerminal,
+                DummySyntaxTokenTest::A,
+            ]);
 
-            true
-        }
+            let serialized_tables = PrecomputedParseTables::from_grammar(&grammar)
+                .serialize(&grammar, &DebugSymbolEncoder);
 
-        fn failed_symbols_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_failed_symbol: &FailedSymbol<TLex, TSyntax>,
-            second_failed_symbol: &FailedSymbol<TLex, TSyntax>,
-        ) -> bool {
-            if first_failed_symbol
-                .symbol_to_derive
-                .ne(&second_failed_symbol.symbol_to_derive)
-            {
-                return false;
-            }
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_precomputed(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            )
+            .ok()
+            .unwrap();
 
-            if first_failed_symbol.failed_productions.len()
-                != second_failed_symbol.failed_productions.len()
-            {
-                return false;
-            }
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+            ];
 
-            for i in 0..first_failed_symbol.failed_productions.len() {
-                if !failed_productions_equals(
-                    first_failed_symbol.failed_productions.get(i).unwrap(),
-                    second_failed_symbol.failed_productions.get(i).unwrap(),
-                ) {
-                    return false;
-                }
-            }
+            let parse_result = recursive_descent_parser.parse_from_tokens(tokens.into_iter());
 
-            true
+            assert!(matches!(parse_result, ParseResult::Ok(_)));
         }
 
-        fn failed_productions_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_failed_production: &FailedProduction<TLex, TSyntax>,
-            second_failed_production: &FailedProduction<TLex, TSyntax>,
-        ) -> bool {
-            if first_failed_production.parsed_symbols.len()
-                != second_failed_production.parsed_symbols.len()
-            {
-                return false;
-            }
+        #[test]
+        fn it_does_not_build_a_parser_with_tables_of_a_different_grammar() -> () {
+            let grammar = build_grammar(vec![
+                DummySyntaxTokenTest::ATerminal,
+                DummySyntaxTokenTest::A,
+            ]);
+            let other_grammar = build_grammar(vec![DummySyntaxTokenTest::ATerminal]);
+
+            let serialized_tables = PrecomputedParseTables::from_grammar(&other_grammar)
+                .serialize(&other_grammar, &DebugSymbolEncoder);
+
+            let recursive_descent_parser_result: Result<
+                RecursiveDescentParser<
+                    u64,
+                    DummySyntaxTokenTest,
+                    SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+                >,
+                PrecomputedParseTablesError,
+            > = RecursiveDescentParser::from_precomputed(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+            );
 
-            if first_failed_production.pending_symbols.len()
-                != second_failed_production.pending_symbols.len()
-            {
-                return false;
-            }
+            assert!(matches!(
+                recursive_descent_parser_result,
+                Err(PrecomputedParseTablesError::FingerprintMismatch(_, _))
+            ));
+        }
+    }
 
-            for i in 0..first_failed_production.parsed_symbols.len() {
-                if !production_parsed_symbol_equals(
-                    first_failed_production.parsed_symbols.get(i).unwrap(),
-                    second_failed_production.parsed_symbols.get(i).unwrap(),
-                ) {
-                    return false;
-                }
-            }
+    mod from_precomputed_and_solver {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::debug_symbol_encoder::DebugSymbolEncoder;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::precomputed_parse_tables::PrecomputedParseTables;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::single_token_solver::SingleTokenSolver;
+        use crate::token::token::Token;
 
-            if !failed_symbols_equals(
-                &first_failed_production.failed_symbol,
-                &second_failed_production.failed_symbol,
-            ) {
-                return false;
-            }
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            ATerminal,
+            BTerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
 
-            for i in 0..first_failed_production.pending_symbols.len() {
-                if first_failed_production
-                    .pending_symbols
-                    .get(i)
-                    .unwrap()
-                    .ne(second_failed_production.pending_symbols.get(i).unwrap())
-                {
-                    return false;
-                }
-            }
+        fn build_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                        DummySyntaxTokenTest::Eof,
+                    ],
+                )];
 
-            true
+            ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            )
         }
 
-        fn fix_gap_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_fix_gap: &FixGap<TLex, TSyntax>,
-            second_fix_gap: &FixGap<TLex, TSyntax>,
-        ) -> bool {
-            if first_fix_gap.skipped_symbols.len() != second_fix_gap.skipped_symbols.len() {
-                return false;
-            }
+        #[test]
+        fn it_fixes_with_precomputed_tables() -> () {
+            let grammar = build_grammar();
 
-            for i in 0..first_fix_gap.skipped_symbols.len() {
-                let skipped_symbol_equals: bool = first_fix_gap.skipped_symbols.get(i).unwrap()
-                    == second_fix_gap.skipped_symbols.get(i).unwrap();
+            let serialized_tables = PrecomputedParseTables::from_grammar(&grammar)
+                .serialize(&grammar, &DebugSymbolEncoder);
 
-                if !skipped_symbol_equals {
-                    return false;
-                }
-            }
+            let recursive_descent_parser = RecursiveDescentParser::from_precomputed_and_solver(
+                &grammar,
+                &DebugSymbolEncoder,
+                &serialized_tables,
+                SingleTokenSolver::new(),
+            )
+            .ok()
+            .unwrap();
 
-            if first_fix_gap.skipped_tokens.len() != second_fix_gap.skipped_tokens.len() {
-                return false;
-            }
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+            ];
 
-            for i in 0..first_fix_gap.skipped_tokens.len() {
-                let skipped_token_equals: bool = first_fix_gap.skipped_tokens.get(i).unwrap()
-                    == second_fix_gap.skipped_tokens.get(i).unwrap();
+            let parse_result = recursive_descent_parser.parse_from_tokens(tokens.into_iter());
 
-                if !skipped_token_equals {
-                    return false;
-                }
-            }
+            assert!(matches!(parse_result, ParseResult::Fix(_)));
+        }
+    }
 
-            true
+    mod from_shared_grammar {
+        use std::sync::Arc;
+        use std::thread;
+
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::{
+            OwnedRecursiveDescentParser, RecursiveDescentParser,
+        };
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::token::token::Token;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            ATerminal,
+            Eof,
+            Epsilon,
+            S,
         }
 
-        fn fixed_production_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_fixed_production: &FixedProduction<TLex, TSyntax>,
-            second_fixed_production: &FixedProduction<TLex, TSyntax>,
-        ) -> bool {
-            if first_fixed_production.fixed_parts.len() != second_fixed_production.fixed_parts.len()
-            {
-                return false;
-            }
+        struct ParserHolder {
+            parser: OwnedRecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            >,
+        }
 
-            for i in 0..first_fixed_production.fixed_parts.len() {
-                if !fixed_production_part_equals(
-                    first_fixed_production.fixed_parts.get(i).unwrap(),
-                    second_fixed_production.fixed_parts.get(i).unwrap(),
-                ) {
-                    return false;
-                }
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        fn build_parser_holder() -> ParserHolder {
+            let grammar = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::ATerminal, DummySyntaxTokenTest::Eof],
+                )],
+            );
+
+            ParserHolder {
+                parser: RecursiveDescentParser::from_shared_grammar(Arc::new(grammar)),
             }
+        }
 
-            true
+        #[test]
+        fn it_builds_a_thread_safe_parser() -> () {
+            assert_send_sync::<
+                OwnedRecursiveDescentParser<
+                    u64,
+                    DummySyntaxTokenTest,
+                    SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+                >,
+            >();
         }
 
-        fn fixed_production_part_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_fixed_production_part: &FixedProductionPart<TLex, TSyntax>,
-            second_fixed_production_part: &FixedProductionPart<TLex, TSyntax>,
-        ) -> bool {
-            match (first_fixed_production_part, second_fixed_production_part) {
-                (FixedProductionPart::Ok(first_asn), FixedProductionPart::Ok(second_asn)) => {
-                    asn_equals(first_asn, second_asn)
-                }
-                (
-                    FixedProductionPart::Fixed(first_fixed_symbol),
-                    FixedProductionPart::Fixed(second_fixed_symbol),
-                ) => fixed_symbol_equals(first_fixed_symbol, second_fixed_symbol),
-                (
-                    FixedProductionPart::Gap(first_fix_gap),
-                    FixedProductionPart::Gap(second_fix_gap),
-                ) => fix_gap_equals(first_fix_gap, second_fix_gap),
-                _ => false,
+        #[test]
+        fn it_parses_from_several_threads() -> () {
+            let parser_holder = Arc::new(build_parser_holder());
+
+            let handles: Vec<thread::JoinHandle<bool>> = (0..4)
+                .map(|_| {
+                    let parser_holder = parser_holder.clone();
+
+                    thread::spawn(move || {
+                        let tokens = vec![
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                            Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                        ];
+
+                        matches!(
+                            parser_holder.parser.parse_from_tokens(tokens.into_iter()),
+                            ParseResult::Ok(_)
+                        )
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                assert!(handle.join().unwrap());
             }
         }
+    }
 
-        fn fixed_symbol_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_fixed_symbol: &FixedSymbol<TLex, TSyntax>,
-            second_fixed_symbol: &FixedSymbol<TLex, TSyntax>,
-        ) -> bool {
-            if first_fixed_symbol
-                .symbol_to_derive
-                .ne(&second_fixed_symbol.symbol_to_derive)
-            {
-                return false;
-            }
+    mod get_expected_symbols {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::token::token::Token;
 
-            fixed_production_equals(
-                &first_fixed_symbol.fixed_production,
-                &second_fixed_symbol.fixed_production,
-            )
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            B,
+            BTerminal,
+            CTerminal,
+            Eof,
+            Epsilon,
+            PrefixTerminal,
+            S,
+        }
+
+        fn build_grammar(
+            a_productions: Vec<Vec<DummySyntaxTokenTest>>,
+        ) -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            let mut grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::PrefixTerminal,
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::B,
+                        DummySyntaxTokenTest::Eof,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![DummySyntaxTokenTest::BTerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![DummySyntaxTokenTest::CTerminal],
+                ),
+            ];
+
+            for a_production in a_productions {
+                grammar_productions.push(ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    a_production,
+                ));
+            }
+
+            ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            )
+        }
+
+        #[test]
+        fn it_returns_expected_symbols_after_prefix() -> () {
+            let grammar = build_grammar(vec![vec![DummySyntaxTokenTest::ATerminal]]);
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let expected_symbols = recursive_descent_parser.get_expected_symbols(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::PrefixTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                ]
+                .into_iter(),
+            );
+
+            assert_eq!(expected_symbols.terminal_symbols.len(), 2);
+            assert!(expected_symbols
+                .terminal_symbols
+                .contains(&DummySyntaxTokenTest::BTerminal));
+            assert!(expected_symbols
+                .terminal_symbols
+                .contains(&DummySyntaxTokenTest::CTerminal));
+
+            assert_eq!(expected_symbols.non_terminal_symbols.len(), 2);
+            assert!(expected_symbols
+                .non_terminal_symbols
+                .contains(&DummySyntaxTokenTest::S));
+            assert!(expected_symbols
+                .non_terminal_symbols
+                .contains(&DummySyntaxTokenTest::B));
+        }
+
+        #[test]
+        fn it_returns_expected_symbols_after_nullable_symbol() -> () {
+            let grammar = build_grammar(vec![
+                vec![DummySyntaxTokenTest::ATerminal],
+                vec![DummySyntaxTokenTest::Epsilon],
+            ]);
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let expected_symbols = recursive_descent_parser.get_expected_symbols(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::PrefixTerminal)].into_iter(),
+            );
+
+            assert_eq!(expected_symbols.terminal_symbols.len(), 3);
+            assert!(expected_symbols
+                .terminal_symbols
+                .contains(&DummySyntaxTokenTest::ATerminal));
+            assert!(expected_symbols
+                .terminal_symbols
+                .contains(&DummySyntaxTokenTest::BTerminal));
+            assert!(expected_symbols
+                .terminal_symbols
+                .contains(&DummySyntaxTokenTest::CTerminal));
+
+            assert_eq!(expected_symbols.non_terminal_symbols.len(), 3);
+            assert!(expected_symbols
+                .non_terminal_symbols
+                .contains(&DummySyntaxTokenTest::A));
+        }
+
+        #[test]
+        fn it_returns_expected_symbols_in_order_of_appearance() -> () {
+            let grammar = build_grammar(vec![
+                vec![DummySyntaxTokenTest::ATerminal],
+                vec![DummySyntaxTokenTest::Epsilon],
+            ]);
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let expected_symbols = recursive_descent_parser.get_expected_symbols(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::PrefixTerminal)].into_iter(),
+            );
+
+            assert_eq!(
+                expected_symbols.terminal_symbols,
+                vec![
+                    DummySyntaxTokenTest::BTerminal,
+                    DummySyntaxTokenTest::CTerminal,
+                    DummySyntaxTokenTest::ATerminal,
+                ]
+            );
+            assert_eq!(
+                expected_symbols.non_terminal_symbols,
+                vec![
+                    DummySyntaxTokenTest::S,
+                    DummySyntaxTokenTest::A,
+                    DummySyntaxTokenTest::B,
+                ]
+            );
+        }
+
+        #[test]
+        fn it_returns_expected_symbols_of_a_nullable_symbol_derived_again() -> () {
+            let grammar = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::S,
+                        vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::B],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::B,
+                        vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::CTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![
+                            DummySyntaxTokenTest::ATerminal,
+                            DummySyntaxTokenTest::BTerminal,
+                        ],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![DummySyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let expected_symbols = recursive_descent_parser.get_expected_symbols(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal)].into_iter(),
+            );
+
+            assert_eq!(
+                expected_symbols.terminal_symbols,
+                vec![DummySyntaxTokenTest::BTerminal]
+            );
+            assert_eq!(expected_symbols.non_terminal_symbols.len(), 3);
+            assert!(expected_symbols
+                .non_terminal_symbols
+                .contains(&DummySyntaxTokenTest::B));
+        }
+
+        #[test]
+        fn it_returns_no_expected_symbols_on_invalid_prefix() -> () {
+            let grammar = build_grammar(vec![vec![DummySyntaxTokenTest::ATerminal]]);
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let expected_symbols = recursive_descent_parser.get_expected_symbols(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal)].into_iter(),
+            );
+
+            assert!(expected_symbols.terminal_symbols.is_empty());
+            assert!(expected_symbols.non_terminal_symbols.is_empty());
+        }
+    }
+
+    mod parse_all {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::parse_abort::ParseAbort;
+        use crate::parser::parse_all_result::ParseAllResult;
+        use crate::parser::parse_limits::ParseLimits;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::token::token::Token;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            B,
+            Eof,
+            Epsilon,
+            S,
+        }
+
+        fn build_ambiguous_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::B,
+                        DummySyntaxTokenTest::Eof,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![DummySyntaxTokenTest::Epsilon],
+                ),
+            ];
+
+            ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            )
+        }
+
+        fn build_tokens(a_terminals: usize) -> Vec<Token<u64, DummySyntaxTokenTest>> {
+            let mut tokens: Vec<Token<u64, DummySyntaxTokenTest>> = (0..a_terminals)
+                .map(|_| Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal))
+                .collect();
+
+            tokens.push(Token::new(Some(0u64), DummySyntaxTokenTest::Eof));
+
+            tokens
+        }
+
+        fn count_trees(parse_all_result: ParseAllResult<u64, DummySyntaxTokenTest>) -> usize {
+            match parse_all_result {
+                ParseAllResult::Ok(trees) => trees.len(),
+                _ => panic!("Expected trees!"),
+            }
+        }
+
+        #[test]
+        fn it_returns_every_tree() -> () {
+            let grammar = build_ambiguous_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_all_result =
+                recursive_descent_parser.parse_all(build_tokens(2).into_iter(), 8);
+
+            assert_eq!(count_trees(parse_all_result), 2);
+        }
+
+        #[test]
+        fn it_returns_trees_up_to_limit() -> () {
+            let grammar = build_ambiguous_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_all_result =
+                recursive_descent_parser.parse_all(build_tokens(2).into_iter(), 1);
+
+            assert_eq!(count_trees(parse_all_result), 1);
+        }
+
+        #[test]
+        fn it_returns_no_trees_on_invalid_input() -> () {
+            let grammar = build_ambiguous_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_all_result =
+                recursive_descent_parser.parse_all(build_tokens(4).into_iter(), 8);
+
+            assert_eq!(count_trees(parse_all_result), 0);
+        }
+
+        #[test]
+        fn it_aborts_on_left_recursion_as_parse_from_tokens_does() -> () {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::ATerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(None, Some(64), None));
+
+            assert!(matches!(
+                recursive_descent_parser.parse_from_tokens(build_tokens(1).into_iter()),
+                ParseResult::Abort(ParseAbort::MaxDepthExceeded)
+            ));
+            assert!(matches!(
+                recursive_descent_parser.parse_all(build_tokens(1).into_iter(), 8),
+                ParseAllResult::Abort(ParseAbort::MaxDepthExceeded)
+            ));
+        }
+
+        #[test]
+        fn it_does_not_nest_calls_for_every_token() -> () {
+            let tokens_count: usize = 100_000;
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::ATerminal; tokens_count],
+                )],
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let tokens: Vec<Token<u64, DummySyntaxTokenTest>> = (0..tokens_count)
+                .map(|_| Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal))
+                .collect();
+
+            assert_eq!(
+                count_trees(recursive_descent_parser.parse_all(tokens.into_iter(), 8)),
+                1
+            );
+        }
+
+        #[test]
+        fn it_counts_depth_as_parse_from_tokens_does() -> () {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::A,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(None, Some(3), None));
+
+            let tokens: Vec<Token<u64, DummySyntaxTokenTest>> = (0..5)
+                .map(|_| Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal))
+                .collect();
+
+            assert!(matches!(
+                recursive_descent_parser.parse_from_tokens(tokens.clone().into_iter()),
+                ParseResult::Ok(_)
+            ));
+            assert_eq!(
+                count_trees(recursive_descent_parser.parse_all(tokens.into_iter(), 8)),
+                1
+            );
+        }
+    }
+
+    mod parse_from_tokens {
+        use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+        use crate::ast::abstract_syntax_tree::AbstractSyntaxTree;
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::failed_production::FailedProduction;
+        use crate::parser::failed_symbol::FailedSymbol;
+        use crate::parser::fix_gap::FixGap;
+        use crate::parser::fix_result::FixResult;
+        use crate::parser::fixed_production::FixedProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::invalid_input::InvalidInput;
+        use crate::parser::parse_abort::ParseAbort;
+        use crate::parser::parse_limits::ParseLimits;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+        use crate::parser::recovery_context::RecoveryContext;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+        use crate::token::token::Token;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        fn ast_equals<T: PartialEq>(
+            first_ast: &AbstractSyntaxTree<T>,
+            second_ast: &AbstractSyntaxTree<T>,
+        ) -> bool {
+            asn_equals(&first_ast.root, &second_ast.root)
+        }
+
+        fn asn_equals<T: PartialEq>(
+            first_asn: &AbstractSyntaxNode<T>,
+            second_asn: &AbstractSyntaxNode<T>,
+        ) -> bool {
+            if first_asn.token.ne(&second_asn.token) {
+                return false;
+            }
+
+            if first_asn
+                .child_nodes
+                .len()
+                .ne(&second_asn.child_nodes.len())
+            {
+                return false;
+            }
+
+            for i in 0..first_asn.child_nodes.len() {
+                if !asn_equals(
+                    first_asn.child_nodes.get(i).unwrap(),
+                    second_asn.child_nodes.get(i).unwrap(),
+                ) {
+                    return false;
+                }
+            }
+
+            true
+        }
+
+        fn failed_symbols_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_failed_symbol: &FailedSymbol<TLex, TSyntax>,
+            second_failed_symbol: &FailedSymbol<TLex, TSyntax>,
+        ) -> bool {
+            if first_failed_symbol
+                .symbol_to_derive
+                .ne(&second_failed_symbol.symbol_to_derive)
+            {
+                return false;
+            }
+
+            if first_failed_symbol.token_position != second_failed_symbol.token_position {
+                return false;
+            }
+
+            if first_failed_symbol.failed_productions.len()
+                != second_failed_symbol.failed_productions.len()
+            {
+                return false;
+            }
+
+            for i in 0..first_failed_symbol.failed_productions.len() {
+                if !failed_productions_equals(
+                    first_failed_symbol.failed_productions.get(i).unwrap(),
+                    second_failed_symbol.failed_productions.get(i).unwrap(),
+                ) {
+                    return false;
+                }
+            }
+
+            true
+        }
+
+        fn failed_productions_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_failed_production: &FailedProduction<TLex, TSyntax>,
+            second_failed_production: &FailedProduction<TLex, TSyntax>,
+        ) -> bool {
+            if first_failed_production.parsed_symbols.len()
+                != second_failed_production.parsed_symbols.len()
+            {
+                return false;
+            }
+
+            if first_failed_production.pending_symbols.len()
+                != second_failed_production.pending_symbols.len()
+            {
+                return false;
+            }
+
+            for i in 0..first_failed_production.parsed_symbols.len() {
+                if !production_parsed_symbol_equals(
+                    first_failed_production.parsed_symbols.get(i).unwrap(),
+                    second_failed_production.parsed_symbols.get(i).unwrap(),
+                ) {
+                    return false;
+                }
+            }
+
+            if !failed_symbols_equals(
+                &first_failed_production.failed_symbol,
+                &second_failed_production.failed_symbol,
+            ) {
+                return false;
+            }
+
+            for i in 0..first_failed_production.pending_symbols.len() {
+                if first_failed_production
+                    .pending_symbols
+                    .get(i)
+                    .unwrap()
+                    .ne(second_failed_production.pending_symbols.get(i).unwrap())
+                {
+                    return false;
+                }
+            }
+
+            true
+        }
+
+        fn fix_gap_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_fix_gap: &FixGap<TLex, TSyntax>,
+            second_fix_gap: &FixGap<TLex, TSyntax>,
+        ) -> bool {
+            if first_fix_gap.skipped_symbols.len() != second_fix_gap.skipped_symbols.len() {
+                return false;
+            }
+
+            for i in 0..first_fix_gap.skipped_symbols.len() {
+                let skipped_symbol_equals: bool = first_fix_gap.skipped_symbols.get(i).unwrap()
+                    == second_fix_gap.skipped_symbols.get(i).unwrap();
+
+                if !skipped_symbol_equals {
+                    return false;
+                }
+            }
+
+            if first_fix_gap.skipped_tokens.len() != second_fix_gap.skipped_tokens.len() {
+                return false;
+            }
+
+            for i in 0..first_fix_gap.skipped_tokens.len() {
+                let skipped_token_equals: bool = first_fix_gap.skipped_tokens.get(i).unwrap()
+                    == second_fix_gap.skipped_tokens.get(i).unwrap();
+
+                if !skipped_token_equals {
+                    return false;
+                }
+            }
+
+            true
+        }
+
+        fn fixed_production_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_fixed_production: &FixedProduction<TLex, TSyntax>,
+            second_fixed_production: &FixedProduction<TLex, TSyntax>,
+        ) -> bool {
+            if first_fixed_production.fixed_parts.len() != second_fixed_production.fixed_parts.len()
+            {
+                return false;
+            }
+
+            for i in 0..first_fixed_production.fixed_parts.len() {
+                if !fixed_production_part_equals(
+                    first_fixed_production.fixed_parts.get(i).unwrap(),
+                    second_fixed_production.fixed_parts.get(i).unwrap(),
+                ) {
+                    return false;
+                }
+            }
+
+            true
+        }
+
+        fn fixed_production_part_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_fixed_production_part: &FixedProductionPart<TLex, TSyntax>,
+            second_fixed_production_part: &FixedProductionPart<TLex, TSyntax>,
+        ) -> bool {
+            match (first_fixed_production_part, second_fixed_production_part) {
+                (FixedProductionPart::Ok(first_asn), FixedProductionPart::Ok(second_asn)) => {
+                    asn_equals(first_asn, second_asn)
+                }
+                (
+                    FixedProductionPart::Fixed(first_fixed_symbol),
+                    FixedProductionPart::Fixed(second_fixed_symbol),
+                ) => fixed_symbol_equals(first_fixed_symbol, second_fixed_symbol),
+                (
+                    FixedProductionPart::Gap(first_fix_gap),
+                    FixedProductionPart::Gap(second_fix_gap),
+                ) => fix_gap_equals(first_fix_gap, second_fix_gap),
+                _ => false,
+            }
+        }
+
+        fn fixed_symbol_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_fixed_symbol: &FixedSymbol<TLex, TSyntax>,
+            second_fixed_symbol: &FixedSymbol<TLex, TSyntax>,
+        ) -> bool {
+            if first_fixed_symbol
+                .symbol_to_derive
+                .ne(&second_fixed_symbol.symbol_to_derive)
+            {
+                return false;
+            }
+
+            fixed_production_equals(
+                &first_fixed_symbol.fixed_production,
+                &second_fixed_symbol.fixed_production,
+            )
         }
 
         fn parse_result_equals<TLex: PartialEq, TSyntax: PartialEq>(
@@ -263,35 +1006,670 @@ mod test {
                 (ParseResult::Err(first_failed_symbol), ParseResult::Err(second_failed_symbol)) => {
                     failed_symbols_equals(first_failed_symbol, second_failed_symbol)
                 }
+                (ParseResult::Abort(first_parse_abort), ParseResult::Abort(second_parse_abort)) => {
+                    first_parse_abort == second_parse_abort
+                }
+                (
+                    ParseResult::InvalidInput(first_invalid_input),
+                    ParseResult::InvalidInput(second_invalid_input),
+                ) => first_invalid_input == second_invalid_input,
+                _ => false,
+            }
+        }
+
+        fn production_parsed_symbol_equals<TLex: PartialEq, TSyntax: PartialEq>(
+            first_production_parsed_symbol: &ProductionParsedSymbol<TLex, TSyntax>,
+            second_production_parsed_symbol: &ProductionParsedSymbol<TLex, TSyntax>,
+        ) -> bool {
+            match (
+                first_production_parsed_symbol,
+                second_production_parsed_symbol,
+            ) {
+                (
+                    ProductionParsedSymbol::Fix(first_fixed_symbol),
+                    ProductionParsedSymbol::Fix(second_fixed_symbol),
+                ) => fixed_symbol_equals(first_fixed_symbol, second_fixed_symbol),
+                (ProductionParsedSymbol::Ok(first_asn), ProductionParsedSymbol::Ok(second_asn)) => {
+                    asn_equals(first_asn, second_asn)
+                }
                 _ => false,
             }
         }
 
-        fn production_parsed_symbol_equals<TLex: PartialEq, TSyntax: PartialEq>(
-            first_production_parsed_symbol: &ProductionParsedSymbol<TLex, TSyntax>,
-            second_production_parsed_symbol: &ProductionParsedSymbol<TLex, TSyntax>,
-        ) -> bool {
-            match (
-                first_production_parsed_symbol,
-                second_production_parsed_symbol,
-            ) {
-                (
-                    ProductionParsedSymbol::Fix(first_fixed_symbol),
-                    ProductionParsedSymbol::Fix(second_fixed_symbol),
-                ) => fixed_symbol_equals(first_fixed_symbol, second_fixed_symbol),
-                (ProductionParsedSymbol::Ok(first_asn), ProductionParsedSymbol::Ok(second_asn)) => {
-                    asn_equals(first_asn, second_asn)
-                }
-                _ => false,
-            }
+        #[test]
+        fn it_does_not_parse_empty_input() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::Epsilon],
+                )];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens::<std::vec::IntoIter<
+                Token<u64, DummySyntaxTokenTest>,
+            >>(vec![].into_iter());
+
+            let expected_parse_result = ParseResult::InvalidInput(InvalidInput::EmptyInput);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_fails_on_unknown_token_type() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                ATerminal,
+                Epsilon,
+                S,
+                UnknownTerminal,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                    ],
+                )];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::UnknownTerminal),
+                ]
+                .into_iter(),
+            );
+
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    ))],
+                    vec![],
+                )],
+                DummySyntaxTokenTest::S,
+                0,
+            );
+
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_does_not_parse_unknown_initial_symbol() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                ATerminal,
+                Epsilon,
+                S,
+                UnknownSymbol,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                )];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::UnknownSymbol,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal)].into_iter(),
+            );
+
+            let expected_parse_result = ParseResult::InvalidInput(InvalidInput::UnknownSymbol(
+                DummySyntaxTokenTest::UnknownSymbol,
+            ));
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_fails_on_unexpected_end_of_input() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                A,
+                ATerminal,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::ATerminal, DummySyntaxTokenTest::A],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal)].into_iter(),
+            );
+
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::A, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    ))],
+                    vec![],
+                )],
+                DummySyntaxTokenTest::S,
+                0,
+            );
+
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_parses_terminal_production() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                Eof,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::Eof],
+                )];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::Eof)].into_iter(),
+            );
+
+            let expected_asn = AbstractSyntaxNode::new(
+                vec![AbstractSyntaxNode::new(
+                    vec![],
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                )],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let expected_ast = AbstractSyntaxTree::new(expected_asn);
+
+            let expected_parse_result = ParseResult::Ok(expected_ast);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_parses_epsilon_production() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                Empty,
+                Eof,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::Empty, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::Empty,
+                    vec![DummySyntaxTokenTest::Epsilon],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::Eof)].into_iter(),
+            );
+
+            let expected_asn = AbstractSyntaxNode::new(
+                vec![
+                    AbstractSyntaxNode::new(
+                        vec![AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(None, DummySyntaxTokenTest::Epsilon),
+                        )],
+                        Token::new(None, DummySyntaxTokenTest::Empty),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                    ),
+                ],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let expected_ast = AbstractSyntaxTree::new(expected_asn);
+
+            let expected_parse_result = ParseResult::Ok(expected_ast);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+        }
+
+        #[test]
+        fn it_parses_with_first_first_conflict() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                A,
+                ATerminal,
+                B,
+                BTerminal,
+                CommonTerminal,
+                Eof,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::B, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![
+                        DummySyntaxTokenTest::CommonTerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![
+                        DummySyntaxTokenTest::CommonTerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                    ],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let a_parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
+
+            let a_expected_asn = AbstractSyntaxNode::new(
+                vec![
+                    AbstractSyntaxNode::new(
+                        vec![
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
+                            ),
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                            ),
+                        ],
+                        Token::new(None, DummySyntaxTokenTest::A),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                    ),
+                ],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let a_expected_ast = AbstractSyntaxTree::new(a_expected_asn);
+
+            let a_expected_parse_result = ParseResult::Ok(a_expected_ast);
+
+            let b_parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
+
+            let b_expected_asn = AbstractSyntaxNode::new(
+                vec![
+                    AbstractSyntaxNode::new(
+                        vec![
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
+                            ),
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(None, DummySyntaxTokenTest::BTerminal),
+                            ),
+                        ],
+                        Token::new(None, DummySyntaxTokenTest::B),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                    ),
+                ],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let b_expected_ast = AbstractSyntaxTree::new(b_expected_asn);
+
+            let b_expected_parse_result = ParseResult::Ok(b_expected_ast);
+
+            assert!(parse_result_equals(
+                &a_parse_result,
+                &a_expected_parse_result,
+            ));
+
+            assert!(parse_result_equals(
+                &b_parse_result,
+                &b_expected_parse_result,
+            ));
+        }
+
+        #[test]
+        fn it_parses_with_production_backtracking() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                A,
+                ATerminal,
+                B,
+                BTerminal,
+                CTerminal,
+                DTerminal,
+                Eof,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![
+                        DummySyntaxTokenTest::A,
+                        DummySyntaxTokenTest::B,
+                        DummySyntaxTokenTest::Eof,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![
+                        DummySyntaxTokenTest::BTerminal,
+                        DummySyntaxTokenTest::CTerminal,
+                        DummySyntaxTokenTest::DTerminal,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::B,
+                    vec![DummySyntaxTokenTest::CTerminal],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let abc_parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
+
+            let abc_expected_asn = AbstractSyntaxNode::new(
+                vec![
+                    AbstractSyntaxNode::new(
+                        vec![
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                            ),
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                            ),
+                        ],
+                        Token::new(None, DummySyntaxTokenTest::A),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                        )],
+                        Token::new(None, DummySyntaxTokenTest::B),
+                    ),
+                    AbstractSyntaxNode::new(vec![], Token::new(None, DummySyntaxTokenTest::Eof)),
+                ],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let abc_expected_ast = AbstractSyntaxTree::new(abc_expected_asn);
+
+            let abc_expected_parse_result = ParseResult::Ok(abc_expected_ast);
+
+            let abcd_parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::DTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
+
+            let abcd_expected_asn = AbstractSyntaxNode::new(
+                vec![
+                    AbstractSyntaxNode::new(
+                        vec![AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        )],
+                        Token::new(None, DummySyntaxTokenTest::A),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                            ),
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
+                            ),
+                            AbstractSyntaxNode::new(
+                                vec![],
+                                Token::new(Some(0u64), DummySyntaxTokenTest::DTerminal),
+                            ),
+                        ],
+                        Token::new(None, DummySyntaxTokenTest::B),
+                    ),
+                    AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                    ),
+                ],
+                Token::new(None, DummySyntaxTokenTest::S),
+            );
+
+            let abcd_expected_ast = AbstractSyntaxTree::new(abcd_expected_asn);
+
+            let abcd_expected_parse_result = ParseResult::Ok(abcd_expected_ast);
+
+            assert!(parse_result_equals(
+                &abc_parse_result,
+                &abc_expected_parse_result,
+            ));
+            assert!(parse_result_equals(
+                &abcd_parse_result,
+                &abcd_expected_parse_result,
+            ));
+        }
+
+        #[test]
+        fn it_fails_on_unexpected_symbol_when_no_production_is_found() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                ATerminal,
+                BTerminal,
+                Epsilon,
+                S,
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
+                vec![ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::ATerminal],
+                )];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
+            );
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal)].into_iter(),
+            );
+
+            let expected_failed_symbol =
+                <FailedSymbol<u64, DummySyntaxTokenTest>>::new(vec![], DummySyntaxTokenTest::S, 0);
+
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
         }
 
-        #[allow(unused_must_use)]
         #[test]
-        #[should_panic]
-        fn it_does_not_parse_empty_input() -> () {
+        fn it_fails_on_unexpected_symbol_when_a_production_is_found() -> () {
             #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
             enum DummySyntaxTokenTest {
+                ATerminal,
+                BTerminal,
                 Epsilon,
                 S,
             }
@@ -299,7 +1677,10 @@ mod test {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
                 vec![ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![DummySyntaxTokenTest::Epsilon],
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                    ],
                 )];
 
             let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
@@ -314,17 +1695,39 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            recursive_descent_parser
-                .parse_from_tokens::<std::vec::IntoIter<Token<u64, DummySyntaxTokenTest>>>(
-                    vec![].into_iter(),
-                );
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                ]
+                .into_iter(),
+            );
+
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![],
+                        Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    ))],
+                    vec![],
+                )],
+                DummySyntaxTokenTest::S,
+                0,
+            );
+
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
         }
 
         #[test]
-        fn it_parses_terminal_production() -> () {
+        fn it_fails_on_unexpected_symbol_when_a_production_is_found_with_parsed_symbols_ordered(
+        ) -> () {
             #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
             enum DummySyntaxTokenTest {
-                Eof,
+                ATerminal,
+                BTerminal,
                 Epsilon,
                 S,
             }
@@ -332,7 +1735,11 @@ mod test {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
                 vec![ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![DummySyntaxTokenTest::Eof],
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                    ],
                 )];
 
             let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
@@ -348,30 +1755,44 @@ mod test {
             > = RecursiveDescentParser::from_grammar(&grammar);
 
             let parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![Token::new(Some(0u64), DummySyntaxTokenTest::Eof)].into_iter(),
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                ]
+                .into_iter(),
             );
 
-            let expected_asn = AbstractSyntaxNode::new(
-                vec![AbstractSyntaxNode::new(
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal, 2),
+                    vec![
+                        ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        )),
+                        ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                        )),
+                    ],
                     vec![],
-                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
                 )],
-                Token::new(None, DummySyntaxTokenTest::S),
+                DummySyntaxTokenTest::S,
+                0,
             );
 
-            let expected_ast = AbstractSyntaxTree::new(expected_asn);
-
-            let expected_parse_result = ParseResult::Ok(expected_ast);
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
 
-            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
         }
 
         #[test]
-        fn it_parses_epsilon_production() -> () {
+        fn it_fails_on_unexpected_symbol_keeping_parsed_non_terminal_symbols() -> () {
             #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
             enum DummySyntaxTokenTest {
-                Empty,
-                Eof,
+                A,
+                ATerminal,
+                BTerminal,
                 Epsilon,
                 S,
             }
@@ -379,11 +1800,11 @@ mod test {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
                 ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![DummySyntaxTokenTest::Empty, DummySyntaxTokenTest::Eof],
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::BTerminal],
                 ),
                 ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::Empty,
-                    vec![DummySyntaxTokenTest::Epsilon],
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
                 ),
             ];
 
@@ -400,43 +1821,42 @@ mod test {
             > = RecursiveDescentParser::from_grammar(&grammar);
 
             let parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![Token::new(Some(0u64), DummySyntaxTokenTest::Eof)].into_iter(),
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                ]
+                .into_iter(),
             );
 
-            let expected_asn = AbstractSyntaxNode::new(
-                vec![
-                    AbstractSyntaxNode::new(
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::BTerminal, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
                         vec![AbstractSyntaxNode::new(
                             vec![],
-                            Token::new(None, DummySyntaxTokenTest::Epsilon),
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
                         )],
-                        Token::new(None, DummySyntaxTokenTest::Empty),
-                    ),
-                    AbstractSyntaxNode::new(
-                        vec![],
-                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                    ),
-                ],
-                Token::new(None, DummySyntaxTokenTest::S),
+                        Token::new(None, DummySyntaxTokenTest::A),
+                    ))],
+                    vec![],
+                )],
+                DummySyntaxTokenTest::S,
+                0,
             );
 
-            let expected_ast = AbstractSyntaxTree::new(expected_asn);
-
-            let expected_parse_result = ParseResult::Ok(expected_ast);
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
 
             assert!(parse_result_equals(&parse_result, &expected_parse_result,));
         }
 
         #[test]
-        fn it_parses_with_first_first_conflict() -> () {
+        fn it_fails_on_unexpected_symbol_keeping_non_terminal_symbols_failing_to_derive_again() -> ()
+        {
             #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
             enum DummySyntaxTokenTest {
                 A,
                 ATerminal,
-                B,
                 BTerminal,
-                CommonTerminal,
-                Eof,
                 Epsilon,
                 S,
             }
@@ -444,23 +1864,17 @@ mod test {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
                 ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::BTerminal],
                 ),
                 ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::S,
-                    vec![DummySyntaxTokenTest::B, DummySyntaxTokenTest::Eof],
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal],
                 ),
                 ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::A,
                     vec![
-                        DummySyntaxTokenTest::CommonTerminal,
                         DummySyntaxTokenTest::ATerminal,
-                    ],
-                ),
-                ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::B,
-                    vec![
-                        DummySyntaxTokenTest::CommonTerminal,
+                        DummySyntaxTokenTest::BTerminal,
                         DummySyntaxTokenTest::BTerminal,
                     ],
                 ),
@@ -478,100 +1892,135 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let a_parse_result = recursive_descent_parser.parse_from_tokens(
+            let parse_result = recursive_descent_parser.parse_from_tokens(
                 vec![
-                    Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
                     Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
                 ]
                 .into_iter(),
             );
 
-            let a_expected_asn = AbstractSyntaxNode::new(
-                vec![
-                    AbstractSyntaxNode::new(
-                        vec![
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
-                            ),
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                            ),
-                        ],
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
+                vec![FailedProduction::new(
+                    FailedSymbol::new(vec![], DummySyntaxTokenTest::BTerminal, 1),
+                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                        vec![AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        )],
                         Token::new(None, DummySyntaxTokenTest::A),
-                    ),
-                    AbstractSyntaxNode::new(
-                        vec![],
-                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                    ),
-                ],
-                Token::new(None, DummySyntaxTokenTest::S),
+                    ))],
+                    vec![],
+                )],
+                DummySyntaxTokenTest::S,
+                0,
             );
 
-            let a_expected_ast = AbstractSyntaxTree::new(a_expected_asn);
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
 
-            let a_expected_parse_result = ParseResult::Ok(a_expected_ast);
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+        }
 
-            let b_parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![
-                    Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                ]
-                .into_iter(),
-            );
+        #[test]
+        fn it_passes_the_non_terminal_stack_to_the_solver() -> () {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            enum DummySyntaxTokenTest {
+                A,
+                ATerminal,
+                BTerminal,
+                Eof,
+                Epsilon,
+                S,
+            }
 
-            let b_expected_asn = AbstractSyntaxNode::new(
-                vec![
-                    AbstractSyntaxNode::new(
-                        vec![
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::CommonTerminal),
-                            ),
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(None, DummySyntaxTokenTest::BTerminal),
-                            ),
-                        ],
-                        Token::new(None, DummySyntaxTokenTest::B),
-                    ),
-                    AbstractSyntaxNode::new(
-                        vec![],
-                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                    ),
-                ],
-                Token::new(None, DummySyntaxTokenTest::S),
+            struct NonTerminalStackRecorder {
+                non_terminal_stacks: Rc<RefCell<Vec<Vec<DummySyntaxTokenTest>>>>,
+            }
+
+            impl SyntaxErrorSolver<u64, DummySyntaxTokenTest> for NonTerminalStackRecorder {
+                fn fix_failed_production(
+                    &self,
+                    context: &RecoveryContext<DummySyntaxTokenTest>,
+                    _tokens: &Vec<Token<u64, DummySyntaxTokenTest>>,
+                    _tokens_position: usize,
+                    _failed_production: &FailedProduction<u64, DummySyntaxTokenTest>,
+                ) -> Option<FixResult<u64, DummySyntaxTokenTest>> {
+                    self.non_terminal_stacks
+                        .borrow_mut()
+                        .push(context.get_non_terminal_stack().to_vec());
+
+                    None
+                }
+
+                fn fix_failed_productions(
+                    &self,
+                    context: &RecoveryContext<DummySyntaxTokenTest>,
+                    _tokens: &Vec<Token<u64, DummySyntaxTokenTest>>,
+                    _tokens_position: usize,
+                    _failed_productions: &Vec<FailedProduction<u64, DummySyntaxTokenTest>>,
+                ) -> Option<FixResult<u64, DummySyntaxTokenTest>> {
+                    self.non_terminal_stacks
+                        .borrow_mut()
+                        .push(context.get_non_terminal_stack().to_vec());
+
+                    None
+                }
+            }
+
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                    ],
+                ),
+            ];
+
+            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                grammar_productions,
             );
 
-            let b_expected_ast = AbstractSyntaxTree::new(b_expected_asn);
+            let non_terminal_stacks = Rc::new(RefCell::new(vec![]));
 
-            let b_expected_parse_result = ParseResult::Ok(b_expected_ast);
+            let recursive_descent_parser = RecursiveDescentParser::from_grammar_and_solver(
+                &grammar,
+                NonTerminalStackRecorder {
+                    non_terminal_stacks: non_terminal_stacks.clone(),
+                },
+            );
 
-            assert!(parse_result_equals(
-                &a_parse_result,
-                &a_expected_parse_result,
-            ));
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(1u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(2u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+            );
 
-            assert!(parse_result_equals(
-                &b_parse_result,
-                &b_expected_parse_result,
-            ));
+            assert!(matches!(parse_result, ParseResult::Err(_)));
+            assert_eq!(
+                vec![
+                    vec![DummySyntaxTokenTest::S, DummySyntaxTokenTest::A],
+                    vec![DummySyntaxTokenTest::S],
+                ],
+                *non_terminal_stacks.borrow(),
+            );
         }
 
         #[test]
-        fn it_parses_with_production_backtracking() -> () {
+        fn it_fails_on_unexpected_symbol_when_multiple_productions_are_found() -> () {
             #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
             enum DummySyntaxTokenTest {
-                A,
                 ATerminal,
-                B,
                 BTerminal,
-                CTerminal,
-                DTerminal,
-                Eof,
                 Epsilon,
                 S,
             }
@@ -579,35 +2028,19 @@ mod test {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
                 ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![
-                        DummySyntaxTokenTest::A,
-                        DummySyntaxTokenTest::B,
-                        DummySyntaxTokenTest::Eof,
-                    ],
-                ),
-                ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::A,
                     vec![
                         DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::BTerminal,
+                        DummySyntaxTokenTest::ATerminal,
                     ],
                 ),
                 ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::A,
-                    vec![DummySyntaxTokenTest::ATerminal],
-                ),
-                ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::B,
+                    DummySyntaxTokenTest::S,
                     vec![
-                        DummySyntaxTokenTest::BTerminal,
-                        DummySyntaxTokenTest::CTerminal,
-                        DummySyntaxTokenTest::DTerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::ATerminal,
                     ],
                 ),
-                ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::B,
-                    vec![DummySyntaxTokenTest::CTerminal],
-                ),
             ];
 
             let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
@@ -622,127 +2055,331 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let abc_parse_result = recursive_descent_parser.parse_from_tokens(
+            let parse_result = recursive_descent_parser.parse_from_tokens(
                 vec![
                     Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
                     Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
                 ]
                 .into_iter(),
             );
 
-            let abc_expected_asn = AbstractSyntaxNode::new(
+            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
                 vec![
-                    AbstractSyntaxNode::new(
-                        vec![
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                            ),
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                            ),
-                        ],
-                        Token::new(None, DummySyntaxTokenTest::A),
+                    FailedProduction::new(
+                        FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal, 1),
+                        vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
+                            vec![],
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        ))],
+                        vec![],
                     ),
-                    AbstractSyntaxNode::new(
-                        vec![AbstractSyntaxNode::new(
+                    FailedProduction::new(
+                        FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal, 1),
+                        vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
                             vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
-                        )],
-                        Token::new(None, DummySyntaxTokenTest::B),
+                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                        ))],
+                        vec![DummySyntaxTokenTest::ATerminal],
                     ),
-                    AbstractSyntaxNode::new(vec![], Token::new(None, DummySyntaxTokenTest::Eof)),
                 ],
-                Token::new(None, DummySyntaxTokenTest::S),
+                DummySyntaxTokenTest::S,
+                0,
             );
 
-            let abc_expected_ast = AbstractSyntaxTree::new(abc_expected_asn);
+            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
 
-            let abc_expected_parse_result = ParseResult::Ok(abc_expected_ast);
+            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+        }
 
-            let abcd_parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![
-                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::DTerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                ]
-                .into_iter(),
-            );
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum ListSyntaxTokenTest {
+            A,
+            ATerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
 
-            let abcd_expected_asn = AbstractSyntaxNode::new(
-                vec![
-                    AbstractSyntaxNode::new(
-                        vec![AbstractSyntaxNode::new(
-                            vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                        )],
-                        Token::new(None, DummySyntaxTokenTest::A),
-                    ),
-                    AbstractSyntaxNode::new(
-                        vec![
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                            ),
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::CTerminal),
-                            ),
-                            AbstractSyntaxNode::new(
-                                vec![],
-                                Token::new(Some(0u64), DummySyntaxTokenTest::DTerminal),
-                            ),
-                        ],
-                        Token::new(None, DummySyntaxTokenTest::B),
-                    ),
-                    AbstractSyntaxNode::new(
-                        vec![],
-                        Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
-                    ),
-                ],
-                Token::new(None, DummySyntaxTokenTest::S),
-            );
+        fn build_list_grammar(is_left_recursive: bool) -> ContextFreeGrammar<ListSyntaxTokenTest> {
+            let a_recursive_production_output: Vec<ListSyntaxTokenTest> = if is_left_recursive {
+                vec![ListSyntaxTokenTest::A, ListSyntaxTokenTest::ATerminal]
+            } else {
+                vec![ListSyntaxTokenTest::ATerminal, ListSyntaxTokenTest::A]
+            };
 
-            let abcd_expected_ast = AbstractSyntaxTree::new(abcd_expected_asn);
+            let grammar_productions: Vec<ContextFreeGrammarProduction<ListSyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    ListSyntaxTokenTest::S,
+                    vec![ListSyntaxTokenTest::A, ListSyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    ListSyntaxTokenTest::A,
+                    a_recursive_production_output,
+                ),
+                ContextFreeGrammarProduction::new(
+                    ListSyntaxTokenTest::A,
+                    vec![ListSyntaxTokenTest::ATerminal],
+                ),
+            ];
 
-            let abcd_expected_parse_result = ParseResult::Ok(abcd_expected_ast);
+            ContextFreeGrammar::new(
+                ListSyntaxTokenTest::Epsilon,
+                ListSyntaxTokenTest::S,
+                grammar_productions,
+            )
+        }
 
-            assert!(parse_result_equals(
-                &abc_parse_result,
-                &abc_expected_parse_result,
+        fn build_list_tokens(length: usize) -> Vec<Token<u64, ListSyntaxTokenTest>> {
+            let mut tokens: Vec<Token<u64, ListSyntaxTokenTest>> = (0..length)
+                .map(|_| Token::new(Some(0u64), ListSyntaxTokenTest::ATerminal))
+                .collect();
+
+            tokens.push(Token::new(Some(0u64), ListSyntaxTokenTest::Eof));
+
+            tokens
+        }
+
+        #[test]
+        fn it_aborts_when_max_depth_is_exceeded() -> () {
+            let grammar = build_list_grammar(false);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(None, Some(4), None));
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(8).into_iter());
+
+            let expected_parse_result = ParseResult::Abort(ParseAbort::MaxDepthExceeded);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_aborts_when_max_depth_is_exceeded_on_left_recursion() -> () {
+            let grammar = build_list_grammar(true);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(None, Some(64), None));
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(2).into_iter());
+
+            let expected_parse_result = ParseResult::Abort(ParseAbort::MaxDepthExceeded);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_aborts_when_max_steps_are_exceeded() -> () {
+            let grammar = build_list_grammar(false);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(None, None, Some(8)));
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(8).into_iter());
+
+            let expected_parse_result = ParseResult::Abort(ParseAbort::MaxStepsExceeded);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_aborts_when_cancelled() -> () {
+            let grammar = build_list_grammar(false);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let cancellation_flag = Arc::new(AtomicBool::new(true));
+
+            recursive_descent_parser.set_limits(ParseLimits::new(
+                Some(cancellation_flag),
+                None,
+                None,
             ));
-            assert!(parse_result_equals(
-                &abcd_parse_result,
-                &abcd_expected_parse_result,
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(512).into_iter());
+
+            let expected_parse_result = ParseResult::Abort(ParseAbort::Cancelled);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
+        }
+
+        #[test]
+        fn it_aborts_when_cancelled_before_a_short_parse() -> () {
+            let grammar = build_list_grammar(false);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(
+                Some(Arc::new(AtomicBool::new(true))),
+                None,
+                None,
             ));
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(1).into_iter());
+
+            let expected_parse_result = ParseResult::Abort(ParseAbort::Cancelled);
+
+            assert!(parse_result_equals(&parse_result, &expected_parse_result));
         }
 
         #[test]
-        fn it_fails_on_unexpected_symbol_when_no_production_is_found() -> () {
-            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-            enum DummySyntaxTokenTest {
-                ATerminal,
-                BTerminal,
-                Epsilon,
-                S,
+        fn it_parses_within_limits() -> () {
+            let grammar = build_list_grammar(false);
+
+            let mut recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                ListSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, ListSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            recursive_descent_parser.set_limits(ParseLimits::new(
+                Some(Arc::new(AtomicBool::new(false))),
+                Some(16),
+                Some(256),
+            ));
+
+            let parse_result =
+                recursive_descent_parser.parse_from_tokens(build_list_tokens(8).into_iter());
+
+            assert!(matches!(parse_result, ParseResult::Ok(_)));
+        }
+    }
+
+    mod parse_from_tokens_with_observer {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::parse_observer::ParseObserver;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::parser::trace_parse_observer::TraceParseObserver;
+        use crate::token::token::Token;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            BTerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
+
+        struct ParseObserverMock {
+            events: Vec<String>,
+        }
+
+        impl ParseObserver<u64, DummySyntaxTokenTest> for ParseObserverMock {
+            fn on_enter_non_terminal(
+                &mut self,
+                symbol: &DummySyntaxTokenTest,
+                token_position: usize,
+            ) {
+                self.events
+                    .push(format!("enter {:?} {}", symbol, token_position));
             }
 
-            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
-                vec![ContextFreeGrammarProduction::new(
+            fn on_exit_non_terminal(
+                &mut self,
+                symbol: &DummySyntaxTokenTest,
+                token_position: usize,
+                is_success: bool,
+            ) {
+                self.events.push(format!(
+                    "exit {:?} {} {}",
+                    symbol, token_position, is_success
+                ));
+            }
+
+            fn on_try_production(
+                &mut self,
+                symbol: &DummySyntaxTokenTest,
+                production_output: &[DummySyntaxTokenTest],
+                token_position: usize,
+            ) {
+                self.events.push(format!(
+                    "try {:?} {:?} {}",
+                    symbol, production_output, token_position
+                ));
+            }
+
+            fn on_reject_production(
+                &mut self,
+                symbol: &DummySyntaxTokenTest,
+                production_output: &[DummySyntaxTokenTest],
+                token_position: usize,
+            ) {
+                self.events.push(format!(
+                    "reject {:?} {:?} {}",
+                    symbol, production_output, token_position
+                ));
+            }
+
+            fn on_match_terminal(
+                &mut self,
+                token: &Token<u64, DummySyntaxTokenTest>,
+                token_position: usize,
+            ) {
+                self.events
+                    .push(format!("match {:?} {}", token.t_type, token_position));
+            }
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![
+                        DummySyntaxTokenTest::ATerminal,
+                        DummySyntaxTokenTest::BTerminal,
+                    ],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
                     vec![DummySyntaxTokenTest::ATerminal],
-                )];
+                ),
+            ];
 
-            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+            ContextFreeGrammar::new(
                 DummySyntaxTokenTest::Epsilon,
                 DummySyntaxTokenTest::S,
                 grammar_productions,
-            );
+            )
+        }
+
+        #[test]
+        fn it_notifies_parse_events() -> () {
+            let grammar = build_grammar();
 
             let recursive_descent_parser: RecursiveDescentParser<
                 u64,
@@ -750,42 +2387,37 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal)].into_iter(),
-            );
+            let mut parse_observer = ParseObserverMock { events: vec![] };
 
-            let expected_failed_symbol =
-                <FailedSymbol<u64, DummySyntaxTokenTest>>::new(vec![], DummySyntaxTokenTest::S);
+            recursive_descent_parser.parse_from_tokens_with_observer(
+                vec![
+                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
+                ]
+                .into_iter(),
+                &mut parse_observer,
+            );
 
-            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+            let expected_events: Vec<&str> = vec![
+                "enter S 0",
+                "try S [A, Eof] 0",
+                "enter A 0",
+                "try A [ATerminal, BTerminal] 0",
+                "match ATerminal 0",
+                "reject A [ATerminal, BTerminal] 0",
+                "try A [ATerminal] 0",
+                "match ATerminal 0",
+                "exit A 0 true",
+                "match Eof 1",
+                "exit S 0 true",
+            ];
 
-            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+            assert_eq!(parse_observer.events, expected_events);
         }
 
         #[test]
-        fn it_fails_on_unexpected_symbol_when_a_production_is_found() -> () {
-            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-            enum DummySyntaxTokenTest {
-                ATerminal,
-                BTerminal,
-                Epsilon,
-                S,
-            }
-
-            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
-                vec![ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::S,
-                    vec![
-                        DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::ATerminal,
-                    ],
-                )];
-
-            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
-                DummySyntaxTokenTest::Epsilon,
-                DummySyntaxTokenTest::S,
-                grammar_productions,
-            );
+        fn it_writes_an_indented_trace() -> () {
+            let grammar = build_grammar();
 
             let recursive_descent_parser: RecursiveDescentParser<
                 u64,
@@ -793,128 +2425,301 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let parse_result = recursive_descent_parser.parse_from_tokens(
+            let mut trace_parse_observer = TraceParseObserver::new(Vec::<u8>::new());
+
+            recursive_descent_parser.parse_from_tokens_with_observer(
                 vec![
                     Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
                     Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                    Token::new(Some(0u64), DummySyntaxTokenTest::Eof),
                 ]
                 .into_iter(),
+                &mut trace_parse_observer,
             );
 
-            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
-                vec![FailedProduction::new(
-                    FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal),
-                    vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
-                        vec![],
-                        Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                    ))],
-                    vec![],
-                )],
-                DummySyntaxTokenTest::S,
-            );
+            let trace = String::from_utf8(trace_parse_observer.into_writer()).unwrap();
+
+            let expected_trace = "\
+enter S at 0
+  try S → [A, Eof] at 0
+  enter A at 0
+    try A → [ATerminal, BTerminal] at 0
+    match ATerminal at 0
+    match BTerminal at 1
+  exit A at 0 (success)
+  match Eof at 2
+exit S at 0 (success)
+";
+
+            assert_eq!(trace, expected_trace);
+        }
+    }
 
-            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+    mod parse_from_tokens_with_recovery {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recovery_parse_result::RecoveryParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::parser::single_token_solver::SingleTokenSolver;
+        use crate::token::token::Token;
 
-            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum StatementSyntaxTokenTest {
+            Assign,
+            Eof,
+            Epsilon,
+            Id,
+            Number,
+            S,
+            Semicolon,
+            Stmt,
         }
 
-        #[test]
-        fn it_fails_on_unexpected_symbol_when_a_production_is_found_with_parsed_symbols_ordered(
-        ) -> () {
-            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-            enum DummySyntaxTokenTest {
-                ATerminal,
-                BTerminal,
-                Epsilon,
-                S,
-            }
-
-            let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> =
-                vec![ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::S,
+        fn build_grammar() -> ContextFreeGrammar<StatementSyntaxTokenTest> {
+            let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+                ContextFreeGrammarProduction::new(
+                    StatementSyntaxTokenTest::S,
                     vec![
-                        DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::BTerminal,
-                        DummySyntaxTokenTest::ATerminal,
+                        StatementSyntaxTokenTest::Stmt,
+                        StatementSyntaxTokenTest::Semicolon,
+                        StatementSyntaxTokenTest::Stmt,
+                        StatementSyntaxTokenTest::Eof,
                     ],
-                )];
+                ),
+                ContextFreeGrammarProduction::new(
+                    StatementSyntaxTokenTest::Stmt,
+                    vec![
+                        StatementSyntaxTokenTest::Id,
+                        StatementSyntaxTokenTest::Assign,
+                        StatementSyntaxTokenTest::Number,
+                    ],
+                ),
+            ];
 
-            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
-                DummySyntaxTokenTest::Epsilon,
-                DummySyntaxTokenTest::S,
+            ContextFreeGrammar::new(
+                StatementSyntaxTokenTest::Epsilon,
+                StatementSyntaxTokenTest::S,
                 grammar_productions,
-            );
+            )
+        }
+
+        fn build_tokens(
+            token_types: Vec<StatementSyntaxTokenTest>,
+        ) -> Vec<Token<u64, StatementSyntaxTokenTest>> {
+            token_types
+                .into_iter()
+                .map(|t_type| Token::new(Some(0u64), t_type))
+                .collect()
+        }
+
+        fn diagnostic_messages(
+            recovery_parse_result: &RecoveryParseResult<u64, StatementSyntaxTokenTest>,
+        ) -> Vec<String> {
+            recovery_parse_result
+                .diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .collect()
+        }
+
+        #[test]
+        fn it_parses_without_diagnostics() -> () {
+            let grammar = build_grammar();
 
             let recursive_descent_parser: RecursiveDescentParser<
                 u64,
-                DummySyntaxTokenTest,
-                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+                StatementSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, StatementSyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![
-                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                ]
+            let recovery_parse_result = recursive_descent_parser.parse_from_tokens_with_recovery(
+                build_tokens(vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Eof,
+                ])
                 .into_iter(),
             );
 
-            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
-                vec![FailedProduction::new(
-                    FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal),
-                    vec![
-                        ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
-                            vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                        )),
-                        ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
-                            vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                        )),
-                    ],
-                    vec![],
-                )],
-                DummySyntaxTokenTest::S,
+            assert!(recovery_parse_result.diagnostics.is_empty());
+            assert!(matches!(
+                recovery_parse_result.parse_result,
+                ParseResult::Ok(_)
+            ));
+        }
+
+        #[test]
+        fn it_recovers_from_every_syntax_error() -> () {
+            let grammar = build_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                StatementSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, StatementSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let recovery_parse_result = recursive_descent_parser.parse_from_tokens_with_recovery(
+                build_tokens(vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Eof,
+                ])
+                .into_iter(),
             );
 
-            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+            assert_eq!(
+                vec![
+                    "expected `Number` but found `Semicolon` at token 2",
+                    "expected `Assign` but found `Number` at token 4",
+                ],
+                diagnostic_messages(&recovery_parse_result)
+            );
 
-            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+            match recovery_parse_result.parse_result {
+                ParseResult::Fix(fixed_symbol) => {
+                    assert_eq!(StatementSyntaxTokenTest::S, fixed_symbol.symbol_to_derive);
+                    assert_eq!(4, fixed_symbol.fixed_production.fixed_parts.len());
+                }
+                _ => panic!("Expected a fix!"),
+            }
         }
 
         #[test]
-        fn it_fails_on_unexpected_symbol_when_multiple_productions_are_found() -> () {
-            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-            enum DummySyntaxTokenTest {
-                ATerminal,
-                BTerminal,
-                Epsilon,
-                S,
+        fn it_skips_the_tokens_following_the_initial_symbol() -> () {
+            let grammar = build_grammar();
+
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                StatementSyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, StatementSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
+
+            let recovery_parse_result = recursive_descent_parser.parse_from_tokens_with_recovery(
+                build_tokens(vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Eof,
+                    StatementSyntaxTokenTest::Eof,
+                    StatementSyntaxTokenTest::Id,
+                ])
+                .into_iter(),
+            );
+
+            assert_eq!(
+                vec!["expected end of input but found `Eof` at token 8"],
+                diagnostic_messages(&recovery_parse_result)
+            );
+
+            match recovery_parse_result.parse_result {
+                ParseResult::Fix(fixed_symbol) => {
+                    assert_eq!(StatementSyntaxTokenTest::S, fixed_symbol.symbol_to_derive);
+                    assert!(matches!(
+                        fixed_symbol.fixed_production.fixed_parts.as_slice(),
+                        [
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Gap(fix_gap),
+                        ] if fix_gap.skipped_tokens.len() == 2 && fix_gap.skipped_symbols.is_empty()
+                    ));
+                }
+                _ => panic!("Expected a fix!"),
             }
+        }
+
+        #[test]
+        fn it_recovers_from_errors_the_solver_does_not_fix() -> () {
+            let grammar = build_grammar();
+
+            let recursive_descent_parser =
+                RecursiveDescentParser::from_grammar_and_solver(&grammar, SingleTokenSolver::new());
+
+            let recovery_parse_result = recursive_descent_parser.parse_from_tokens_with_recovery(
+                build_tokens(vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Eof,
+                ])
+                .into_iter(),
+            );
+
+            assert_eq!(
+                vec![
+                    "expected `Number` but found `Semicolon` at token 2",
+                    "expected `Assign` but found `Eof` at token 4",
+                ],
+                diagnostic_messages(&recovery_parse_result)
+            );
+            assert!(matches!(
+                recovery_parse_result.parse_result,
+                ParseResult::Fix(_)
+            ));
+        }
+    }
+
+    mod parse_many {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::recursive_descent_parser_spec::test::SyntaxErrorSolverMock;
+        use crate::token::token::Token;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
 
+        fn build_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
             let grammar_productions: Vec<ContextFreeGrammarProduction<DummySyntaxTokenTest>> = vec![
                 ContextFreeGrammarProduction::new(
                     DummySyntaxTokenTest::S,
-                    vec![
-                        DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::ATerminal,
-                    ],
+                    vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
                 ),
                 ContextFreeGrammarProduction::new(
-                    DummySyntaxTokenTest::S,
-                    vec![
-                        DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::ATerminal,
-                        DummySyntaxTokenTest::ATerminal,
-                    ],
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::ATerminal, DummySyntaxTokenTest::A],
+                ),
+                ContextFreeGrammarProduction::new(
+                    DummySyntaxTokenTest::A,
+                    vec![DummySyntaxTokenTest::Epsilon],
                 ),
             ];
 
-            let grammar: ContextFreeGrammar<DummySyntaxTokenTest> = ContextFreeGrammar::new(
+            ContextFreeGrammar::new(
                 DummySyntaxTokenTest::Epsilon,
                 DummySyntaxTokenTest::S,
                 grammar_productions,
-            );
+            )
+        }
+
+        #[test]
+        fn it_parses_every_input_in_order() -> () {
+            let grammar = build_grammar();
 
             let recursive_descent_parser: RecursiveDescentParser<
                 u64,
@@ -922,39 +2727,49 @@ mod test {
                 SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
             > = RecursiveDescentParser::from_grammar(&grammar);
 
-            let parse_result = recursive_descent_parser.parse_from_tokens(
-                vec![
-                    Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                    Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
-                ]
-                .into_iter(),
-            );
+            let inputs: Vec<Vec<Token<u64, DummySyntaxTokenTest>>> = (0..32)
+                .map(|input_index| {
+                    if input_index % 3 == 0 {
+                        vec![]
+                    } else {
+                        let mut tokens: Vec<Token<u64, DummySyntaxTokenTest>> = (0..input_index)
+                            .map(|_| Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal))
+                            .collect();
+
+                        tokens.push(Token::new(Some(0u64), DummySyntaxTokenTest::Eof));
+
+                        tokens
+                    }
+                })
+                .collect();
+
+            let parse_results = recursive_descent_parser.parse_many(inputs);
+
+            assert_eq!(parse_results.len(), 32);
+
+            for (input_index, parse_result) in parse_results.iter().enumerate() {
+                match parse_result {
+                    ParseResult::Ok(tree) => {
+                        assert_ne!(input_index % 3, 0);
+                        assert_eq!(tree.root.child_nodes.len(), 2);
+                    }
+                    ParseResult::InvalidInput(_) => assert_eq!(input_index % 3, 0),
+                    _ => panic!("Expected a tree or an invalid input!"),
+                }
+            }
+        }
 
-            let expected_failed_symbol = <FailedSymbol<u64, DummySyntaxTokenTest>>::new(
-                vec![
-                    FailedProduction::new(
-                        FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal),
-                        vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
-                            vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                        ))],
-                        vec![],
-                    ),
-                    FailedProduction::new(
-                        FailedSymbol::new(vec![], DummySyntaxTokenTest::ATerminal),
-                        vec![ProductionParsedSymbol::Ok(AbstractSyntaxNode::new(
-                            vec![],
-                            Token::new(Some(0u64), DummySyntaxTokenTest::ATerminal),
-                        ))],
-                        vec![DummySyntaxTokenTest::ATerminal],
-                    ),
-                ],
-                DummySyntaxTokenTest::S,
-            );
+        #[test]
+        fn it_parses_no_inputs() -> () {
+            let grammar = build_grammar();
 
-            let expected_parse_result = ParseResult::Err(expected_failed_symbol);
+            let recursive_descent_parser: RecursiveDescentParser<
+                u64,
+                DummySyntaxTokenTest,
+                SyntaxErrorSolverMock<u64, DummySyntaxTokenTest>,
+            > = RecursiveDescentParser::from_grammar(&grammar);
 
-            assert!(parse_result_equals(&parse_result, &expected_parse_result,));
+            assert!(recursive_descent_parser.parse_many(vec![]).is_empty());
         }
     }
 }
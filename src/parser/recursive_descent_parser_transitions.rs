@@ -1,54 +1,193 @@
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
-use std::iter::FromIterator;
+use std::sync::Arc;
 
 use crate::grammar::context_free_grammar::ContextFreeGrammar;
 use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
 use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+use crate::grammar::hash_symbol_map::HashSymbolMap;
+use crate::grammar::symbol_map::SymbolMap;
+use crate::grammar::symbol_set::SymbolSet;
+use crate::grammar::symbol_table::SymbolTable;
 
-pub struct RecursiveDescentParserTransitions<T> {
-    table: HashMap<T, HashMap<T, Vec<ContextFreeGrammarProduction<T>>>>,
+/// Transitions table M[A; a] of a recursive descent parser
+///
+/// The table is a dense matrix with a row per non terminal symbol and a column per terminal
+/// symbol. Every cell holds the indexes of the candidate productions, which refer to the
+/// productions of the row's non terminal symbol.
+///
+/// Symbols are looked up through the `SymbolMap` of the grammar.
+pub struct RecursiveDescentParserTransitions<T, TMap = HashSymbolMap<T>> {
+    cell_offsets: Vec<usize>,
+    columns: Vec<Option<usize>>,
+    columns_count: usize,
+    production_indexes: Vec<u32>,
+    productions: Vec<Vec<ContextFreeGrammarProduction<T>>>,
+    rows: Vec<Option<usize>>,
+    symbol_table: Arc<SymbolTable<T, TMap>>,
 }
 
-impl<T: Eq + Hash> RecursiveDescentParserTransitions<T> {
+impl<T, TMap> RecursiveDescentParserTransitions<T, TMap> {
+    /// Candidate production indexes of M[A; a], given the IDs of A and a.
+    pub fn get_production_indexes_by_id(
+        &self,
+        symbol_to_derive_id: u32,
+        first_symbol_id: u32,
+    ) -> Option<&[u32]> {
+        let cell_index = self.get_cell_index(symbol_to_derive_id, first_symbol_id)?;
+
+        Some(
+            &self.production_indexes
+                [self.cell_offsets[cell_index]..self.cell_offsets[cell_index + 1]],
+        )
+    }
+
+    /// Candidate productions of M[A; a], given the IDs of A and a.
+    pub fn get_productions_by_id(
+        &self,
+        symbol_to_derive_id: u32,
+        first_symbol_id: u32,
+    ) -> Option<Vec<&ContextFreeGrammarProduction<T>>> {
+        let production_indexes =
+            self.get_production_indexes_by_id(symbol_to_derive_id, first_symbol_id)?;
+        let row_productions = &self.productions[self.rows[symbol_to_derive_id as usize]?];
+
+        Some(
+            production_indexes
+                .iter()
+                .map(|production_index| &row_productions[*production_index as usize])
+                .collect(),
+        )
+    }
+
+    fn get_cell_index(&self, symbol_to_derive_id: u32, first_symbol_id: u32) -> Option<usize> {
+        let row = (*self.rows.get(symbol_to_derive_id as usize)?)?;
+        let column = (*self.columns.get(first_symbol_id as usize)?)?;
+
+        Some(row * self.columns_count + column)
+    }
+}
+
+impl<T, TMap: SymbolMap<T>> RecursiveDescentParserTransitions<T, TMap> {
     pub fn get_productions(
         &self,
         symbol_to_derive: &T,
         first_symbol: &T,
-    ) -> Option<&Vec<ContextFreeGrammarProduction<T>>> {
-        let derivations_map_option = self.table.get(symbol_to_derive);
+    ) -> Option<Vec<&ContextFreeGrammarProduction<T>>> {
+        let symbol_to_derive_id = self.symbol_table.get_id(symbol_to_derive)?;
+        let first_symbol_id = self.symbol_table.get_id(first_symbol)?;
 
-        match derivations_map_option {
-            Some(derivations_map) => derivations_map.get(first_symbol),
-            None => None,
-        }
+        self.get_productions_by_id(symbol_to_derive_id, first_symbol_id)
     }
 }
 
-impl<T: Clone + Eq + Hash> RecursiveDescentParserTransitions<T> {
+impl<T: Clone + PartialEq, TMap: SymbolMap<T>> RecursiveDescentParserTransitions<T, TMap> {
     pub fn from(
-        grammar: &ContextFreeGrammar<T>,
-        first_follow_symbols: &FirstFollowSymbols<T>,
-    ) -> RecursiveDescentParserTransitions<T> {
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+    ) -> RecursiveDescentParserTransitions<T, TMap> {
         RecursiveDescentParserTransitions::inner_from(grammar, first_follow_symbols)
     }
 
+    /// Builds a transitions table from its cells.
+    ///
+    /// Every cell M[A; a] is given by A, a and the indexes of its candidate productions among the
+    /// productions of A. Cells referencing unknown symbols or productions are ignored.
+    pub fn new(grammar: &ContextFreeGrammar<T, TMap>, cells: Vec<((T, T), Vec<usize>)>) -> Self {
+        let mut table = Self::inner_from_initial_table(grammar);
+
+        for ((symbol_to_derive, first_symbol), production_indexes) in cells {
+            let productions_count = grammar
+                .get_productions(&symbol_to_derive)
+                .map_or(0, |productions| productions.len());
+
+            let cell_option = grammar
+                .get_symbol_id(&symbol_to_derive)
+                .zip(grammar.get_symbol_id(&first_symbol))
+                .and_then(|(symbol_to_derive_id, first_symbol_id)| {
+                    table.get_cell_index(symbol_to_derive_id, first_symbol_id)
+                });
+
+            if let Some(cell_index) = cell_option {
+                table.cells[cell_index].extend(
+                    production_indexes
+                        .into_iter()
+                        .filter(|production_index| *production_index < productions_count)
+                        .map(|production_index| production_index as u32),
+                );
+            }
+        }
+
+        table.into_transitions(grammar)
+    }
+
+    /// Updates the table after a production is added to or removed from the grammar.
+    ///
+    /// The FIRST / FOLLOW symbols must be already updated, `changed_symbol_ids` being the IDs
+    /// returned by the update. Only the rows of the edited symbol and the rows depending on
+    /// changed symbols are computed again.
+    pub fn update(
+        &mut self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        production: &ContextFreeGrammarProduction<T>,
+        changed_symbol_ids: &SymbolSet,
+    ) {
+        let input_id = match grammar.get_symbol_id(&production.input) {
+            Some(input_id) if self.inner_update_is_symbol_table_compatible(grammar) => input_id,
+            _ => {
+                *self = Self::from(grammar, first_follow_symbols);
+
+                return;
+            }
+        };
+
+        let mut table = Self::inner_from_initial_table(grammar);
+        let terminal_symbol_ids = grammar.get_terminal_symbol_ids();
+
+        for non_terminal_symbol_id in grammar.get_non_terminal_symbol_ids() {
+            let is_row_affected = non_terminal_symbol_id == input_id
+                || changed_symbol_ids.contains(non_terminal_symbol_id)
+                || grammar
+                    .get_production_output_ids(non_terminal_symbol_id)
+                    .iter()
+                    .flatten()
+                    .any(|output_id| changed_symbol_ids.contains(*output_id));
+
+            if is_row_affected {
+                Self::inner_from_process_symbol_productions(
+                    grammar,
+                    first_follow_symbols,
+                    &mut table,
+                    non_terminal_symbol_id,
+                );
+
+                continue;
+            }
+
+            for terminal_symbol_id in &terminal_symbol_ids {
+                let production_indexes_option =
+                    self.get_production_indexes_by_id(non_terminal_symbol_id, *terminal_symbol_id);
+
+                if let (Some(production_indexes), Some(cell_index)) = (
+                    production_indexes_option,
+                    table.get_cell_index(non_terminal_symbol_id, *terminal_symbol_id),
+                ) {
+                    table.cells[cell_index].extend_from_slice(production_indexes);
+                }
+            }
+        }
+
+        *self = table.into_transitions(grammar);
+    }
+
     fn inner_from(
-        grammar: &ContextFreeGrammar<T>,
-        first_follow_symbols: &FirstFollowSymbols<T>,
-    ) -> RecursiveDescentParserTransitions<T> {
-        let non_terminal_symbols = grammar.get_non_terminal_symbols();
-        let terminal_symbols = grammar.get_terminal_symbols();
-        let mut table = Self::inner_from_initial_table(&non_terminal_symbols, &terminal_symbols);
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+    ) -> RecursiveDescentParserTransitions<T, TMap> {
+        let mut table = Self::inner_from_initial_table(grammar);
 
-        Self::inner_from_process_productions(
-            grammar,
-            first_follow_symbols,
-            &mut table,
-            &non_terminal_symbols,
-        );
+        Self::inner_from_process_productions(grammar, first_follow_symbols, &mut table);
 
-        Self { table }
+        table.into_transitions(grammar)
     }
 
     /*
@@ -72,142 +211,231 @@ impl<T: Clone + Eq + Hash> RecursiveDescentParserTransitions<T> {
      *        to M[A; b]. If ε is in FIRST(α) and $ is in FOLLOW(A), add A → α to
      *        M[A; $] as well.
      *
-     * -----------------------------------------------------------------------------
-     *
-     * Unfortunately we don't compute FIRST(α). Fortunately, $ is handled as an
-     * additional terminal symbol in the grammar. Keeping this in mind, there's a
-     * way to determine all the terminal symbols a in which M[A; a] = A → α
-     *
-     * -----------------------------------------------------------------------------
-     *
-     * Alternative algorithm:
-     *
-     * INPUT: Grammar G.
-     * OUTPUT: Parsing table M.
-     * METHOD: For each production A → Bα of the grammar, do the following:
-     *
-     *     1. For each terminal a in FIRST(B), add A → α to M[A; a].
-     *     2.1. If B is terminal and B is ε, then for each terminal b in FOLLOW(A),
-     *          add A → α to M[A; b]
-     *     2.2. If B is non terminal and ε is in FIRST(B), then for each terminal b
-     *          in FOLLOW(B), add A → α to M[A; b].
      */
     fn inner_from_get_production_first_symbols(
-        grammar: &ContextFreeGrammar<T>,
-        production: &ContextFreeGrammarProduction<T>,
-        first_follow_symbols: &FirstFollowSymbols<T>,
-    ) -> HashSet<T> {
-        let production_first_symbols: HashSet<T>;
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        input_id: u32,
+        output_ids: &[u32],
+    ) -> SymbolSet {
+        let mut production_first_symbols =
+            Self::inner_from_get_sequence_first_symbols(grammar, first_follow_symbols, output_ids);
 
-        let symbol = production.output.get(0).unwrap();
+        if production_first_symbols.remove(grammar.get_epsilon_symbol_id()) {
+            production_first_symbols.union_with(&Self::inner_from_get_follow_symbols(
+                grammar,
+                first_follow_symbols,
+                input_id,
+            ));
+        }
 
-        let symbol_first_symbols = first_follow_symbols.get_first_symbols(&symbol).unwrap();
+        production_first_symbols
+    }
 
-        if symbol_first_symbols.contains(grammar.get_epsilon_symbol()) {
-            production_first_symbols =
-                Self::inner_from_get_production_first_symbols_on_epsilon_first(
-                    grammar,
-                    production,
-                    first_follow_symbols,
-                    symbol,
-                    symbol_first_symbols,
-                );
+    fn inner_from_get_sequence_first_symbols(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        symbol_ids: &[u32],
+    ) -> SymbolSet {
+        if Arc::ptr_eq(
+            grammar.get_symbol_table(),
+            first_follow_symbols.get_symbol_table(),
+        ) {
+            first_follow_symbols
+                .get_first_symbol_set_of_sequence(symbol_ids)
+                .unwrap()
         } else {
-            production_first_symbols =
-                Self::inner_from_get_production_first_symbols_on_non_epsilon_first(
-                    symbol_first_symbols,
-                );
+            let symbols: Vec<T> = symbol_ids
+                .iter()
+                .map(|symbol_id| grammar.get_symbol_table().get_symbol(*symbol_id).clone())
+                .collect();
+
+            Self::inner_from_to_grammar_symbol_set(
+                grammar,
+                first_follow_symbols
+                    .first_of_sequence(&symbols)
+                    .unwrap()
+                    .iter(),
+            )
         }
+    }
 
-        production_first_symbols
+    fn inner_from_get_follow_symbols(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        symbol_id: u32,
+    ) -> SymbolSet {
+        if Arc::ptr_eq(
+            grammar.get_symbol_table(),
+            first_follow_symbols.get_symbol_table(),
+        ) {
+            first_follow_symbols
+                .get_follow_symbol_set(symbol_id)
+                .unwrap()
+                .clone()
+        } else {
+            let symbol = grammar.get_symbol_table().get_symbol(symbol_id);
+
+            Self::inner_from_to_grammar_symbol_set(
+                grammar,
+                first_follow_symbols
+                    .get_follow_symbols(symbol)
+                    .unwrap()
+                    .iter(),
+            )
+        }
     }
 
-    fn inner_from_get_production_first_symbols_on_epsilon_first(
-        grammar: &ContextFreeGrammar<T>,
-        production: &ContextFreeGrammarProduction<T>,
-        first_follow_symbols: &FirstFollowSymbols<T>,
-        symbol: &T,
-        symbol_first_symbols: &HashSet<T>,
-    ) -> HashSet<T> {
-        let mut production_first_symbols = HashSet::new();
+    fn inner_from_initial_table(
+        grammar: &ContextFreeGrammar<T, TMap>,
+    ) -> TransitionsTable<T, TMap> {
+        let symbols_count = grammar.get_symbol_table().len();
 
-        symbol_first_symbols
-            .iter()
-            .filter(|symbol| grammar.get_epsilon_symbol().ne(*symbol))
-            .for_each(|symbol| {
-                production_first_symbols.insert(symbol.clone());
-            });
+        let mut rows: Vec<Option<usize>> = vec![None; symbols_count];
+        let mut columns: Vec<Option<usize>> = vec![None; symbols_count];
 
-        let symbol_follow_symbols: &HashSet<T>;
+        let non_terminal_symbol_ids = grammar.get_non_terminal_symbol_ids();
+        let terminal_symbol_ids = grammar.get_terminal_symbol_ids();
 
-        if grammar.get_epsilon_symbol().eq(symbol) {
-            symbol_follow_symbols = first_follow_symbols
-                .get_follow_symbols(&production.input)
-                .unwrap();
-        } else {
-            symbol_follow_symbols = first_follow_symbols.get_follow_symbols(&symbol).unwrap();
+        for (row, symbol_id) in non_terminal_symbol_ids.iter().enumerate() {
+            rows[*symbol_id as usize] = Some(row);
         }
 
-        symbol_follow_symbols.iter().for_each(|symbol| {
-            production_first_symbols.insert(symbol.clone());
-        });
+        for (column, symbol_id) in terminal_symbol_ids.iter().enumerate() {
+            columns[*symbol_id as usize] = Some(column);
+        }
 
-        production_first_symbols
+        TransitionsTable {
+            cells: vec![vec![]; non_terminal_symbol_ids.len() * terminal_symbol_ids.len()],
+            columns,
+            columns_count: terminal_symbol_ids.len(),
+            non_terminal_symbol_ids,
+            rows,
+            symbol_table: grammar.get_symbol_table().clone(),
+        }
     }
 
-    fn inner_from_get_production_first_symbols_on_non_epsilon_first(
-        symbol_first_symbols: &HashSet<T>,
-    ) -> HashSet<T> {
-        let mut production_first_symbols = HashSet::new();
+    fn inner_from_process_productions(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        table: &mut TransitionsTable<T, TMap>,
+    ) {
+        for non_terminal_symbol_id in grammar.get_non_terminal_symbol_ids() {
+            Self::inner_from_process_symbol_productions(
+                grammar,
+                first_follow_symbols,
+                table,
+                non_terminal_symbol_id,
+            );
+        }
+    }
 
-        symbol_first_symbols.iter().for_each(|symbol| {
-            production_first_symbols.insert(symbol.clone());
-        });
+    fn inner_from_process_symbol_productions(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        first_follow_symbols: &FirstFollowSymbols<T, TMap>,
+        table: &mut TransitionsTable<T, TMap>,
+        non_terminal_symbol_id: u32,
+    ) {
+        let production_output_ids = grammar.get_production_output_ids(non_terminal_symbol_id);
 
-        production_first_symbols
+        for (production_index, output_ids) in production_output_ids.iter().enumerate() {
+            let production_first_symbols = Self::inner_from_get_production_first_symbols(
+                grammar,
+                first_follow_symbols,
+                non_terminal_symbol_id,
+                output_ids,
+            );
+
+            for production_first_symbol_id in production_first_symbols.iter() {
+                if let Some(cell_index) =
+                    table.get_cell_index(non_terminal_symbol_id, production_first_symbol_id)
+                {
+                    table.cells[cell_index].push(production_index as u32);
+                }
+            }
+        }
     }
 
-    fn inner_from_initial_table(
-        non_terminal_symbols: &Vec<T>,
-        terminal_symbols: &Vec<T>,
-    ) -> HashMap<T, HashMap<T, Vec<ContextFreeGrammarProduction<T>>>> {
-        HashMap::from_iter(non_terminal_symbols.iter().map(|symbol| {
-            (
-                symbol.clone(),
-                HashMap::from_iter(
-                    terminal_symbols
-                        .iter()
-                        .map(|symbol| (symbol.clone(), vec![])),
-                ),
-            )
-        }))
+    /// Whether the IDs of the table are the ones of the grammar.
+    fn inner_update_is_symbol_table_compatible(
+        &self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+    ) -> bool {
+        Arc::ptr_eq(&self.symbol_table, grammar.get_symbol_table())
+            || grammar
+                .get_symbol_table()
+                .get_symbols()
+                .starts_with(self.symbol_table.get_symbols())
     }
 
-    fn inner_from_process_productions(
-        grammar: &ContextFreeGrammar<T>,
-        first_follow_symbols: &FirstFollowSymbols<T>,
-        table: &mut HashMap<T, HashMap<T, Vec<ContextFreeGrammarProduction<T>>>>,
-        non_terminal_symbols: &Vec<T>,
-    ) {
-        for non_terminal_symbol in non_terminal_symbols {
-            let symbol_productions = grammar.get_productions(non_terminal_symbol).unwrap();
+    fn inner_from_to_grammar_symbol_set<'a, TIter: Iterator<Item = &'a T>>(
+        grammar: &ContextFreeGrammar<T, TMap>,
+        symbols: TIter,
+    ) -> SymbolSet
+    where
+        T: 'a,
+    {
+        let mut symbol_set = SymbolSet::new();
 
-            let symbol_ref_table = table.get_mut(non_terminal_symbol).unwrap();
+        for symbol in symbols {
+            if let Some(symbol_id) = grammar.get_symbol_id(symbol) {
+                symbol_set.insert(symbol_id);
+            }
+        }
 
-            for symbol_production in symbol_productions {
-                let production_first_symbols = Self::inner_from_get_production_first_symbols(
-                    grammar,
-                    symbol_production,
-                    first_follow_symbols,
-                );
+        symbol_set
+    }
+}
 
-                for production_first_symbol in &production_first_symbols {
-                    let symbol_symbol_productions =
-                        symbol_ref_table.get_mut(production_first_symbol).unwrap();
+struct TransitionsTable<T, TMap> {
+    cells: Vec<Vec<u32>>,
+    columns: Vec<Option<usize>>,
+    columns_count: usize,
+    non_terminal_symbol_ids: Vec<u32>,
+    rows: Vec<Option<usize>>,
+    symbol_table: Arc<SymbolTable<T, TMap>>,
+}
 
-                    symbol_symbol_productions.push((*symbol_production).clone());
-                }
-            }
+impl<T: Clone + PartialEq, TMap> TransitionsTable<T, TMap> {
+    fn get_cell_index(&self, symbol_to_derive_id: u32, first_symbol_id: u32) -> Option<usize> {
+        let row = (*self.rows.get(symbol_to_derive_id as usize)?)?;
+        let column = (*self.columns.get(first_symbol_id as usize)?)?;
+
+        Some(row * self.columns_count + column)
+    }
+
+    fn into_transitions(
+        self,
+        grammar: &ContextFreeGrammar<T, TMap>,
+    ) -> RecursiveDescentParserTransitions<T, TMap> {
+        let mut cell_offsets: Vec<usize> = Vec::with_capacity(self.cells.len() + 1);
+        let mut production_indexes: Vec<u32> = vec![];
+
+        cell_offsets.push(0);
+
+        for cell in self.cells {
+            production_indexes.extend(cell);
+            cell_offsets.push(production_indexes.len());
+        }
+
+        let productions: Vec<Vec<ContextFreeGrammarProduction<T>>> = self
+            .non_terminal_symbol_ids
+            .iter()
+            .map(|symbol_id| {
+                grammar
+                    .get_productions_by_id(*symbol_id)
+                    .map_or(vec![], |productions| productions.clone())
+            })
+            .collect();
+
+        RecursiveDescentParserTransitions {
+            cell_offsets,
+            columns: self.columns,
+            columns_count: self.columns_count,
+            production_indexes,
+            productions,
+            rows: self.rows,
+            symbol_table: self.symbol_table,
         }
     }
 }
@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::unused_unit)]
 mod test {
     mod from_grammar {
         use crate::grammar::context_free_grammar::ContextFreeGrammar;
@@ -85,7 +86,7 @@ mod test {
                 )
                 .unwrap();
 
-            let first_module_id_production = module_id_productions.get(0).unwrap();
+            let first_module_id_production = module_id_productions.first().unwrap();
             let expected_first_module_id_production = ContextFreeGrammarProduction::new(
                 SyntaxTokenTest::Module,
                 vec![
@@ -150,7 +151,7 @@ mod test {
                 )
                 .unwrap();
 
-            let first_module_eof_production = module_eof_productions.get(0).unwrap();
+            let first_module_eof_production = module_eof_productions.first().unwrap();
             let expected_first_module_id_production = ContextFreeGrammarProduction::new(
                 SyntaxTokenTest::Module,
                 vec![
@@ -216,7 +217,7 @@ mod test {
                 )
                 .unwrap();
 
-            let first_module_id_production = module_id_productions.get(0).unwrap();
+            let first_module_id_production = module_id_productions.first().unwrap();
             let expected_first_module_id_production = ContextFreeGrammarProduction::new(
                 SyntaxTokenTest::Module,
                 vec![
@@ -271,7 +272,7 @@ mod test {
                 .get_productions(&DummySyntaxTokenTest::Empty, &DummySyntaxTokenTest::Eof)
                 .unwrap();
 
-            let first_empty_eof_production = empty_eof_productions.get(0).unwrap();
+            let first_empty_eof_production = empty_eof_productions.first().unwrap();
             let expected_first_empty_eof_production = ContextFreeGrammarProduction::new(
                 DummySyntaxTokenTest::Empty,
                 vec![DummySyntaxTokenTest::Epsilon],
@@ -284,4 +285,274 @@ mod test {
             ));
         }
     }
+
+    mod from_sequence_first_symbols {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+        use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            C,
+            S,
+            BTerminal,
+            CTerminal,
+            XTerminal,
+            YTerminal,
+        }
+
+        #[test]
+        fn it_adds_productions_on_first_symbols_of_the_whole_production() -> () {
+            let grammar: ContextFreeGrammar<SyntaxTokenTest> = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::XTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::B, SyntaxTokenTest::YTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::B, SyntaxTokenTest::C],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::C,
+                        vec![SyntaxTokenTest::CTerminal],
+                    ),
+                ],
+            );
+
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+
+            // FOLLOW(B) includes YTerminal, which can not start A → B C
+            for (terminal_symbol, expected_productions_count) in [
+                (SyntaxTokenTest::BTerminal, 1),
+                (SyntaxTokenTest::CTerminal, 1),
+                (SyntaxTokenTest::XTerminal, 0),
+                (SyntaxTokenTest::YTerminal, 0),
+            ] {
+                assert_eq!(
+                    expected_productions_count,
+                    transitions
+                        .get_productions(&SyntaxTokenTest::A, &terminal_symbol)
+                        .unwrap()
+                        .len()
+                );
+            }
+        }
+    }
+
+    mod from_symbol_map {
+        use crate::grammar::btree_symbol_map::BTreeSymbolMap;
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+        use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+        #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            S,
+            ATerminal,
+            Eof,
+        }
+
+        #[test]
+        fn it_adds_productions_of_a_grammar_of_symbols_which_are_not_hash() -> () {
+            let grammar: ContextFreeGrammar<SyntaxTokenTest, BTreeSymbolMap<SyntaxTokenTest>> =
+                ContextFreeGrammar::with_symbol_map(
+                    SyntaxTokenTest::Epsilon,
+                    SyntaxTokenTest::S,
+                    vec![
+                        ContextFreeGrammarProduction::new(
+                            SyntaxTokenTest::S,
+                            vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                        ),
+                        ContextFreeGrammarProduction::new(
+                            SyntaxTokenTest::A,
+                            vec![SyntaxTokenTest::ATerminal],
+                        ),
+                        ContextFreeGrammarProduction::new(
+                            SyntaxTokenTest::A,
+                            vec![SyntaxTokenTest::Epsilon],
+                        ),
+                    ],
+                );
+
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+
+            assert!(first_follow_symbols.is_nullable(&SyntaxTokenTest::A));
+            assert_eq!(
+                1,
+                transitions
+                    .get_productions(&SyntaxTokenTest::S, &SyntaxTokenTest::ATerminal)
+                    .unwrap()
+                    .len()
+            );
+            assert_eq!(
+                1,
+                transitions
+                    .get_productions(&SyntaxTokenTest::A, &SyntaxTokenTest::Eof)
+                    .unwrap()
+                    .len()
+            );
+        }
+    }
+
+    mod update {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+        use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum SyntaxTokenTest {
+            Epsilon,
+            A,
+            B,
+            S,
+            ATerminal,
+            BTerminal,
+            Eof,
+        }
+
+        fn assert_same_transitions(
+            grammar: &ContextFreeGrammar<SyntaxTokenTest>,
+            expected: &RecursiveDescentParserTransitions<SyntaxTokenTest>,
+            actual: &RecursiveDescentParserTransitions<SyntaxTokenTest>,
+        ) -> () {
+            for non_terminal_symbol_id in grammar.get_non_terminal_symbol_ids() {
+                for terminal_symbol_id in grammar.get_terminal_symbol_ids() {
+                    assert_eq!(
+                        expected.get_production_indexes_by_id(
+                            non_terminal_symbol_id,
+                            terminal_symbol_id
+                        ),
+                        actual.get_production_indexes_by_id(
+                            non_terminal_symbol_id,
+                            terminal_symbol_id
+                        ),
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn it_returns_the_same_transitions_as_from() -> () {
+            let mut grammar: ContextFreeGrammar<SyntaxTokenTest> = ContextFreeGrammar::new(
+                SyntaxTokenTest::Epsilon,
+                SyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::S,
+                        vec![SyntaxTokenTest::A, SyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal],
+                    ),
+                ],
+            );
+
+            let mut first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let mut transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+
+            let edits: Vec<(bool, ContextFreeGrammarProduction<SyntaxTokenTest>)> = vec![
+                (
+                    true,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::B, SyntaxTokenTest::A],
+                    ),
+                ),
+                (
+                    true,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                ),
+                (
+                    true,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ),
+                (
+                    false,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::A,
+                        vec![SyntaxTokenTest::ATerminal, SyntaxTokenTest::A],
+                    ),
+                ),
+                (
+                    false,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::BTerminal],
+                    ),
+                ),
+                (
+                    false,
+                    ContextFreeGrammarProduction::new(
+                        SyntaxTokenTest::B,
+                        vec![SyntaxTokenTest::Epsilon],
+                    ),
+                ),
+            ];
+
+            for (is_addition, production) in edits {
+                let changed_symbol_ids = if is_addition {
+                    grammar.add_production(production.clone());
+                    first_follow_symbols.update_added_production(&grammar, &production)
+                } else {
+                    assert!(grammar.remove_production(&production));
+                    first_follow_symbols.update_removed_production(&grammar, &production)
+                };
+
+                transitions.update(
+                    &grammar,
+                    &first_follow_symbols,
+                    &production,
+                    &changed_symbol_ids,
+                );
+
+                assert_same_transitions(
+                    &grammar,
+                    &RecursiveDescentParserTransitions::from(
+                        &grammar,
+                        &FirstFollowSymbols::from(&grammar),
+                    ),
+                    &transitions,
+                );
+            }
+        }
+    }
 }
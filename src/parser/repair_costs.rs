@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Costs of the edits considered by a `MinimumCostSolver`
+///
+/// - `deletion_costs`: cost of deleting a token, by token type.
+/// - `insertion_costs`: cost of inserting a terminal symbol.
+/// - `substitution_costs`: cost of replacing a token by a terminal symbol, by terminal symbol.
+///
+/// Terminal symbols without a specific cost use the default cost of the edit.
+pub struct RepairCosts<TSyntax> {
+    pub default_deletion_cost: u32,
+    pub default_insertion_cost: u32,
+    pub default_substitution_cost: u32,
+    pub deletion_costs: HashMap<TSyntax, u32>,
+    pub insertion_costs: HashMap<TSyntax, u32>,
+    pub substitution_costs: HashMap<TSyntax, u32>,
+}
+
+impl<TSyntax> RepairCosts<TSyntax> {
+    pub fn new(
+        default_deletion_cost: u32,
+        default_insertion_cost: u32,
+        default_substitution_cost: u32,
+    ) -> Self {
+        Self {
+            default_deletion_cost,
+            default_insertion_cost,
+            default_substitution_cost,
+            deletion_costs: HashMap::new(),
+            insertion_costs: HashMap::new(),
+            substitution_costs: HashMap::new(),
+        }
+    }
+}
+
+impl<TSyntax: Eq + Hash> RepairCosts<TSyntax> {
+    pub fn get_deletion_cost(&self, symbol: &TSyntax) -> u32 {
+        *self
+            .deletion_costs
+            .get(symbol)
+            .unwrap_or(&self.default_deletion_cost)
+    }
+
+    pub fn get_insertion_cost(&self, symbol: &TSyntax) -> u32 {
+        *self
+            .insertion_costs
+            .get(symbol)
+            .unwrap_or(&self.default_insertion_cost)
+    }
+
+    pub fn get_substitution_cost(&self, symbol: &TSyntax) -> u32 {
+        *self
+            .substitution_costs
+            .get(symbol)
+            .unwrap_or(&self.default_substitution_cost)
+    }
+}
+
+impl<TSyntax: Clone> Clone for RepairCosts<TSyntax> {
+    fn clone(&self) -> Self {
+        Self {
+            default_deletion_cost: self.default_deletion_cost,
+            default_insertion_cost: self.default_insertion_cost,
+            default_substitution_cost: self.default_substitution_cost,
+            deletion_costs: self.deletion_costs.clone(),
+            insertion_costs: self.insertion_costs.clone(),
+            substitution_costs: self.substitution_costs.clone(),
+        }
+    }
+}
+
+impl<TSyntax> Default for RepairCosts<TSyntax> {
+    /// Every edit costs 1.
+    fn default() -> Self {
+        Self::new(1, 1, 1)
+    }
+}
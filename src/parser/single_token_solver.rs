@@ -0,0 +1,172 @@
+use std::hash::Hash;
+
+use crate::parser::failed_production::FailedProduction;
+use crate::parser::fix_gap::FixGap;
+use crate::parser::fix_result::FixResult;
+use crate::parser::fixed_production::FixedProduction;
+use crate::parser::fixed_production_part::FixedProductionPart;
+use crate::parser::production_parsed_symbol::ProductionParsedSymbol;
+use crate::parser::recovery_context::RecoveryContext;
+use crate::parser::symbol_sequence_matcher::SymbolSequenceMatcher;
+use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+use crate::token::token::Token;
+
+/// Single token insertion / deletion syntax error solver
+///
+/// Given a failed production A → αXβ where X failed at its first token:
+///
+/// 1. If Xβ can be matched from the token after the current one, the current token is deleted.
+/// 2. Otherwise, if X is a terminal symbol and the current token can follow it, X is considered
+///    missing. The current token can follow X if it starts β or, β deriving no tokens, if it is
+///    in FOLLOW(A) or the input ends.
+///
+/// Deleted tokens and missing symbols are recorded as gaps. Errors found after the first token
+/// of X are left to the solver call fixing the production X failed at.
+pub struct SingleTokenSolver {}
+
+impl SingleTokenSolver {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Parts fixing the failed and pending symbols by deleting the current token.
+    fn try_delete_token<TLex: Clone, TSyntax: Clone + Eq + Hash>(
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<(Vec<FixedProductionPart<TLex, TSyntax>>, usize)> {
+        let error_position = failed_production.failed_symbol.token_position;
+        let current_token = tokens.get(error_position)?;
+
+        let symbols: Vec<TSyntax> =
+            std::iter::once(failed_production.failed_symbol.symbol_to_derive.clone())
+                .chain(failed_production.pending_symbols.iter().cloned())
+                .collect();
+
+        let (nodes, final_token_position) =
+            Self::build_matcher(context).match_symbols(tokens, error_position + 1, &symbols)?;
+
+        let fixed_parts = std::iter::once(FixedProductionPart::Gap(FixGap::new(
+            vec![current_token.clone()],
+            vec![],
+        )))
+        .chain(nodes.into_iter().map(FixedProductionPart::Ok))
+        .collect();
+
+        Some((fixed_parts, final_token_position))
+    }
+
+    /// Parts fixing the failed and pending symbols by considering the failed symbol missing.
+    fn try_insert_symbol<TLex: Clone, TSyntax: Clone + Eq + Hash>(
+        context: &RecoveryContext<TSyntax>,
+        tokens: &[Token<TLex, TSyntax>],
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<(Vec<FixedProductionPart<TLex, TSyntax>>, usize)> {
+        if !context
+            .get_grammar()
+            .is_terminal(&failed_production.failed_symbol.symbol_to_derive)
+        {
+            return None;
+        }
+
+        let error_position = failed_production.failed_symbol.token_position;
+
+        let (pending_nodes, final_token_position) = Self::build_matcher(context).match_symbols(
+            tokens,
+            error_position,
+            &failed_production.pending_symbols,
+        )?;
+
+        if final_token_position == error_position
+            && tokens
+                .get(error_position)
+                .is_some_and(|current_token| !Self::is_follow_token(context, current_token))
+        {
+            return None;
+        }
+
+        let fixed_parts = std::iter::once(FixedProductionPart::Gap(FixGap::new(
+            vec![],
+            vec![failed_production.failed_symbol.symbol_to_derive.clone()],
+        )))
+        .chain(pending_nodes.into_iter().map(FixedProductionPart::Ok))
+        .collect();
+
+        Some((fixed_parts, final_token_position))
+    }
+
+    fn build_matcher<'a, TSyntax: Clone + Eq + Hash>(
+        context: &RecoveryContext<'a, TSyntax>,
+    ) -> SymbolSequenceMatcher<'a, TSyntax> {
+        SymbolSequenceMatcher::new(
+            context.get_first_follow_symbols(),
+            context.get_grammar(),
+            context.get_transitions(),
+        )
+    }
+
+    /// Determines whether a token is in FOLLOW of the symbol to fix.
+    fn is_follow_token<TLex, TSyntax: Clone + Eq + Hash>(
+        context: &RecoveryContext<TSyntax>,
+        token: &Token<TLex, TSyntax>,
+    ) -> bool {
+        context
+            .get_first_follow_symbols()
+            .get_follow_symbols(context.get_symbol_to_derive())
+            .is_some_and(|follow_symbols| follow_symbols.contains(&token.t_type))
+    }
+}
+
+impl Default for SingleTokenSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TLex: Clone, TSyntax: Clone + Eq + Hash> SyntaxErrorSolver<TLex, TSyntax>
+    for SingleTokenSolver
+{
+    fn fix_failed_production(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        _tokens_position: usize,
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        if !failed_production
+            .failed_symbol
+            .failed_productions
+            .is_empty()
+        {
+            return None;
+        }
+
+        let (fixed_parts, final_token_position) =
+            Self::try_delete_token(context, tokens, failed_production)
+                .or_else(|| Self::try_insert_symbol(context, tokens, failed_production))?;
+
+        let fixed_parts = failed_production
+            .parsed_symbols
+            .iter()
+            .map(ProductionParsedSymbol::to_fixed_production_part)
+            .chain(fixed_parts)
+            .collect();
+
+        Some(FixResult::new(
+            final_token_position,
+            FixedProduction::new(fixed_parts),
+        ))
+    }
+
+    fn fix_failed_productions(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        failed_productions.iter().find_map(|failed_production| {
+            self.fix_failed_production(context, tokens, tokens_position, failed_production)
+        })
+    }
+}
@@ -0,0 +1,237 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::parser::parse_result::ParseResult;
+    use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+    use crate::parser::single_token_solver::SingleTokenSolver;
+    use crate::token::token::Token;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum StatementSyntaxTokenTest {
+        Assign,
+        Eof,
+        Epsilon,
+        Id,
+        Number,
+        S,
+        Semicolon,
+        Stmt,
+        Unknown,
+        Value,
+    }
+
+    fn parse(tokens: Vec<StatementSyntaxTokenTest>) -> ParseResult<u64, StatementSyntaxTokenTest> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::S,
+                vec![
+                    StatementSyntaxTokenTest::Stmt,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Value,
+                    StatementSyntaxTokenTest::Semicolon,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Number],
+            ),
+        ];
+
+        let grammar = Arc::new(ContextFreeGrammar::new(
+            StatementSyntaxTokenTest::Epsilon,
+            StatementSyntaxTokenTest::S,
+            grammar_productions,
+        ));
+
+        let recursive_descent_parser: RecursiveDescentParser<
+            u64,
+            StatementSyntaxTokenTest,
+            SingleTokenSolver,
+        > = RecursiveDescentParser::from_shared_grammar_and_solver(
+            grammar,
+            SingleTokenSolver::new(),
+        );
+
+        recursive_descent_parser.parse_from_tokens(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(index, t_type)| Token::new(Some(index as u64), t_type)),
+        )
+    }
+
+    mod fix_failed_production {
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::fixed_symbol::FixedSymbol;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::single_token_solver_spec::test::{parse, StatementSyntaxTokenTest};
+
+        fn get_statement_parts(
+            parse_result: &ParseResult<u64, StatementSyntaxTokenTest>,
+        ) -> &Vec<FixedProductionPart<u64, StatementSyntaxTokenTest>> {
+            let fixed_symbol: &FixedSymbol<u64, StatementSyntaxTokenTest> = match parse_result {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol,
+                _ => panic!("expected a fix"),
+            };
+
+            match fixed_symbol.fixed_production.fixed_parts.as_slice() {
+                [FixedProductionPart::Fixed(statement_fixed_symbol), FixedProductionPart::Ok(_)] => {
+                    &statement_fixed_symbol.fixed_production.fixed_parts
+                }
+                _ => panic!("expected a fixed statement"),
+            }
+        }
+
+        #[test]
+        fn it_inserts_a_missing_terminal_at_the_end_of_a_production() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Eof,
+            ]);
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                ] if fix_gap.skipped_tokens.is_empty()
+                    && fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Semicolon]
+            ));
+        }
+
+        #[test]
+        fn it_inserts_missing_terminals_at_the_end_of_input() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Number,
+            ]);
+
+            let fixed_symbol: &FixedSymbol<u64, StatementSyntaxTokenTest> = match &parse_result {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol,
+                _ => panic!("expected a fix"),
+            };
+
+            let statement_parts = match fixed_symbol.fixed_production.fixed_parts.as_slice() {
+                [FixedProductionPart::Fixed(statement_fixed_symbol), FixedProductionPart::Gap(fix_gap)]
+                    if fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Eof] =>
+                {
+                    &statement_fixed_symbol.fixed_production.fixed_parts
+                }
+                _ => panic!("expected a fixed statement followed by a missing end of file"),
+            };
+
+            assert!(matches!(
+                statement_parts.as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                ] if fix_gap.skipped_tokens.is_empty()
+                    && fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Semicolon]
+            ));
+        }
+
+        #[test]
+        fn it_inserts_a_missing_terminal_followed_by_pending_symbols() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Semicolon,
+                StatementSyntaxTokenTest::Eof,
+            ]);
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                    FixedProductionPart::Ok(value_node),
+                    FixedProductionPart::Ok(semicolon_node),
+                ] if fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Assign]
+                    && value_node.token.t_type == StatementSyntaxTokenTest::Value
+                    && semicolon_node.token.t_type == StatementSyntaxTokenTest::Semicolon
+            ));
+        }
+
+        #[test]
+        fn it_deletes_an_unexpected_token() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Semicolon,
+                StatementSyntaxTokenTest::Eof,
+            ]);
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                    FixedProductionPart::Ok(value_node),
+                    FixedProductionPart::Ok(_),
+                ] if fix_gap.skipped_tokens.len() == 1
+                    && fix_gap.skipped_tokens[0].lex == Some(2u64)
+                    && fix_gap.skipped_symbols.is_empty()
+                    && value_node.child_nodes[0].token.lex == Some(3u64)
+            ));
+        }
+
+        #[test]
+        fn it_deletes_a_token_of_unknown_type() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Unknown,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Semicolon,
+                StatementSyntaxTokenTest::Eof,
+            ]);
+
+            assert!(matches!(
+                get_statement_parts(&parse_result).as_slice(),
+                [
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Gap(fix_gap),
+                    FixedProductionPart::Ok(_),
+                    FixedProductionPart::Ok(_),
+                ] if fix_gap.skipped_tokens.len() == 1
+                    && fix_gap.skipped_tokens[0].t_type == StatementSyntaxTokenTest::Unknown
+            ));
+        }
+
+        #[test]
+        fn it_does_not_fix_if_the_current_token_can_not_follow_the_missing_terminal() -> () {
+            let parse_result = parse(vec![
+                StatementSyntaxTokenTest::Id,
+                StatementSyntaxTokenTest::Assign,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Number,
+                StatementSyntaxTokenTest::Eof,
+            ]);
+
+            assert!(matches!(parse_result, ParseResult::Err(_)));
+        }
+    }
+}
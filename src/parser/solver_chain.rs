@@ -0,0 +1,90 @@
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+
+use crate::parser::failed_production::FailedProduction;
+use crate::parser::fix_result::FixResult;
+use crate::parser::recovery_context::RecoveryContext;
+use crate::parser::solver_chain_strategy::SolverChainStrategy;
+use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+use crate::token::token::Token;
+
+/// Syntax error solver trying a list of solvers in order
+///
+/// The fix is selected among the fixes of the solvers according to the `SolverChainStrategy` of
+/// the chain. Solvers are boxed `TSolver` trait objects, `dyn SyntaxErrorSolver` by default. Use
+/// a `SyncSolverChain` to share parsers using the chain across threads.
+pub struct SolverChain<TLex, TSyntax, TSolver: ?Sized = dyn SyntaxErrorSolver<TLex, TSyntax>> {
+    marker: PhantomData<fn() -> (TLex, TSyntax)>,
+    solvers: Vec<Box<TSolver>>,
+    strategy: SolverChainStrategy,
+}
+
+/// Solver chain of `Send + Sync` solvers
+pub type SyncSolverChain<TLex, TSyntax> =
+    SolverChain<TLex, TSyntax, dyn SyntaxErrorSolver<TLex, TSyntax> + Send + Sync>;
+
+impl<TLex, TSyntax, TSolver: SyntaxErrorSolver<TLex, TSyntax> + ?Sized>
+    SolverChain<TLex, TSyntax, TSolver>
+{
+    pub fn new(solvers: Vec<Box<TSolver>>, strategy: SolverChainStrategy) -> Self {
+        Self {
+            marker: PhantomData,
+            solvers,
+            strategy,
+        }
+    }
+
+    /// Fix selected among the fixes of the solvers.
+    fn select_fix<F>(&self, fix: F) -> Option<FixResult<TLex, TSyntax>>
+    where
+        F: Fn(&TSolver) -> Option<FixResult<TLex, TSyntax>>,
+    {
+        let mut fix_results = self.solvers.iter().filter_map(|solver| {
+            fix(solver.as_ref()).map(|fix_result| (solver.as_ref(), fix_result))
+        });
+
+        match self.strategy {
+            SolverChainStrategy::First => fix_results.next().map(|(_, fix_result)| fix_result),
+            SolverChainStrategy::LowestCost => fix_results
+                .enumerate()
+                .min_by_key(|(index, (solver, fix_result))| {
+                    (solver.get_fix_cost(fix_result), *index)
+                })
+                .map(|(_, (_, fix_result))| fix_result),
+            SolverChainStrategy::MostTokens => fix_results
+                .enumerate()
+                .max_by_key(|(index, (_, fix_result))| {
+                    (fix_result.final_token_position, Reverse(*index))
+                })
+                .map(|(_, (_, fix_result))| fix_result),
+        }
+    }
+}
+
+impl<TLex, TSyntax, TSolver: SyntaxErrorSolver<TLex, TSyntax> + ?Sized>
+    SyntaxErrorSolver<TLex, TSyntax> for SolverChain<TLex, TSyntax, TSolver>
+{
+    fn fix_failed_production(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        failed_production: &FailedProduction<TLex, TSyntax>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        self.select_fix(|solver| {
+            solver.fix_failed_production(context, tokens, tokens_position, failed_production)
+        })
+    }
+
+    fn fix_failed_productions(
+        &self,
+        context: &RecoveryContext<TSyntax>,
+        tokens: &Vec<Token<TLex, TSyntax>>,
+        tokens_position: usize,
+        failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
+    ) -> Option<FixResult<TLex, TSyntax>> {
+        self.select_fix(|solver| {
+            solver.fix_failed_productions(context, tokens, tokens_position, failed_productions)
+        })
+    }
+}
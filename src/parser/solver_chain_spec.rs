@@ -0,0 +1,319 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::grammar::context_free_grammar::ContextFreeGrammar;
+    use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+    use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+    use crate::parser::failed_production::FailedProduction;
+    use crate::parser::fix_gap::FixGap;
+    use crate::parser::fix_result::FixResult;
+    use crate::parser::fixed_production::FixedProduction;
+    use crate::parser::fixed_production_part::FixedProductionPart;
+    use crate::parser::recovery_context::RecoveryContext;
+    use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+    use crate::parser::syntax_error_solver::SyntaxErrorSolver;
+    use crate::token::token::Token;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum StatementSyntaxTokenTest {
+        Assign,
+        Eof,
+        Epsilon,
+        Id,
+        Number,
+        S,
+        Semicolon,
+        Stmt,
+        Value,
+    }
+
+    /// Solver providing a fix ending at a given position and skipping a given number of symbols.
+    ///
+    /// The cost of the fix is the number of skipped symbols, unless a cost is reported.
+    struct SyntaxErrorSolverStub {
+        fix: Option<(usize, usize)>,
+        reported_cost: Option<usize>,
+    }
+
+    impl SyntaxErrorSolverStub {
+        fn build_fix_result(&self) -> Option<FixResult<u64, StatementSyntaxTokenTest>> {
+            self.fix.map(|(final_token_position, cost)| {
+                FixResult::new(
+                    final_token_position,
+                    FixedProduction::new(vec![FixedProductionPart::Gap(FixGap::new(
+                        vec![],
+                        vec![StatementSyntaxTokenTest::Semicolon; cost],
+                    ))]),
+                )
+            })
+        }
+    }
+
+    impl SyntaxErrorSolver<u64, StatementSyntaxTokenTest> for SyntaxErrorSolverStub {
+        fn fix_failed_production(
+            &self,
+            _context: &RecoveryContext<StatementSyntaxTokenTest>,
+            _tokens: &Vec<Token<u64, StatementSyntaxTokenTest>>,
+            _tokens_position: usize,
+            _failed_production: &FailedProduction<u64, StatementSyntaxTokenTest>,
+        ) -> Option<FixResult<u64, StatementSyntaxTokenTest>> {
+            self.build_fix_result()
+        }
+
+        fn fix_failed_productions(
+            &self,
+            _context: &RecoveryContext<StatementSyntaxTokenTest>,
+            _tokens: &Vec<Token<u64, StatementSyntaxTokenTest>>,
+            _tokens_position: usize,
+            _failed_productions: &Vec<FailedProduction<u64, StatementSyntaxTokenTest>>,
+        ) -> Option<FixResult<u64, StatementSyntaxTokenTest>> {
+            self.build_fix_result()
+        }
+
+        fn get_fix_cost(&self, fix_result: &FixResult<u64, StatementSyntaxTokenTest>) -> usize {
+            self.reported_cost
+                .unwrap_or_else(|| fix_result.production.get_cost())
+        }
+    }
+
+    fn build_grammar() -> Arc<ContextFreeGrammar<StatementSyntaxTokenTest>> {
+        let grammar_productions: Vec<ContextFreeGrammarProduction<StatementSyntaxTokenTest>> = vec![
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::S,
+                vec![
+                    StatementSyntaxTokenTest::Stmt,
+                    StatementSyntaxTokenTest::Eof,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Stmt,
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Value,
+                    StatementSyntaxTokenTest::Semicolon,
+                ],
+            ),
+            ContextFreeGrammarProduction::new(
+                StatementSyntaxTokenTest::Value,
+                vec![StatementSyntaxTokenTest::Number],
+            ),
+        ];
+
+        Arc::new(ContextFreeGrammar::new(
+            StatementSyntaxTokenTest::Epsilon,
+            StatementSyntaxTokenTest::S,
+            grammar_productions,
+        ))
+    }
+
+    fn build_stubs(
+        fixes: Vec<Option<(usize, usize)>>,
+    ) -> Vec<Box<dyn SyntaxErrorSolver<u64, StatementSyntaxTokenTest>>> {
+        fixes
+            .into_iter()
+            .map(|fix| {
+                Box::new(SyntaxErrorSolverStub {
+                    fix,
+                    reported_cost: None,
+                }) as Box<dyn SyntaxErrorSolver<u64, StatementSyntaxTokenTest>>
+            })
+            .collect()
+    }
+
+    /// Final token position of the fix of a solver with no failed productions.
+    fn fix_final_token_position(
+        solver: &dyn SyntaxErrorSolver<u64, StatementSyntaxTokenTest>,
+    ) -> Option<usize> {
+        let grammar = build_grammar();
+        let first_follow_symbols = FirstFollowSymbols::from(grammar.as_ref());
+        let transitions = RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+        let non_terminal_stack = [StatementSyntaxTokenTest::S];
+        let context = RecoveryContext::new(
+            &first_follow_symbols,
+            &grammar,
+            &non_terminal_stack,
+            &transitions,
+        );
+
+        solver
+            .fix_failed_productions(&context, &vec![], 0, &vec![])
+            .map(|fix_result| fix_result.final_token_position)
+    }
+
+    mod fix_failed_productions {
+        use crate::parser::solver_chain::SolverChain;
+        use crate::parser::solver_chain_spec::test::{
+            build_stubs, fix_final_token_position, SyntaxErrorSolverStub,
+        };
+        use crate::parser::solver_chain_strategy::SolverChainStrategy;
+
+        #[test]
+        fn it_returns_the_first_fix() -> () {
+            let solver_chain = SolverChain::new(
+                build_stubs(vec![None, Some((2, 3)), Some((3, 1))]),
+                SolverChainStrategy::First,
+            );
+
+            assert_eq!(Some(2), fix_final_token_position(&solver_chain));
+        }
+
+        #[test]
+        fn it_returns_the_fix_with_the_lowest_cost() -> () {
+            let solver_chain = SolverChain::new(
+                build_stubs(vec![Some((2, 3)), Some((3, 1)), Some((4, 1))]),
+                SolverChainStrategy::LowestCost,
+            );
+
+            assert_eq!(Some(3), fix_final_token_position(&solver_chain));
+        }
+
+        #[test]
+        fn it_returns_the_fix_with_the_lowest_cost_reported_by_its_solver() -> () {
+            let solver_chain = SolverChain::<_, _>::new(
+                vec![
+                    Box::new(SyntaxErrorSolverStub {
+                        fix: Some((2, 1)),
+                        reported_cost: Some(4),
+                    }),
+                    Box::new(SyntaxErrorSolverStub {
+                        fix: Some((3, 2)),
+                        reported_cost: None,
+                    }),
+                ],
+                SolverChainStrategy::LowestCost,
+            );
+
+            assert_eq!(Some(3), fix_final_token_position(&solver_chain));
+        }
+
+        #[test]
+        fn it_returns_the_fix_consuming_the_most_tokens() -> () {
+            let solver_chain = SolverChain::new(
+                build_stubs(vec![Some((2, 1)), Some((4, 3)), Some((4, 1))]),
+                SolverChainStrategy::MostTokens,
+            );
+
+            assert_eq!(Some(4), fix_final_token_position(&solver_chain));
+        }
+
+        #[test]
+        fn it_does_not_fix_if_no_solver_fixes() -> () {
+            let solver_chain =
+                SolverChain::new(build_stubs(vec![None, None]), SolverChainStrategy::First);
+
+            assert_eq!(None, fix_final_token_position(&solver_chain));
+        }
+    }
+
+    mod parse_from_tokens {
+        use crate::parser::fixed_production_part::FixedProductionPart;
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::single_token_solver::SingleTokenSolver;
+        use crate::parser::solver_chain::SolverChain;
+        use crate::parser::solver_chain_spec::test::{build_grammar, StatementSyntaxTokenTest};
+        use crate::parser::solver_chain_strategy::SolverChainStrategy;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_falls_back_to_the_next_solver() -> () {
+            let recursive_descent_parser = RecursiveDescentParser::from_shared_grammar_and_solver(
+                build_grammar(),
+                SolverChain::<_, _>::new(
+                    vec![
+                        Box::new(SingleTokenSolver::new()),
+                        Box::new(PanicModeSolver::new()),
+                    ],
+                    SolverChainStrategy::First,
+                ),
+            );
+
+            let parse_result = recursive_descent_parser.parse_from_tokens(
+                vec![
+                    StatementSyntaxTokenTest::Id,
+                    StatementSyntaxTokenTest::Assign,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Number,
+                    StatementSyntaxTokenTest::Semicolon,
+                    StatementSyntaxTokenTest::Eof,
+                ]
+                .into_iter()
+                .enumerate()
+                .map(|(index, t_type)| Token::new(Some(index as u64), t_type)),
+            );
+
+            let fixed_symbol = match parse_result {
+                ParseResult::Fix(fixed_symbol) => fixed_symbol,
+                _ => panic!("expected a fix"),
+            };
+
+            assert!(matches!(
+                fixed_symbol.fixed_production.fixed_parts.as_slice(),
+                [FixedProductionPart::Fixed(statement_fixed_symbol), FixedProductionPart::Ok(_)]
+                    if matches!(
+                        statement_fixed_symbol.fixed_production.fixed_parts.as_slice(),
+                        [
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Ok(_),
+                            FixedProductionPart::Gap(fix_gap),
+                        ] if fix_gap.skipped_tokens.len() == 3
+                            && fix_gap.skipped_symbols == vec![StatementSyntaxTokenTest::Semicolon]
+                    )
+            ));
+        }
+    }
+
+    mod parse_many {
+        use crate::parser::panic_mode_solver::PanicModeSolver;
+        use crate::parser::parse_result::ParseResult;
+        use crate::parser::recursive_descent_parser::OwnedRecursiveDescentParser;
+        use crate::parser::recursive_descent_parser::RecursiveDescentParser;
+        use crate::parser::single_token_solver::SingleTokenSolver;
+        use crate::parser::solver_chain::SyncSolverChain;
+        use crate::parser::solver_chain_spec::test::{build_grammar, StatementSyntaxTokenTest};
+        use crate::parser::solver_chain_strategy::SolverChainStrategy;
+        use crate::token::token::Token;
+
+        #[test]
+        fn it_fixes_every_input_from_several_threads() -> () {
+            let recursive_descent_parser: OwnedRecursiveDescentParser<
+                u64,
+                StatementSyntaxTokenTest,
+                SyncSolverChain<u64, StatementSyntaxTokenTest>,
+            > = RecursiveDescentParser::from_shared_grammar_and_solver(
+                build_grammar(),
+                SyncSolverChain::new(
+                    vec![
+                        Box::new(SingleTokenSolver::new()),
+                        Box::new(PanicModeSolver::new()),
+                    ],
+                    SolverChainStrategy::First,
+                ),
+            );
+
+            let inputs: Vec<Vec<Token<u64, StatementSyntaxTokenTest>>> = (0..8)
+                .map(|_| {
+                    vec![
+                        Token::new(Some(0u64), StatementSyntaxTokenTest::Id),
+                        Token::new(Some(1u64), StatementSyntaxTokenTest::Assign),
+                        Token::new(Some(2u64), StatementSyntaxTokenTest::Number),
+                        Token::new(Some(3u64), StatementSyntaxTokenTest::Eof),
+                    ]
+                })
+                .collect();
+
+            let parse_results = recursive_descent_parser.parse_many(inputs);
+
+            assert_eq!(8, parse_results.len());
+            assert!(parse_results
+                .iter()
+                .all(|parse_result| matches!(parse_result, ParseResult::Fix(_))));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+/// Fix selected by a `SolverChain`
+///
+/// - `First`: fix of the first solver providing one. Following solvers are not called.
+/// - `LowestCost`: fix with the lowest cost, as given by `SyntaxErrorSolver.get_fix_cost` of the
+///   solver providing it.
+/// - `MostTokens`: fix consuming the most tokens.
+///
+/// Ties are resolved in favor of the solver appearing first in the chain.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SolverChainStrategy {
+    First,
+    LowestCost,
+    MostTokens,
+}
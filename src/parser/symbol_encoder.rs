@@ -0,0 +1,7 @@
+/// Symbol encoder
+///
+/// Encodes the symbols of a grammar in order to serialize precomputed parse tables. Every symbol
+/// is expected to be encoded as a different non empty string with no whitespaces.
+pub trait SymbolEncoder<T> {
+    fn encode(&self, symbol: &T) -> String;
+}
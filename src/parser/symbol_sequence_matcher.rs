@@ -0,0 +1,149 @@
+use std::hash::Hash;
+
+use crate::ast::abstract_syntax_node::AbstractSyntaxNode;
+use crate::grammar::context_free_grammar::ContextFreeGrammar;
+use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+use crate::token::token::Token;
+
+/// Matches sequences of symbols against tokens, without fixing syntax errors
+///
+/// Candidate productions are taken from the transitions table and tried in order. The first
+/// production matching a non terminal symbol is kept, so no backtracking is done across the
+/// symbols of a sequence. Nullable symbols derive no tokens once the input ends. Intended for
+/// syntax error solvers checking a fix.
+pub struct SymbolSequenceMatcher<'a, TSyntax> {
+    first_follow_symbols: &'a FirstFollowSymbols<TSyntax>,
+    grammar: &'a ContextFreeGrammar<TSyntax>,
+    transitions: &'a RecursiveDescentParserTransitions<TSyntax>,
+}
+
+impl<'a, TSyntax: Clone + Eq + Hash> SymbolSequenceMatcher<'a, TSyntax> {
+    pub fn new(
+        first_follow_symbols: &'a FirstFollowSymbols<TSyntax>,
+        grammar: &'a ContextFreeGrammar<TSyntax>,
+        transitions: &'a RecursiveDescentParserTransitions<TSyntax>,
+    ) -> Self {
+        Self {
+            first_follow_symbols,
+            grammar,
+            transitions,
+        }
+    }
+
+    /// Matches symbols from a token position.
+    ///
+    /// Returns the nodes of the symbols and the position of the first token not consumed.
+    #[allow(clippy::type_complexity)]
+    pub fn match_symbols<TLex: Clone>(
+        &self,
+        tokens: &[Token<TLex, TSyntax>],
+        tokens_position: usize,
+        symbols: &[TSyntax],
+    ) -> Option<(Vec<AbstractSyntaxNode<Token<TLex, TSyntax>>>, usize)> {
+        let mut nodes: Vec<AbstractSyntaxNode<Token<TLex, TSyntax>>> = vec![];
+        let mut current_token_position = tokens_position;
+
+        for symbol in symbols {
+            let (node, final_token_position) =
+                self.match_symbol(tokens, current_token_position, symbol)?;
+
+            nodes.push(node);
+            current_token_position = final_token_position;
+        }
+
+        Some((nodes, current_token_position))
+    }
+
+    fn match_symbol<TLex: Clone>(
+        &self,
+        tokens: &[Token<TLex, TSyntax>],
+        tokens_position: usize,
+        symbol: &TSyntax,
+    ) -> Option<(AbstractSyntaxNode<Token<TLex, TSyntax>>, usize)> {
+        if self.grammar.get_epsilon_symbol().eq(symbol) {
+            return Some((
+                AbstractSyntaxNode::new(vec![], Token::new(None, symbol.clone())),
+                tokens_position,
+            ));
+        }
+
+        let token = match tokens.get(tokens_position) {
+            Some(token) => token,
+            None => {
+                return self
+                    .match_empty_symbol(symbol, &mut vec![])
+                    .map(|node| (node, tokens_position))
+            }
+        };
+
+        if self.grammar.is_non_terminal(symbol) {
+            self.transitions
+                .get_productions(symbol, &token.t_type)?
+                .into_iter()
+                .find_map(|production| {
+                    self.match_symbols(tokens, tokens_position, &production.output)
+                })
+                .map(|(child_nodes, final_token_position)| {
+                    (
+                        AbstractSyntaxNode::new(child_nodes, Token::new(None, symbol.clone())),
+                        final_token_position,
+                    )
+                })
+        } else if token.t_type.eq(symbol) {
+            Some((
+                AbstractSyntaxNode::new(vec![], token.clone()),
+                tokens_position + 1,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Matches a symbol deriving no tokens.
+    ///
+    /// Productions deriving a symbol already being matched are skipped, so cycles of nullable
+    /// symbols end.
+    fn match_empty_symbol<TLex>(
+        &self,
+        symbol: &TSyntax,
+        derived_symbols: &mut Vec<TSyntax>,
+    ) -> Option<AbstractSyntaxNode<Token<TLex, TSyntax>>> {
+        if self.grammar.get_epsilon_symbol().eq(symbol) {
+            return Some(AbstractSyntaxNode::new(
+                vec![],
+                Token::new(None, symbol.clone()),
+            ));
+        }
+
+        if !self.grammar.is_non_terminal(symbol) || !self.first_follow_symbols.is_nullable(symbol) {
+            return None;
+        }
+
+        let productions = self.grammar.get_productions(symbol)?;
+
+        derived_symbols.push(symbol.clone());
+
+        let child_nodes_option = productions.iter().find_map(|production| {
+            if production
+                .output
+                .iter()
+                .any(|output_symbol| derived_symbols.contains(output_symbol))
+            {
+                return None;
+            }
+
+            production
+                .output
+                .iter()
+                .map(|output_symbol| self.match_empty_symbol(output_symbol, derived_symbols))
+                .collect::<Option<Vec<AbstractSyntaxNode<Token<TLex, TSyntax>>>>>()
+        });
+
+        derived_symbols.pop();
+
+        child_nodes_option.map(|child_nodes| {
+            AbstractSyntaxNode::new(child_nodes, Token::new(None, symbol.clone()))
+        })
+    }
+}
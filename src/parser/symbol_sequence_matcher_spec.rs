@@ -0,0 +1,130 @@
+#[cfg(test)]
+#[allow(clippy::unused_unit)]
+mod test {
+    mod match_symbols {
+        use crate::grammar::context_free_grammar::ContextFreeGrammar;
+        use crate::grammar::context_free_grammar_production::ContextFreeGrammarProduction;
+        use crate::grammar::first_follow_symbols::FirstFollowSymbols;
+        use crate::parser::recursive_descent_parser_transitions::RecursiveDescentParserTransitions;
+        use crate::parser::symbol_sequence_matcher::SymbolSequenceMatcher;
+        use crate::token::token::Token;
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        enum DummySyntaxTokenTest {
+            A,
+            ATerminal,
+            BTerminal,
+            Eof,
+            Epsilon,
+            S,
+        }
+
+        fn build_grammar() -> ContextFreeGrammar<DummySyntaxTokenTest> {
+            ContextFreeGrammar::new(
+                DummySyntaxTokenTest::Epsilon,
+                DummySyntaxTokenTest::S,
+                vec![
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::S,
+                        vec![DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![
+                            DummySyntaxTokenTest::ATerminal,
+                            DummySyntaxTokenTest::BTerminal,
+                        ],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![DummySyntaxTokenTest::ATerminal],
+                    ),
+                    ContextFreeGrammarProduction::new(
+                        DummySyntaxTokenTest::A,
+                        vec![DummySyntaxTokenTest::Epsilon],
+                    ),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_matches_symbols_from_a_position() -> () {
+            let grammar = build_grammar();
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+            let matcher = SymbolSequenceMatcher::new(&first_follow_symbols, &grammar, &transitions);
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::ATerminal),
+                Token::new(Some(2u64), DummySyntaxTokenTest::Eof),
+            ];
+
+            let (nodes, final_token_position) = matcher
+                .match_symbols(
+                    &tokens,
+                    1,
+                    &[DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                )
+                .unwrap();
+
+            assert_eq!(3, final_token_position);
+            assert_eq!(2, nodes.len());
+            assert_eq!(DummySyntaxTokenTest::A, nodes[0].token.t_type);
+            assert_eq!(
+                DummySyntaxTokenTest::ATerminal,
+                nodes[0].child_nodes[0].token.t_type
+            );
+        }
+
+        #[test]
+        fn it_matches_nullable_symbols_at_the_end_of_input() -> () {
+            let grammar = build_grammar();
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+            let matcher = SymbolSequenceMatcher::new(&first_follow_symbols, &grammar, &transitions);
+
+            let tokens = vec![Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal)];
+
+            let (nodes, final_token_position) = matcher
+                .match_symbols(
+                    &tokens,
+                    0,
+                    &[DummySyntaxTokenTest::BTerminal, DummySyntaxTokenTest::A],
+                )
+                .unwrap();
+
+            assert_eq!(1, final_token_position);
+            assert_eq!(2, nodes.len());
+            assert_eq!(DummySyntaxTokenTest::A, nodes[1].token.t_type);
+            assert_eq!(
+                DummySyntaxTokenTest::Epsilon,
+                nodes[1].child_nodes[0].token.t_type
+            );
+        }
+
+        #[test]
+        fn it_does_not_match_unexpected_tokens() -> () {
+            let grammar = build_grammar();
+            let first_follow_symbols = FirstFollowSymbols::from(&grammar);
+            let transitions =
+                RecursiveDescentParserTransitions::from(&grammar, &first_follow_symbols);
+            let matcher = SymbolSequenceMatcher::new(&first_follow_symbols, &grammar, &transitions);
+
+            let tokens = vec![
+                Token::new(Some(0u64), DummySyntaxTokenTest::BTerminal),
+                Token::new(Some(1u64), DummySyntaxTokenTest::Eof),
+            ];
+
+            assert!(matcher
+                .match_symbols(
+                    &tokens,
+                    0,
+                    &[DummySyntaxTokenTest::A, DummySyntaxTokenTest::Eof],
+                )
+                .is_none());
+        }
+    }
+}
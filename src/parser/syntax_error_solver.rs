@@ -1,11 +1,15 @@
 use crate::parser::failed_production::FailedProduction;
 use crate::parser::fix_result::FixResult;
+use crate::parser::recovery_context::RecoveryContext;
 use crate::token::token::Token;
 
 /// Syntax error solver
 ///
 /// Fixes syntax errors of a RecursiveDescentParser
 ///
+/// Every fix attempt receives a `RecoveryContext` exposing the grammar, its FIRST / FOLLOW
+/// symbols, the transitions table and the non terminal symbols being derived.
+///
 /// A fix attempt is the last resource used in order to parse an input.
 /// It's safe to make these assumptions:
 ///
@@ -15,6 +19,7 @@ use crate::token::token::Token;
 ///    Any attempt to fix C → E results in an attempt to fix A → BCD if the attempt to fix C → E
 ///    is not successful
 ///
+#[allow(clippy::ptr_arg)]
 pub trait SyntaxErrorSolver<TLex, TSyntax> {
     /// Called when a symbol has a single candidate production.
     ///
@@ -28,6 +33,7 @@ pub trait SyntaxErrorSolver<TLex, TSyntax> {
     /// The solver may consider failing at this point in order to fix the parent production.
     fn fix_failed_production(
         &self,
+        context: &RecoveryContext<TSyntax>,
         tokens: &Vec<Token<TLex, TSyntax>>,
         tokens_position: usize,
         failed_production: &FailedProduction<TLex, TSyntax>,
@@ -44,8 +50,16 @@ pub trait SyntaxErrorSolver<TLex, TSyntax> {
     /// The solver may consider failing at this point in order to fix the parent production.
     fn fix_failed_productions(
         &self,
+        context: &RecoveryContext<TSyntax>,
         tokens: &Vec<Token<TLex, TSyntax>>,
         tokens_position: usize,
         failed_productions: &Vec<FailedProduction<TLex, TSyntax>>,
     ) -> Option<FixResult<TLex, TSyntax>>;
+
+    /// Cost of a fix provided by the solver, used to compare the fixes of several solvers.
+    ///
+    /// Defaults to `FixedProduction.get_cost`, the number of skipped tokens and symbols.
+    fn get_fix_cost(&self, fix_result: &FixResult<TLex, TSyntax>) -> usize {
+        fix_result.production.get_cost()
+    }
 }
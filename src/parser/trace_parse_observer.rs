@@ -0,0 +1,88 @@
+use std::fmt::Debug;
+use std::io::Write;
+
+use crate::parser::parse_observer::ParseObserver;
+use crate::token::token::Token;
+
+const INDENTATION: &str = "  ";
+
+/// Parse observer which writes an indented trace of the parse
+///
+/// Every derivation of a non terminal symbol increases the indentation of the trace. Write
+/// errors are ignored.
+pub struct TraceParseObserver<TWrite: Write> {
+    depth: usize,
+    writer: TWrite,
+}
+
+impl<TWrite: Write> TraceParseObserver<TWrite> {
+    pub fn new(writer: TWrite) -> Self {
+        Self { depth: 0, writer }
+    }
+
+    pub fn into_writer(self) -> TWrite {
+        self.writer
+    }
+
+    fn write_line(&mut self, line: String) {
+        let indentation = INDENTATION.repeat(self.depth);
+
+        let _ = writeln!(self.writer, "{}{}", indentation, line);
+    }
+}
+
+impl<TLex, TSyntax: Debug, TWrite: Write> ParseObserver<TLex, TSyntax>
+    for TraceParseObserver<TWrite>
+{
+    fn on_enter_non_terminal(&mut self, symbol: &TSyntax, token_position: usize) {
+        self.write_line(format!("enter {:?} at {}", symbol, token_position));
+        self.depth += 1;
+    }
+
+    fn on_exit_non_terminal(&mut self, symbol: &TSyntax, token_position: usize, is_success: bool) {
+        self.depth = self.depth.saturating_sub(1);
+
+        let outcome = if is_success { "success" } else { "failure" };
+
+        self.write_line(format!(
+            "exit {:?} at {} ({})",
+            symbol, token_position, outcome
+        ));
+    }
+
+    fn on_try_production(
+        &mut self,
+        symbol: &TSyntax,
+        production_output: &[TSyntax],
+        token_position: usize,
+    ) {
+        self.write_line(format!(
+            "try {:?} → {:?} at {}",
+            symbol, production_output, token_position
+        ));
+    }
+
+    fn on_reject_production(
+        &mut self,
+        symbol: &TSyntax,
+        production_output: &[TSyntax],
+        token_position: usize,
+    ) {
+        self.write_line(format!(
+            "reject {:?} → {:?} at {}",
+            symbol, production_output, token_position
+        ));
+    }
+
+    fn on_match_terminal(&mut self, token: &Token<TLex, TSyntax>, token_position: usize) {
+        self.write_line(format!("match {:?} at {}", token.t_type, token_position));
+    }
+
+    fn on_backtrack(&mut self, symbol: &TSyntax, token_position: usize) {
+        self.write_line(format!("backtrack {:?} at {}", symbol, token_position));
+    }
+
+    fn on_invoke_solver(&mut self, symbol: &TSyntax, token_position: usize) {
+        self.write_line(format!("solve {:?} at {}", symbol, token_position));
+    }
+}
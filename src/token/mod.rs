@@ -1 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod token;
+pub mod token_edit;
+pub mod token_span;
@@ -19,8 +19,4 @@ impl<TLex, TSyntax: PartialEq> PartialEq for Token<TLex, TSyntax> {
     fn eq(&self, other: &Self) -> bool {
         self.t_type.eq(&other.t_type)
     }
-
-    fn ne(&self, other: &Self) -> bool {
-        self.t_type.ne(&other.t_type)
-    }
 }
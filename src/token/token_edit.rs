@@ -0,0 +1,24 @@
+use crate::token::token::Token;
+
+/// Edit of a sequence of input tokens
+///
+/// - `Delete`: token removed at a position of the input.
+/// - `Insert`: token inserted before a position of the input, the length of the input for
+///   tokens inserted at the end.
+pub enum TokenEdit<TLex, TSyntax> {
+    Delete(usize, Token<TLex, TSyntax>),
+    Insert(usize, Token<TLex, TSyntax>),
+}
+
+impl<TLex: Clone, TSyntax: Clone> Clone for TokenEdit<TLex, TSyntax> {
+    fn clone(&self) -> Self {
+        match self {
+            TokenEdit::Delete(token_position, token) => {
+                TokenEdit::Delete(*token_position, token.clone())
+            }
+            TokenEdit::Insert(token_position, token) => {
+                TokenEdit::Insert(*token_position, token.clone())
+            }
+        }
+    }
+}
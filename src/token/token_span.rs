@@ -0,0 +1,14 @@
+use std::ops::Range;
+
+/// Lexeme located in a source
+///
+/// The span is the byte range of the lexeme in the source it was read from.
+pub trait TokenSpan {
+    fn get_span(&self) -> Range<usize>;
+}
+
+impl TokenSpan for Range<usize> {
+    fn get_span(&self) -> Range<usize> {
+        self.clone()
+    }
+}